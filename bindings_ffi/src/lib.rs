@@ -6,11 +6,12 @@ use log::info;
 use logger::FfiLogger;
 use std::error::Error;
 use std::sync::Arc;
-use xmtp::conversation::{ListMessagesOptions, SecretConversation};
+use xmtp::conversation::{ListMessagesOptions, MessageCursor, SecretConversation, SortDirection};
 use xmtp::conversations::Conversations;
 use xmtp::storage::StoredMessage;
 use xmtp::types::Address;
 use xmtp_networking::grpc_api_helper::Client as TonicApiClient;
+use xmtp_proto::xmtp::message_contents::ContentTypeId;
 
 use crate::inbox_owner::RustInboxOwner;
 pub use crate::inbox_owner::SigningError;
@@ -150,20 +151,97 @@ pub struct FfiConversation {
     peer_address: String,
 }
 
+#[derive(uniffi::Enum, Clone, Copy)]
+pub enum FfiSortDirection {
+    Ascending,
+    Descending,
+}
+
+impl From<FfiSortDirection> for SortDirection {
+    fn from(direction: FfiSortDirection) -> Self {
+        match direction {
+            FfiSortDirection::Ascending => SortDirection::Ascending,
+            FfiSortDirection::Descending => SortDirection::Descending,
+        }
+    }
+}
+
+/// The position of a message in a listing, see `FfiListMessagesOptions::cursor`
+#[derive(uniffi::Record, Clone, Copy)]
+pub struct FfiMessageCursor {
+    pub sent_at_ns: i64,
+    pub id: i32,
+}
+
+impl From<MessageCursor> for FfiMessageCursor {
+    fn from(cursor: MessageCursor) -> Self {
+        Self {
+            sent_at_ns: cursor.sent_at_ns,
+            id: cursor.id,
+        }
+    }
+}
+
+impl From<FfiMessageCursor> for MessageCursor {
+    fn from(cursor: FfiMessageCursor) -> Self {
+        Self {
+            sent_at_ns: cursor.sent_at_ns,
+            id: cursor.id,
+        }
+    }
+}
+
 #[derive(uniffi::Object)]
 pub struct FfiListMessagesOptions {
     pub start_time_ns: Option<i64>,
     pub end_time_ns: Option<i64>,
     pub limit: Option<i64>,
+    /// The cursor of the last message of the previous page, to list the messages after it
+    pub cursor: Option<FfiMessageCursor>,
+    pub direction: FfiSortDirection,
+    pub sender_addresses: Option<Vec<String>>,
+    /// Content types as `authority_id/type_id`, e.g. `xmtp.org/text`
+    pub content_types: Option<Vec<String>>,
+    /// Also list delivery and read receipts, which are hidden by default
+    pub include_receipts: bool,
 }
 
 impl FfiListMessagesOptions {
-    fn to_options(&self) -> ListMessagesOptions {
-        ListMessagesOptions {
+    fn to_options(&self) -> Result<ListMessagesOptions, GenericError> {
+        let content_types = match &self.content_types {
+            Some(content_types) => Some(
+                content_types
+                    .iter()
+                    .map(|name| parse_content_type(name))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            None => None,
+        };
+
+        Ok(ListMessagesOptions {
             start_time_ns: self.start_time_ns,
             end_time_ns: self.end_time_ns,
             limit: self.limit,
+            cursor: self.cursor.map(Into::into),
+            direction: self.direction.into(),
+            sender_addresses: self.sender_addresses.clone(),
+            content_types,
+            include_receipts: self.include_receipts,
+        })
+    }
+}
+
+fn parse_content_type(name: &str) -> Result<ContentTypeId, GenericError> {
+    match name.split_once('/') {
+        Some((authority_id, type_id)) if !authority_id.is_empty() && !type_id.is_empty() => {
+            Ok(ContentTypeId {
+                authority_id: authority_id.to_string(),
+                type_id: type_id.to_string(),
+                version_major: 0,
+                version_minor: 0,
+            })
         }
+        _ => Err(format!("Invalid content type {}", name).into()),
     }
 }
 
@@ -192,7 +270,7 @@ impl FfiConversation {
             self.peer_address.clone(),
         )
         .map_err(|e| e.to_string())?;
-        let options: ListMessagesOptions = opts.to_options();
+        let options: ListMessagesOptions = opts.to_options()?;
 
         let messages: Vec<Arc<FfiMessage>> = conversation
             .list_messages(&options)
@@ -208,6 +286,8 @@ impl FfiConversation {
 
 #[derive(uniffi::Object)]
 pub struct FfiMessage {
+    pub id: String,
+    pub cursor: FfiMessageCursor,
     pub sent_at_ns: i64,
     pub convo_id: String,
     pub addr_from: String,
@@ -217,6 +297,8 @@ pub struct FfiMessage {
 impl From<StoredMessage> for FfiMessage {
     fn from(msg: StoredMessage) -> Self {
         Self {
+            cursor: MessageCursor::from(&msg).into(),
+            id: msg.message_id,
             sent_at_ns: msg.sent_at_ns,
             convo_id: msg.convo_id,
             addr_from: msg.addr_from,
@@ -231,7 +313,7 @@ mod tests {
 
     use crate::{
         create_client, inbox_owner::SigningError, logger::FfiLogger, FfiInboxOwner,
-        FfiListMessagesOptions, FfiXmtpClient,
+        FfiListMessagesOptions, FfiMessageCursor, FfiSortDirection, FfiXmtpClient,
     };
     use xmtp::InboxOwner;
     use xmtp_cryptography::{signature::RecoverableSignature, utils::rng};
@@ -325,10 +407,64 @@ mod tests {
                 start_time_ns: None,
                 end_time_ns: None,
                 limit: None,
+                cursor: None,
+                direction: FfiSortDirection::Ascending,
+                sender_addresses: None,
+                content_types: None,
+                include_receipts: false,
             }))
             .await
             .unwrap();
         assert_eq!(messages.len(), 1);
         assert_eq!(messages[0].content, vec![1, 2, 3]);
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_list_pages() {
+        let alice = new_test_client().await;
+        let bob = new_test_client().await;
+
+        let alice_to_bob = alice
+            .conversations()
+            .new_conversation(bob.wallet_address())
+            .await
+            .unwrap();
+        for content in [vec![1], vec![2], vec![3]] {
+            alice_to_bob.send(content).await.unwrap();
+        }
+
+        let page = |cursor: Option<FfiMessageCursor>| {
+            Arc::new(FfiListMessagesOptions {
+                start_time_ns: None,
+                end_time_ns: None,
+                limit: Some(2),
+                cursor,
+                direction: FfiSortDirection::Descending,
+                sender_addresses: Some(vec![alice.wallet_address()]),
+                content_types: None,
+                include_receipts: false,
+            })
+        };
+        let first = alice_to_bob.list_messages(page(None)).await.unwrap();
+        let contents: Vec<Vec<u8>> = first.iter().map(|msg| msg.content.clone()).collect();
+        assert_eq!(contents, vec![vec![3], vec![2]]);
+        let second = alice_to_bob
+            .list_messages(page(Some(first[1].cursor)))
+            .await
+            .unwrap();
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].content, vec![1]);
+
+        let invalid = Arc::new(FfiListMessagesOptions {
+            start_time_ns: None,
+            end_time_ns: None,
+            limit: None,
+            cursor: None,
+            direction: FfiSortDirection::Ascending,
+            sender_addresses: None,
+            content_types: Some(vec!["text".to_string()]),
+            include_receipts: false,
+        });
+        assert!(alice_to_bob.list_messages(invalid).await.is_err());
+    }
 }
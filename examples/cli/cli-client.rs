@@ -273,7 +273,7 @@ async fn format_messages<'c, A: XmtpApiClient>(
 
     for msg in convo.list_messages(&opts).await? {
         let contents = msg.get_text().map_err(|e| e.to_string())?;
-        let is_inbound = msg.state == MessageState::Received as i32;
+        let is_inbound = msg.state == MessageState::Received;
         let direction = if is_inbound {
             String::from("    -------->")
         } else {
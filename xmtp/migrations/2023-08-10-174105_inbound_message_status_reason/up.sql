@@ -0,0 +1,2 @@
+ALTER TABLE inbound_messages
+ADD COLUMN status_reason SMALLINT;
\ No newline at end of file
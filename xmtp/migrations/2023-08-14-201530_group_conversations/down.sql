@@ -0,0 +1,3 @@
+DROP TABLE conversation_members;
+-- Columns cannot be dropped in sqlite without dropping the whole table first
+-- Instead of removing the column, simply do nothing and ignore the column in code
@@ -0,0 +1,10 @@
+ALTER TABLE conversations
+ADD COLUMN convo_kind INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE conversation_members (
+    convo_id TEXT NOT NULL,
+    user_address TEXT NOT NULL,
+    added_at_ns BIGINT NOT NULL,
+    PRIMARY KEY (convo_id, user_address),
+    FOREIGN KEY(convo_id) REFERENCES conversations(convo_id)
+);
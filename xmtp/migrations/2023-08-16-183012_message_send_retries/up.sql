@@ -0,0 +1,11 @@
+ALTER TABLE messages
+ADD COLUMN send_attempts INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE messages
+ADD COLUMN max_send_attempts INTEGER NOT NULL DEFAULT 5;
+
+ALTER TABLE messages
+ADD COLUMN next_attempt_ns BIGINT NOT NULL DEFAULT 0;
+
+ALTER TABLE messages
+ADD COLUMN error_reason TEXT;
\ No newline at end of file
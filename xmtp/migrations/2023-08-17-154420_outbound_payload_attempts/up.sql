@@ -0,0 +1,2 @@
+ALTER TABLE outbound_payloads
+ADD COLUMN publish_attempts INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
@@ -0,0 +1,3 @@
+DROP INDEX messages_message_id;
+-- Columns cannot be dropped in sqlite without dropping the whole table first
+-- Instead of removing the column, simply do nothing and ignore the column in code
\ No newline at end of file
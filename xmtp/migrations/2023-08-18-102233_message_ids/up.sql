@@ -0,0 +1,7 @@
+ALTER TABLE messages
+ADD COLUMN message_id TEXT NOT NULL DEFAULT '';
+
+-- Messages stored before ids existed can't be matched with any other copy, so give them a local one
+UPDATE messages SET message_id = 'local-' || id;
+
+CREATE UNIQUE INDEX messages_message_id ON messages(message_id);
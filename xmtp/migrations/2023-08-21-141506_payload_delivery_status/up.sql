@@ -0,0 +1,13 @@
+ALTER TABLE outbound_payloads
+ADD COLUMN message_id TEXT NOT NULL DEFAULT '';
+
+ALTER TABLE outbound_payloads
+ADD COLUMN recipient_address TEXT NOT NULL DEFAULT '';
+
+ALTER TABLE outbound_payloads
+ADD COLUMN recipient_installation_id TEXT NOT NULL DEFAULT '';
+
+ALTER TABLE outbound_payloads
+ADD COLUMN delivery_status INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX outbound_payloads_message_id ON outbound_payloads(message_id);
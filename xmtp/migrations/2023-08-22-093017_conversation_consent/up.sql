@@ -0,0 +1,5 @@
+ALTER TABLE conversations
+ADD COLUMN consent_state INTEGER NOT NULL DEFAULT 0;
+
+-- Conversations which already exist have been shown to the user, so treat them as allowed
+UPDATE conversations SET consent_state = 10;
@@ -0,0 +1,8 @@
+-- Messages in conversations with a retention are deleted this long after they were sent
+ALTER TABLE conversations
+ADD COLUMN retention_ns BIGINT;
+
+-- The message an inbound payload decrypted to, so the payload can be deleted along with it
+ALTER TABLE inbound_messages
+ADD COLUMN message_id TEXT NOT NULL DEFAULT '';
+CREATE INDEX inbound_messages_message_id ON inbound_messages(message_id);
@@ -0,0 +1,7 @@
+-- Text of messages, decoded from their content when they are stored. The rowid is the id of the
+-- message, and rows are removed along with their message.
+CREATE VIRTUAL TABLE messages_fts USING fts5(body, tokenize = 'unicode61 remove_diacritics 2');
+
+CREATE TRIGGER messages_fts_delete AFTER DELETE ON messages BEGIN
+    DELETE FROM messages_fts WHERE rowid = old.id;
+END;
@@ -0,0 +1,8 @@
+-- The `authority_id/type_id` of the content of a message, so messages can be listed by type.
+-- Messages stored before this column existed are filled in from their content when the store
+-- is opened.
+ALTER TABLE messages
+ADD COLUMN content_type TEXT NOT NULL DEFAULT '';
+
+-- Messages of a conversation are paged through in order of (sent_at_ns, id)
+CREATE INDEX messages_convo_sent_at ON messages(convo_id, sent_at_ns, id);
@@ -5,21 +5,24 @@ use crate::{
     association::{Association, AssociationError},
     contact::Contact,
     types::Address,
+    utils::{base64_encode, get_current_time_ns},
     vmac_protos::ProtoWrapper,
     Signable,
 };
+use prost::Message;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use vodozemac::olm::{
     Account as OlmAccount, AccountPickle as OlmAccountPickle, IdentityKeys, InboundCreationResult,
     PreKeyMessage, Session as OlmSession, SessionConfig, SessionCreationError,
 };
-use vodozemac::Ed25519Signature;
+use vodozemac::{Curve25519PublicKey, Curve25519SecretKey, Ed25519Signature};
 use xmtp_cryptography::signature::SignatureError;
 use xmtp_proto::xmtp::v3::message_contents::{
     installation_contact_bundle::Version, vmac_account_linked_key::Association as AssociationProto,
-    InstallationContactBundle, VmacAccountLinkedKey, VmacInstallationLinkedKey,
-    VmacInstallationPublicKeyBundleV1, VmacUnsignedPublicKey,
+    EdDsaSignature, InstallationContactBundle, VmacAccountLinkedKey, VmacFallbackKeyRotation,
+    VmacInstallationLinkedKey, VmacInstallationPublicKeyBundleV1, VmacOneTimeKeyTopupBundle,
+    VmacUnsignedPublicKey,
 };
 
 #[derive(Debug, Error)]
@@ -62,9 +65,16 @@ impl VmacAccount {
     }
 }
 
+// The association covers both identity keys, so that header signatures made with the Ed25519 key
+// are as strongly tied to the wallet as sessions established with the Curve25519 key. This is
+// association text version 2; version 1 covered the Curve25519 key alone.
 impl Signable for VmacAccount {
     fn bytes_to_sign(&self) -> Vec<u8> {
-        self.account.curve25519_key().to_vec()
+        [
+            self.account.curve25519_key().as_bytes().as_slice(),
+            self.account.ed25519_key().as_bytes().as_slice(),
+        ]
+        .concat()
     }
 }
 
@@ -92,10 +102,52 @@ impl<'de> Deserialize<'de> for VmacAccount {
     }
 }
 
+// Tracks when the fallback key was last rotated, and how long the previous one is kept
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub(crate) struct FallbackKeySchedule {
+    rotated_at_ns: i64,
+    previous_key_expires_at_ns: Option<i64>,
+}
+
+// Accounts persisted before sealed sender was introduced have no key, so one is generated on
+// load. The flag is not persisted, and tells the client builder to save the new key.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct SealedSenderKey {
+    key: Curve25519SecretKey,
+    #[serde(skip)]
+    generated_on_load: bool,
+}
+
+impl SealedSenderKey {
+    fn new() -> Self {
+        Self {
+            key: Curve25519SecretKey::new(),
+            generated_on_load: false,
+        }
+    }
+}
+
+impl Default for SealedSenderKey {
+    fn default() -> Self {
+        Self {
+            generated_on_load: true,
+            ..Self::new()
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct Account {
     pub(crate) keys: Mutex<VmacAccount>,
     pub(crate) assoc: Association,
+    // Sealed sender metadata is encrypted to this key rather than the Olm identity key, whose
+    // secret vodozemac keeps to itself
+    #[serde(default)]
+    sealed_sender_key: SealedSenderKey,
+    // Accounts persisted before rotation was introduced are treated as never having rotated
+    #[serde(default)]
+    pub(crate) fallback_key_schedule: Mutex<FallbackKeySchedule>,
 }
 
 impl fmt::Debug for Account {
@@ -117,6 +169,11 @@ impl Account {
         Self {
             keys: Mutex::new(keys),
             assoc,
+            sealed_sender_key: SealedSenderKey::new(),
+            fallback_key_schedule: Mutex::new(FallbackKeySchedule {
+                rotated_at_ns: get_current_time_ns() as i64,
+                previous_key_expires_at_ns: None,
+            }),
         }
     }
 
@@ -133,6 +190,16 @@ impl Account {
         self.assoc.address()
     }
 
+    pub(crate) fn sealed_sender_key(&self) -> &Curve25519SecretKey {
+        &self.sealed_sender_key.key
+    }
+
+    /// Whether the sealed sender key was generated when this account was loaded, in which case
+    /// the account has to be persisted and its contact bundle published again
+    pub(crate) fn sealed_sender_key_generated_on_load(&self) -> bool {
+        self.sealed_sender_key.generated_on_load
+    }
+
     pub fn olm_account(&self) -> Result<MutexGuard<'_, VmacAccount>, AccountError> {
         self.keys.lock().map_err(|_| AccountError::MutexPoisoned)
     }
@@ -141,26 +208,53 @@ impl Account {
         self.olm_account().unwrap().get().identity_keys()
     }
 
-    pub fn contact(&self) -> Contact {
-        let olm_account = self.olm_account().unwrap();
-        let keys = olm_account.get();
-        let identity_key = keys.curve25519_key();
-        let fallback_key = keys.fallback_key().values().next().unwrap().to_owned();
-
+    fn identity_linked_key(&self, identity_key: Curve25519PublicKey) -> VmacAccountLinkedKey {
         let identity_key_proto: ProtoWrapper<VmacUnsignedPublicKey> = identity_key.into();
-        let fallback_key_proto: ProtoWrapper<VmacUnsignedPublicKey> = fallback_key.into();
-        let identity_key = VmacAccountLinkedKey {
+        VmacAccountLinkedKey {
             key: Some(identity_key_proto.proto),
             association: Some(AssociationProto::Eip191(self.assoc.clone().into())),
-        };
-        let fallback_key = VmacInstallationLinkedKey {
-            key: Some(fallback_key_proto.proto),
-        };
+        }
+    }
+
+    // Takes the already locked account, since every caller is holding it. `created_ns` is
+    // covered by the signature, which lets contacts tell a newer fallback key from a replay.
+    fn installation_linked_key(
+        keys: &OlmAccount,
+        key: Curve25519PublicKey,
+        created_ns: i64,
+    ) -> VmacInstallationLinkedKey {
+        let mut key_proto: ProtoWrapper<VmacUnsignedPublicKey> = key.into();
+        key_proto.proto.created_ns = created_ns as u64;
+        let signature = sign_with(keys, &key_proto.proto.encode_to_vec());
+        VmacInstallationLinkedKey {
+            key: Some(key_proto.proto),
+            signature: Some(EdDsaSignature {
+                bytes: signature.to_bytes().to_vec(),
+            }),
+        }
+    }
+
+    pub fn contact(&self) -> Contact {
+        let olm_account = self.olm_account().unwrap();
+        let keys = olm_account.get();
+        let identity_key = self.identity_linked_key(keys.curve25519_key());
+        let fallback_key = Self::installation_linked_key(
+            keys,
+            keys.fallback_key().values().next().unwrap().to_owned(),
+            self.fallback_key_schedule().rotated_at_ns,
+        );
+        let sealed_sender_key = Self::installation_linked_key(
+            keys,
+            Curve25519PublicKey::from(self.sealed_sender_key()),
+            0,
+        );
         let contact = Contact::new(
             InstallationContactBundle {
                 version: Some(Version::V1(VmacInstallationPublicKeyBundleV1 {
                     identity_key: Some(identity_key),
                     fallback_key: Some(fallback_key),
+                    sealed_sender_key: Some(sealed_sender_key),
+                    ed25519_key: keys.ed25519_key().as_bytes().to_vec(),
                 })),
             },
             self.assoc.address(),
@@ -173,24 +267,105 @@ impl Account {
         }
     }
 
-    pub(crate) fn sign(&self, message: &str) -> Ed25519Signature {
-        self.olm_account().unwrap().get().sign(message)
+    /// Sign with the Ed25519 identity key, verifiable with `Contact::verify_signature`
+    pub(crate) fn sign(&self, message: &[u8]) -> Result<Ed25519Signature, AccountError> {
+        Ok(sign_with(self.olm_account()?.get(), message))
+    }
+
+    /// Generate a batch of one-time keys to publish alongside the contact bundle.
+    /// The account must be persisted afterwards so that the private keys are not lost.
+    pub fn generate_one_time_keys(&self, count: usize) -> VmacOneTimeKeyTopupBundle {
+        let mut olm_account = self.olm_account().unwrap();
+        let keys = olm_account.get_mut();
+        // Marking keys as published would also hide the fallback key from `contact()`, so the new
+        // keys are found by comparing against the ones generated previously instead
+        let existing_keys = keys.one_time_keys();
+        keys.generate_one_time_keys(count);
+        let one_time_keys = keys
+            .one_time_keys()
+            .into_iter()
+            .filter(|(key_id, _)| !existing_keys.contains_key(key_id))
+            .map(|(_, key)| Self::installation_linked_key(keys, key, 0))
+            .collect();
+
+        VmacOneTimeKeyTopupBundle {
+            identity_key: Some(self.identity_linked_key(keys.curve25519_key())),
+            one_time_keys,
+        }
+    }
+
+    fn fallback_key_schedule(&self) -> MutexGuard<'_, FallbackKeySchedule> {
+        self.fallback_key_schedule.lock().unwrap()
+    }
+
+    pub fn fallback_key_rotation_due(&self, now_ns: i64, rotation_interval_ns: i64) -> bool {
+        now_ns - self.fallback_key_schedule().rotated_at_ns >= rotation_interval_ns
+    }
+
+    /// Replace the fallback key, keeping the previous one until `previous_key_expires_at_ns` so
+    /// that prekey messages already in flight can still be decrypted. Returns the rotation to
+    /// publish on the contact topic; the account must be persisted afterwards.
+    pub fn rotate_fallback_key(&self, previous_key_expires_at_ns: i64) -> VmacFallbackKeyRotation {
+        let mut olm_account = self.olm_account().unwrap();
+        let keys = olm_account.get_mut();
+        // vodozemac keeps a single previous fallback key, which is replaced here
+        keys.generate_fallback_key();
+        let fallback_key = keys.fallback_key().values().next().unwrap().to_owned();
+
+        let mut schedule = self.fallback_key_schedule();
+        // Contacts only accept strictly newer keys, even if the clock has not moved on
+        schedule.rotated_at_ns = (get_current_time_ns() as i64).max(schedule.rotated_at_ns + 1);
+        schedule.previous_key_expires_at_ns = Some(previous_key_expires_at_ns);
+
+        VmacFallbackKeyRotation {
+            identity_key: Some(self.identity_linked_key(keys.curve25519_key())),
+            fallback_key: Some(Self::installation_linked_key(
+                keys,
+                fallback_key,
+                schedule.rotated_at_ns,
+            )),
+        }
     }
 
-    pub fn create_outbound_session(&self, contact: &Contact) -> OlmSession {
+    /// Drop the previous fallback key once its grace period has passed. Returns whether a key
+    /// was removed, in which case the account must be persisted.
+    pub fn forget_expired_fallback_key(&self, now_ns: i64) -> bool {
+        let expired = {
+            let mut schedule = self.fallback_key_schedule();
+            match schedule.previous_key_expires_at_ns {
+                Some(expires_at_ns) if expires_at_ns <= now_ns => {
+                    schedule.previous_key_expires_at_ns = None;
+                    true
+                }
+                _ => false,
+            }
+        };
+
+        expired && self.olm_account().unwrap().get_mut().forget_fallback_key()
+    }
+
+    /// Create a session with the contact, using a claimed one-time key if there is one and
+    /// the contact's fallback key otherwise
+    pub fn create_outbound_session(
+        &self,
+        contact: &Contact,
+        one_time_key: Option<Curve25519PublicKey>,
+    ) -> OlmSession {
         self.olm_account().unwrap().get().create_outbound_session(
             SessionConfig::version_2(),
             contact.vmac_identity_key(),
-            contact.vmac_fallback_key(),
+            one_time_key.unwrap_or_else(|| contact.vmac_fallback_key()),
         )
     }
 
+    /// Create a session from an incoming prekey message. If the message used one of our
+    /// one-time keys, the key is removed from the account so it can never be used again,
+    /// so the account must be persisted afterwards.
     pub fn create_inbound_session(
         &self,
         contact: &Contact,
         pre_key_message: PreKeyMessage,
     ) -> Result<InboundCreationResult, AccountError> {
-        // TODO: Save the account keys to the store
         let mut olm_account = self.olm_account().unwrap();
         let keys = olm_account.get_mut();
         let res = keys.create_inbound_session(contact.vmac_identity_key(), &pre_key_message)?;
@@ -199,22 +374,91 @@ impl Account {
     }
 }
 
+fn sign_with(keys: &OlmAccount, message: &[u8]) -> Ed25519Signature {
+    // vodozemac only signs strings
+    keys.sign(&base64_encode(message))
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
 
     use crate::association::AssociationError;
 
     use super::{Account, Association};
+    use crate::{utils::get_current_time_ns, vmac_protos::ProtoWrapper};
     use ethers::core::rand::thread_rng;
     use ethers::signers::{LocalWallet, Signer};
     use ethers_core::types::{Address as EthAddress, Signature};
     use ethers_core::utils::hex;
     use serde_json::json;
+    use vodozemac::{olm::OlmMessage, Curve25519PublicKey};
 
     pub fn test_wallet_signer(pub_key: Vec<u8>) -> Result<Association, AssociationError> {
         Association::test(pub_key)
     }
 
+    #[test]
+    fn one_time_key_is_consumed() {
+        let alice = Account::generate(test_wallet_signer).unwrap();
+        let bob = Account::generate(test_wallet_signer).unwrap();
+
+        let bundle = bob.generate_one_time_keys(5);
+        assert_eq!(bundle.one_time_keys.len(), 5);
+        // Published keys are not handed out again
+        assert_eq!(bob.generate_one_time_keys(0).one_time_keys.len(), 0);
+
+        let one_time_key: Curve25519PublicKey = ProtoWrapper {
+            proto: bundle.one_time_keys[0].clone(),
+        }
+        .into();
+        let mut session = alice.create_outbound_session(&bob.contact(), Some(one_time_key));
+        let message = match session.encrypt("hello") {
+            OlmMessage::PreKey(m) => m,
+            OlmMessage::Normal(_) => panic!("expected prekey message"),
+        };
+
+        let result = bob
+            .create_inbound_session(&alice.contact(), message.clone())
+            .unwrap();
+        assert_eq!(result.plaintext, "hello".as_bytes());
+        // The one-time key was removed, so replaying the message cannot create a second session
+        assert!(bob
+            .create_inbound_session(&alice.contact(), message)
+            .is_err());
+    }
+
+    #[test]
+    fn previous_fallback_key_expires() {
+        let alice = Account::generate(test_wallet_signer).unwrap();
+        let bob = Account::generate(test_wallet_signer).unwrap();
+
+        let prekey_message = |alice: &Account, bob: &Account| match alice
+            .create_outbound_session(&bob.contact(), None)
+            .encrypt("hello")
+        {
+            OlmMessage::PreKey(m) => m,
+            OlmMessage::Normal(_) => panic!("expected prekey message"),
+        };
+        let in_flight = prekey_message(&alice, &bob);
+        let expired = prekey_message(&alice, &bob);
+
+        let now = get_current_time_ns() as i64;
+        assert!(!bob.fallback_key_rotation_due(now - 100, 100));
+        assert!(bob.fallback_key_rotation_due(now + 100, 100));
+        bob.rotate_fallback_key(100);
+        assert!(!bob.forget_expired_fallback_key(99));
+        bob.create_inbound_session(&alice.contact(), in_flight)
+            .unwrap();
+
+        assert!(bob.forget_expired_fallback_key(100));
+        assert!(bob
+            .create_inbound_session(&alice.contact(), expired)
+            .is_err());
+        // Messages to the new fallback key are unaffected
+        bob.create_inbound_session(&alice.contact(), prekey_message(&alice, &bob))
+            .unwrap();
+    }
+
     #[test]
     fn account_serialize() {
         let account = Account::generate(test_wallet_signer).unwrap();
@@ -225,6 +469,37 @@ pub(crate) mod tests {
 
         let recovered_account: Account = serde_json::from_str(&serialized_account).unwrap();
         assert_eq!(account.addr(), recovered_account.addr());
+        assert_eq!(
+            recovered_account.contact().vmac_sealed_sender_key(),
+            account.contact().vmac_sealed_sender_key()
+        );
+        assert!(!recovered_account.sealed_sender_key_generated_on_load());
+    }
+
+    #[test]
+    fn sealed_sender_key_is_generated_for_older_accounts() {
+        let account = Account::generate(test_wallet_signer).unwrap();
+        let mut serialized_account = json!(account);
+        serialized_account
+            .as_object_mut()
+            .unwrap()
+            .remove("sealed_sender_key");
+
+        let recovered_account: Account = serde_json::from_value(serialized_account).unwrap();
+        assert!(recovered_account.sealed_sender_key_generated_on_load());
+        assert_eq!(account.addr(), recovered_account.addr());
+        assert_ne!(
+            recovered_account.contact().vmac_sealed_sender_key(),
+            account.contact().vmac_sealed_sender_key()
+        );
+
+        // Once persisted, the generated key is kept
+        let reloaded_account: Account = serde_json::from_value(json!(recovered_account)).unwrap();
+        assert!(!reloaded_account.sealed_sender_key_generated_on_load());
+        assert_eq!(
+            reloaded_account.contact().vmac_sealed_sender_key(),
+            recovered_account.contact().vmac_sealed_sender_key()
+        );
     }
 
     async fn generate_random_signature(msg: &str) -> (String, Vec<u8>) {
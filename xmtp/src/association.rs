@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use xmtp_cryptography::signature::{RecoverableSignature, SignatureError};
 use xmtp_cryptography::utils::generate_local_wallet;
+use xmtp_proto::xmtp::v3::message_contents::AssociationTextVersion;
 use xmtp_proto::xmtp::v3::message_contents::Eip191Association as Eip191AssociationProto;
 use xmtp_proto::xmtp::v3::message_contents::RecoverableEcdsaSignature as RecoverableEcdsaSignatureProto;
 
@@ -13,6 +14,8 @@ pub enum AssociationError {
     BadSignature(#[from] SignatureError),
     #[error("Association text mismatch")]
     TextMismatch,
+    #[error("unsupported association text version: {0}")]
+    UnsupportedTextVersion(i32),
     #[error(
         "Address mismatch in Association: Provided:{provided_addr:?} != signed:{signing_addr:?}"
     )]
@@ -49,6 +52,14 @@ impl Association {
         proto: Eip191AssociationProto,
         expected_wallet_address: String,
     ) -> Result<Self, AssociationError> {
+        // Version 1 does not cover the Ed25519 key, which prekeys and message headers are signed
+        // with, so those associations are no longer accepted
+        if proto.association_text_version != AssociationTextVersion::AssociationTextVersion2 as i32
+        {
+            return Err(AssociationError::UnsupportedTextVersion(
+                proto.association_text_version,
+            ));
+        }
         let text =
             AssociationText::new_static(expected_wallet_address, account_public_key.to_vec());
         let signature = RecoverableSignature::Eip191Signature(proto.signature.unwrap().bytes);
@@ -78,6 +89,12 @@ impl Association {
         self.text.get_address()
     }
 
+    /// Whether the association uses the text version this client publishes. Accounts created
+    /// before version 2 have to be associated again by their wallet.
+    pub fn is_current(&self) -> bool {
+        self.text.version() == AssociationTextVersion::AssociationTextVersion2
+    }
+
     pub fn test(pub_key: Vec<u8>) -> Result<Self, AssociationError> {
         let wallet = generate_local_wallet();
         let addr = wallet.get_address();
@@ -95,8 +112,7 @@ impl From<Association> for Eip191AssociationProto {
     fn from(assoc: Association) -> Self {
         Self {
             wallet_address: assoc.address(),
-            // Hardcoded version for now
-            association_text_version: 1,
+            association_text_version: assoc.text.version() as i32,
             signature: Some(RecoverableEcdsaSignatureProto {
                 bytes: assoc.signature.into(),
             }),
@@ -109,16 +125,29 @@ impl From<Association> for Eip191AssociationProto {
 /// choose how this information is encoded, as well as adding extra requirements for increased security.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum AssociationText {
+    // Version 1, signed over the Curve25519 identity key alone
     Static {
         addr: Address,
         account_public_key: Vec<u8>,
     },
+    // Version 2, signed over both identity keys, see `VmacAccount::bytes_to_sign`
+    StaticV2 {
+        addr: Address,
+        account_public_key: Vec<u8>,
+    },
 }
 
 impl AssociationText {
     pub fn get_address(&self) -> Address {
         match self {
-            Self::Static { addr, .. } => addr.clone(),
+            Self::Static { addr, .. } | Self::StaticV2 { addr, .. } => addr.clone(),
+        }
+    }
+
+    pub fn version(&self) -> AssociationTextVersion {
+        match self {
+            Self::Static { .. } => AssociationTextVersion::AssociationTextVersion1,
+            Self::StaticV2 { .. } => AssociationTextVersion::AssociationTextVersion2,
         }
     }
 
@@ -128,11 +157,19 @@ impl AssociationText {
                 addr,
                 account_public_key,
             } => gen_static_text_v1(addr, account_public_key),
+            Self::StaticV2 {
+                addr,
+                account_public_key,
+            } => gen_static_text_v2(addr, account_public_key),
         }
     }
 
     pub fn is_valid(&self, addr: &str, account_public_key: &[u8]) -> Result<(), AssociationError> {
-        if self.text() == gen_static_text_v1(addr, account_public_key) {
+        let expected_text = match self {
+            Self::Static { .. } => gen_static_text_v1(addr, account_public_key),
+            Self::StaticV2 { .. } => gen_static_text_v2(addr, account_public_key),
+        };
+        if self.text() == expected_text {
             return Ok(());
         }
 
@@ -140,7 +177,7 @@ impl AssociationText {
     }
 
     pub fn new_static(addr: String, account_public_key: Vec<u8>) -> Self {
-        AssociationText::Static {
+        AssociationText::StaticV2 {
             addr,
             account_public_key,
         }
@@ -154,13 +191,81 @@ fn gen_static_text_v1(addr: &str, key_bytes: &[u8]) -> String {
     )
 }
 
+fn gen_static_text_v2(addr: &str, key_bytes: &[u8]) -> String {
+    format!(
+        "AccountAssociation(XMTPv3) v2: {addr} -> keyBytes:{}",
+        &hex::encode(key_bytes)
+    )
+}
+
+/// An InstallationRevocation is signed by a blockchain account to withdraw one of its
+/// installations, for example when a device is lost. It is published to the account's contact
+/// topic, and other clients stop sending messages to the installation once they have seen it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct InstallationRevocation {
+    pub addr: Address,
+    pub installation_id: String,
+    pub revoked_at_ns: i64,
+    signature: RecoverableSignature,
+}
+
+impl InstallationRevocation {
+    pub fn new(
+        owner: &impl InboxOwner,
+        installation_id: String,
+        revoked_at_ns: i64,
+    ) -> Result<Self, AssociationError> {
+        let addr = owner.get_address();
+        let signature = owner.sign(&gen_revocation_text_v1(
+            &addr,
+            &installation_id,
+            revoked_at_ns,
+        ))?;
+
+        Ok(Self {
+            addr,
+            installation_id,
+            revoked_at_ns,
+            signature,
+        })
+    }
+
+    /// Ensure the revocation was signed by `expected_addr`
+    pub fn verify(&self, expected_addr: &str) -> Result<(), AssociationError> {
+        if self.addr != expected_addr {
+            return Err(AssociationError::AddressMismatch {
+                provided_addr: expected_addr.to_string(),
+                signing_addr: self.addr.clone(),
+            });
+        }
+        let text = gen_revocation_text_v1(&self.addr, &self.installation_id, self.revoked_at_ns);
+        self.signature.verify_signature(&self.addr, &text)?;
+
+        Ok(())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(bytes)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(self)
+    }
+}
+
+fn gen_revocation_text_v1(addr: &str, installation_id: &str, revoked_at_ns: i64) -> String {
+    format!("InstallationRevocation(XMTPv3): {addr} -> installationId:{installation_id} revokedAtNs:{revoked_at_ns}")
+}
+
 #[cfg(test)]
 pub mod tests {
     use ethers::signers::{LocalWallet, Signer};
     use xmtp_cryptography::{signature::h160addr_to_string, utils::rng};
+    use xmtp_proto::xmtp::v3::message_contents::AssociationTextVersion;
     use xmtp_proto::xmtp::v3::message_contents::Eip191Association as Eip191AssociationProto;
 
-    use super::{Association, AssociationText};
+    use super::{Association, AssociationError, AssociationText, InstallationRevocation};
+    use crate::InboxOwner;
 
     #[tokio::test]
     async fn assoc_gen() {
@@ -219,4 +324,65 @@ pub mod tests {
         assert_eq!(proto_signature.association_text_version, 1);
         assert_eq!(proto_signature.signature.unwrap().bytes, sig.to_vec());
     }
+
+    #[tokio::test]
+    async fn only_current_text_version_is_accepted() {
+        let key_bytes = vec![22, 33, 44, 55];
+        let wallet = LocalWallet::new(&mut rng());
+        let addr = h160addr_to_string(wallet.address());
+
+        let text = AssociationText::new_static(addr.clone(), key_bytes.clone());
+        let sig = wallet.sign_message(text.text()).await.expect("BadSign");
+        let assoc = Association::new(&key_bytes, text, sig.into()).unwrap();
+        assert!(assoc.is_current());
+        let proto: Eip191AssociationProto = assoc.into();
+        assert_eq!(
+            proto.association_text_version,
+            AssociationTextVersion::AssociationTextVersion2 as i32
+        );
+        assert!(
+            Association::from_proto_with_expected_address(&key_bytes, proto, addr.clone()).is_ok()
+        );
+
+        // A version 1 signature is not valid for the version 2 text, or accepted as version 1
+        let v1_text = AssociationText::Static {
+            addr: addr.clone(),
+            account_public_key: key_bytes.clone(),
+        };
+        let v1_sig = wallet.sign_message(v1_text.text()).await.expect("BadSign");
+        let v1_assoc = Association::new(&key_bytes, v1_text, v1_sig.into()).unwrap();
+        assert!(!v1_assoc.is_current());
+        let mut v1_proto: Eip191AssociationProto = v1_assoc.into();
+        assert!(matches!(
+            Association::from_proto_with_expected_address(
+                &key_bytes,
+                v1_proto.clone(),
+                addr.clone()
+            ),
+            Err(AssociationError::UnsupportedTextVersion(1))
+        ));
+        v1_proto.association_text_version = AssociationTextVersion::AssociationTextVersion2 as i32;
+        assert!(Association::from_proto_with_expected_address(&key_bytes, v1_proto, addr).is_err());
+    }
+
+    #[test]
+    fn revocation_signature() {
+        let wallet = LocalWallet::new(&mut rng());
+        let other_wallet = LocalWallet::new(&mut rng());
+        let revocation =
+            InstallationRevocation::new(&wallet, "installation_1".to_string(), 10).unwrap();
+
+        let decoded = InstallationRevocation::from_bytes(&revocation.to_bytes().unwrap()).unwrap();
+        assert_eq!(decoded, revocation);
+        assert!(decoded.verify(&wallet.get_address()).is_ok());
+        assert!(decoded.verify(&other_wallet.get_address()).is_err());
+
+        // The signature covers the installation and the revocation time
+        let mut tampered = revocation.clone();
+        tampered.installation_id = "installation_2".to_string();
+        assert!(tampered.verify(&wallet.get_address()).is_err());
+        let mut tampered = revocation;
+        tampered.revoked_at_ns = 20;
+        assert!(tampered.verify(&wallet.get_address()).is_err());
+    }
 }
@@ -1,11 +1,12 @@
 use crate::{
     account::{Account, AccountError},
     association::{Association, AssociationError, AssociationText},
-    client::{Client, Network},
+    client::{Client, Network, DEFAULT_INSTALLATION_REFRESH_INTERVAL_NS},
+    codecs::CodecRegistry,
     storage::{now, EncryptedMessageStore, StoredUser},
     types::networking::XmtpApiClient,
     types::Address,
-    InboxOwner, Store,
+    InboxOwner, Signable, Store,
 };
 use crate::{Fetch, StorageError};
 use log::info;
@@ -25,6 +26,9 @@ pub enum ClientBuilderError {
     #[error("Database was configured with a different wallet")]
     StoredAccountMismatch,
 
+    #[error("Stored account must be associated with its wallet again")]
+    StoredAccountOutdated,
+
     #[error("Associating an address to account failed")]
     AssociationFailed(#[from] AssociationError),
     // #[error("Error Initalizing Store")]
@@ -71,6 +75,8 @@ where
     account: Option<Account>,
     store: Option<EncryptedMessageStore>,
     account_strategy: AccountStrategy<O>,
+    installation_refresh_interval_ns: i64,
+    codecs: CodecRegistry,
 }
 
 impl<A, O> ClientBuilder<A, O>
@@ -85,6 +91,8 @@ where
             account: None,
             store: None,
             account_strategy: strat,
+            installation_refresh_interval_ns: DEFAULT_INSTALLATION_REFRESH_INTERVAL_NS,
+            codecs: CodecRegistry::default(),
         }
     }
 
@@ -108,6 +116,19 @@ where
         self
     }
 
+    /// How long a user's installations are trusted before they are fetched from the network again
+    pub fn installation_refresh_interval_ns(mut self, interval_ns: i64) -> Self {
+        self.installation_refresh_interval_ns = interval_ns;
+        self
+    }
+
+    /// Codecs used to send and decode content. Register application defined content types on
+    /// top of `CodecRegistry::default()` to keep the built in ones.
+    pub fn codecs(mut self, codecs: CodecRegistry) -> Self {
+        self.codecs = codecs;
+        self
+    }
+
     /// Fetch account from peristence or generate and sign a new one
     fn find_or_create_account(
         owner: &O,
@@ -116,12 +137,21 @@ where
         let account = Self::retrieve_persisted_account(store)?;
 
         match account {
-            Some(a) => {
-                if owner.get_address() == a.addr() {
-                    info!("Using existing XMTP identity");
-                    return Ok(a);
+            Some(mut a) => {
+                if owner.get_address() != a.addr() {
+                    return Err(ClientBuilderError::StoredAccountMismatch);
                 }
-                Err(ClientBuilderError::StoredAccountMismatch)
+                // The association text version changed when the association started covering
+                // the Ed25519 key, so older accounts need a new signature from the wallet.
+                // `Client::init` publishes the new contact bundle.
+                if !a.assoc.is_current() {
+                    info!("Associating existing XMTP identity with the current text version");
+                    let public_key_bytes = a.olm_account()?.bytes_to_sign();
+                    a.assoc = Self::sign_association(owner, public_key_bytes)?;
+                    store.update_account(&mut store.conn()?, &a)?;
+                }
+                info!("Using existing XMTP identity");
+                Ok(a)
             }
             None => {
                 info!("Creating new XMTP identity");
@@ -132,28 +162,39 @@ where
         }
     }
 
-    /// Fetch Account from persistence
+    /// Fetch Account from persistence, saving any keys generated while loading it
     fn retrieve_persisted_account(
         store: &mut EncryptedMessageStore,
     ) -> Result<Option<Account>, ClientBuilderError> {
         let conn = &mut store.conn()?;
-        let mut accounts = conn.fetch_all()?;
-        Ok(accounts.pop())
+        let mut accounts: Vec<Account> = conn.fetch_all()?;
+        let account = accounts.pop();
+        if let Some(account) = &account {
+            // Otherwise a different key would be generated every time the account is loaded.
+            // `Client::init` publishes the new key.
+            if account.sealed_sender_key_generated_on_load() {
+                info!("Generated a sealed sender key for an existing XMTP identity");
+                store.update_account(conn, account)?;
+            }
+        }
+
+        Ok(account)
     }
 
-    fn sign_new_account(owner: &O) -> Result<Account, ClientBuilderError> {
-        let sign = |public_key_bytes: Vec<u8>| -> Result<Association, AssociationError> {
-            let assoc_text = AssociationText::Static {
-                addr: owner.get_address(),
-                account_public_key: public_key_bytes.clone(),
-            };
+    fn sign_association(
+        owner: &O,
+        public_key_bytes: Vec<u8>,
+    ) -> Result<Association, AssociationError> {
+        let assoc_text = AssociationText::new_static(owner.get_address(), public_key_bytes.clone());
 
-            let signature = owner.sign(&assoc_text.text())?;
+        let signature = owner.sign(&assoc_text.text())?;
 
-            Association::new(public_key_bytes.as_slice(), assoc_text, signature)
-        };
+        Association::new(public_key_bytes.as_slice(), assoc_text, signature)
+    }
 
-        Account::generate(sign).map_err(ClientBuilderError::AccountInitialization)
+    fn sign_new_account(owner: &O) -> Result<Account, ClientBuilderError> {
+        Account::generate(|public_key_bytes| Self::sign_association(owner, public_key_bytes))
+            .map_err(ClientBuilderError::AccountInitialization)
     }
     pub fn build(mut self) -> Result<Client<A>, ClientBuilderError> {
         let api_client = self.api_client.take().unwrap_or_default();
@@ -161,8 +202,13 @@ where
         // Fetch the Account based upon the account strategy.
         let account = match self.account_strategy {
             AccountStrategy::CachedOnly(_) => {
-                let account = Self::retrieve_persisted_account(&mut store)?;
-                account.ok_or(ClientBuilderError::RequiredAccountNotFound)?
+                let account = Self::retrieve_persisted_account(&mut store)?
+                    .ok_or(ClientBuilderError::RequiredAccountNotFound)?;
+                // Without the wallet the account cannot be associated again
+                if !account.assoc.is_current() {
+                    return Err(ClientBuilderError::StoredAccountOutdated);
+                }
+                account
             }
             AccountStrategy::CreateIfNotFound(owner) => {
                 Self::find_or_create_account(&owner, &mut store)?
@@ -176,7 +222,11 @@ where
             last_refreshed: 0,
         })?;
 
-        Ok(Client::new(api_client, self.network, account, store))
+        let mut client = Client::new(api_client, self.network, account, store);
+        client.installation_refresh_interval_ns = self.installation_refresh_interval_ns;
+        client.codecs = self.codecs;
+
+        Ok(client)
     }
 }
 
@@ -188,12 +238,14 @@ mod tests {
     use xmtp_cryptography::utils::generate_local_wallet;
 
     use crate::{
+        account::Account,
+        association::{Association, AssociationText},
         mock_xmtp_api_client::MockXmtpApiClient,
         storage::{EncryptedMessageStore, StorageOption},
-        Client,
+        Client, InboxOwner, Store,
     };
 
-    use super::ClientBuilder;
+    use super::{ClientBuilder, ClientBuilderError};
 
     impl ClientBuilder<MockXmtpApiClient, LocalWallet> {
         pub fn new_test() -> Self {
@@ -276,4 +328,44 @@ mod tests {
         // Ensure the persistence was used to store the generated keys
         assert_eq!(keybytes_a, keybytes_b);
     }
+
+    #[test]
+    fn outdated_association_is_signed_again() {
+        let wallet = generate_local_wallet();
+        let mut store = EncryptedMessageStore::default();
+
+        // An account created before the association text covered the Ed25519 key
+        let account = Account::generate(|public_key_bytes| {
+            let text = AssociationText::Static {
+                addr: wallet.get_address(),
+                account_public_key: public_key_bytes.clone(),
+            };
+            let signature = wallet.sign(&text.text())?;
+            Association::new(&public_key_bytes, text, signature)
+        })
+        .unwrap();
+        assert!(!account.assoc.is_current());
+        account.store(&mut store.conn().unwrap()).unwrap();
+
+        // It can't be associated again without the wallet
+        let err = ClientBuilder::<MockXmtpApiClient, LocalWallet>::new(wallet.get_address().into())
+            .store(store.clone())
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ClientBuilderError::StoredAccountOutdated));
+
+        let client: Client<MockXmtpApiClient> = ClientBuilder::new(wallet.into())
+            .store(store.clone())
+            .build()
+            .unwrap();
+        // The keys are kept, and the association now yields a valid contact
+        assert!(client.account.assoc.is_current());
+        assert_eq!(
+            client.account.identity_keys().curve25519,
+            account.identity_keys().curve25519
+        );
+        client.account.contact();
+        let stored_account = store.get_account().unwrap().unwrap();
+        assert!(stored_account.assoc.is_current());
+    }
 }
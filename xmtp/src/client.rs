@@ -1,29 +1,53 @@
 use core::fmt;
 use std::fmt::Formatter;
+use std::time::Duration;
 
 use diesel::Connection;
+use futures::{stream, Stream};
 use log::{debug, info};
+use prost::Message;
 use thiserror::Error;
-use vodozemac::olm::PreKeyMessage;
+use vodozemac::{olm::PreKeyMessage, Curve25519PublicKey};
+use xmtp_proto::xmtp::v3::message_contents::{VmacFallbackKeyRotation, VmacOneTimeKeyTopupBundle};
 
 use crate::{
     account::Account,
+    association::{AssociationError, InstallationRevocation},
+    codecs::{CodecError, CodecRegistry, MessageContent},
     contact::{Contact, ContactError},
+    conversation::SearchMessagesOptions,
     conversations::Conversations,
     session::SessionManager,
     storage::{
-        now, DbConnection, EncryptedMessageStore, StorageError, StoredInstallation, StoredSession,
-        StoredUser,
+        now, ConversationKind, ConversationState, DbConnection, EncryptedMessageStore,
+        InboundMessage, StorageError, StoredInstallation, StoredMessage, StoredSession, StoredUser,
+    },
+    types::networking::{
+        PagingInfo, PublishRequest, QueryPages, QueryRequest, SortDirection, SubscribeRequest,
+        XmtpApiClient, XmtpApiSubscription,
     },
-    types::networking::{PublishRequest, QueryRequest, XmtpApiClient},
     types::Address,
-    utils::{build_envelope, build_user_contact_topic, key_fingerprint},
-    Store,
+    utils::{
+        build_envelope, build_installation_message_topic, build_installation_one_time_key_topic,
+        build_user_contact_topic, key_fingerprint,
+    },
+    vmac_protos::ProtoWrapper,
+    InboxOwner, Store,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use xmtp_proto::xmtp::message_api::v1::Envelope;
 
-const INSTALLATION_REFRESH_INTERVAL_NS: i64 = 0;
+// Refresh on every send unless configured otherwise through `ClientBuilder`
+pub(crate) const DEFAULT_INSTALLATION_REFRESH_INTERVAL_NS: i64 = 0;
+const QUERY_PAGE_SIZE: u32 = 100;
+const STREAM_POLL_INTERVAL: Duration = Duration::from_millis(100);
+// Olm accounts keep at most 100 private one-time keys, so a batch must leave room for keys which
+// have been claimed but not yet used
+const ONE_TIME_KEY_BATCH_SIZE: usize = 50;
+const ONE_TIME_KEY_TOPUP_THRESHOLD: usize = 10;
+const FALLBACK_KEY_ROTATION_INTERVAL_NS: i64 = 7 * 24 * 60 * 60 * 1_000_000_000;
+// Long enough for prekey messages sent to the previous fallback key to be received
+const FALLBACK_KEY_GRACE_PERIOD_NS: i64 = 2 * 24 * 60 * 60 * 1_000_000_000;
 
 #[derive(Clone, Copy, Default, Debug)]
 pub enum Network {
@@ -45,6 +69,8 @@ pub enum ClientError {
     Ddd(#[from] diesel::result::Error),
     #[error("Query failed: {0}")]
     QueryError(#[from] crate::types::networking::Error),
+    #[error("association error: {0}")]
+    Association(#[from] AssociationError),
     #[error("generic:{0}")]
     Generic(String),
 }
@@ -61,6 +87,15 @@ impl From<&str> for ClientError {
     }
 }
 
+/// A message found by [`Client::search_messages`]
+#[derive(Debug)]
+pub struct MessageSearchHit {
+    pub convo_id: String,
+    pub message: StoredMessage,
+    /// bm25 rank of the match, lower is better
+    pub rank: f64,
+}
+
 pub struct Client<A>
 where
     A: XmtpApiClient,
@@ -69,6 +104,8 @@ where
     pub(crate) network: Network,
     pub(crate) account: Account,
     pub store: EncryptedMessageStore, // Temporarily exposed outside crate for CLI client
+    pub(crate) installation_refresh_interval_ns: i64,
+    pub(crate) codecs: CodecRegistry,
     is_initialized: bool,
 }
 
@@ -96,6 +133,8 @@ where
             network,
             account,
             store,
+            installation_refresh_interval_ns: DEFAULT_INSTALLATION_REFRESH_INTERVAL_NS,
+            codecs: CodecRegistry::default(),
             is_initialized: false,
         }
     }
@@ -108,16 +147,28 @@ where
         self.account.contact().installation_id()
     }
 
+    pub fn codecs(&self) -> &CodecRegistry {
+        &self.codecs
+    }
+
+    /// Decode the content of `message` into one of the types this client has a codec for
+    pub fn decode_message(&self, message: &StoredMessage) -> Result<MessageContent, CodecError> {
+        message.decode_content(&self.codecs)
+    }
+
     pub async fn init(&mut self) -> Result<(), ClientError> {
         let app_contact_bundle = self.account.contact();
         let registered_bundles = self.get_contacts(&self.wallet_address()).await?;
 
-        if !registered_bundles
-            .iter()
-            .any(|contact| contact.installation_id() == app_contact_bundle.installation_id())
-        {
+        // A bundle published before the account had its sealed sender key is replaced
+        if !registered_bundles.iter().any(|contact| {
+            contact.installation_id() == app_contact_bundle.installation_id()
+                && contact.vmac_sealed_sender_key() == app_contact_bundle.vmac_sealed_sender_key()
+        }) {
             self.publish_user_contact().await?;
         }
+        self.topup_one_time_keys_if_low().await?;
+        self.rotate_fallback_key_if_due().await?;
 
         self.is_initialized = true;
 
@@ -129,46 +180,98 @@ where
         Ok(())
     }
 
+    /// Contacts for every installation of the wallet which has not been revoked
     pub async fn get_contacts(&self, wallet_address: &str) -> Result<Vec<Contact>, ClientError> {
+        let (contacts, revocations) = self.get_contacts_and_revocations(wallet_address).await?;
+
+        Ok(contacts
+            .into_iter()
+            .filter(|contact| !revocations.contains_key(&contact.installation_id()))
+            .collect())
+    }
+
+    /// Every contact published for the wallet, along with the revocation time of each
+    /// installation the wallet has revoked
+    async fn get_contacts_and_revocations(
+        &self,
+        wallet_address: &str,
+    ) -> Result<(Vec<Contact>, HashMap<String, i64>), ClientError> {
         let topic = build_user_contact_topic(wallet_address.to_string());
-        let response = self
-            .api_client
-            .query(QueryRequest {
-                content_topics: vec![topic],
-                start_time_ns: 0,
-                end_time_ns: 0,
-                paging_info: None,
-            })
-            .await?;
+        let envelopes = self.query_all_pages(topic, 0).await?;
 
-        let mut contacts = vec![];
-        for envelope in response.envelopes {
-            let contact_bundle = Contact::from_bytes(envelope.message, wallet_address.to_string());
-            match contact_bundle {
-                Ok(bundle) => {
-                    contacts.push(bundle);
-                }
-                Err(err) => {
-                    log::error!("bad contact bundle: {:?}", err);
+        let mut contacts: Vec<Contact> = vec![];
+        let mut revocations: HashMap<String, i64> = HashMap::new();
+        for envelope in envelopes {
+            // Revocations are JSON, which never parses as one of the protos below
+            if let Ok(revocation) = InstallationRevocation::from_bytes(&envelope.message) {
+                match revocation.verify(wallet_address) {
+                    Ok(()) => {
+                        let revoked_at_ns = revocations
+                            .entry(revocation.installation_id)
+                            .or_insert(revocation.revoked_at_ns);
+                        *revoked_at_ns = (*revoked_at_ns).min(revocation.revoked_at_ns);
+                    }
+                    Err(err) => log::error!("bad installation revocation: {:?}", err),
                 }
+                continue;
+            }
+            let contact_bundle =
+                Contact::from_bytes(envelope.message.clone(), wallet_address.to_string());
+            match contact_bundle {
+                // An installation republishes its bundle when it gains keys, and envelopes come
+                // oldest first, so later bundles replace earlier ones unless they are replays
+                Ok(bundle) => match contacts
+                    .iter_mut()
+                    .find(|contact| contact.installation_id() == bundle.installation_id())
+                {
+                    Some(contact)
+                        if bundle.fallback_key_created_ns()
+                            >= contact.fallback_key_created_ns() =>
+                    {
+                        *contact = bundle
+                    }
+                    Some(_) => log::error!("stale contact bundle"),
+                    None => contacts.push(bundle),
+                },
+                // The contact topic also carries fallback key rotations for published bundles
+                Err(err) => match VmacFallbackKeyRotation::decode(envelope.message.as_slice()) {
+                    Ok(rotation) => Self::apply_fallback_key_rotation(&mut contacts, rotation),
+                    Err(_) => log::error!("bad contact bundle: {:?}", err),
+                },
             }
         }
 
-        Ok(contacts)
+        Ok((contacts, revocations))
     }
 
-    pub fn get_session(
-        &self,
-        conn: &mut DbConnection,
-        contact: &Contact,
-    ) -> Result<SessionManager, ClientError> {
-        let existing_session = self
-            .store
-            .get_latest_session_for_installation(&contact.installation_id(), conn)?;
-        match existing_session {
-            Some(i) => Ok(SessionManager::try_from(&i)?),
-            None => self.create_outbound_session(conn, contact),
+    fn apply_fallback_key_rotation(contacts: &mut [Contact], rotation: VmacFallbackKeyRotation) {
+        let contact = contacts
+            .iter_mut()
+            .find(|contact| contact.identity_key().ok() == rotation.identity_key);
+        match contact {
+            Some(contact) => {
+                if let Err(err) = contact.apply_fallback_key_rotation(rotation) {
+                    log::error!("bad fallback key rotation: {:?}", err);
+                }
+            }
+            None => log::error!("fallback key rotation for unknown installation"),
+        }
+    }
+
+    /// The latest session with the contact's installation, or else a new one using one of its
+    /// one-time keys. Claiming the key is a network call, so callers must not hold a connection
+    /// or be in a transaction.
+    pub async fn get_session(&self, contact: &Contact) -> Result<SessionManager, ClientError> {
+        let existing_session = self.store.get_latest_session_for_installation(
+            &contact.installation_id(),
+            &mut self.store.conn()?,
+        )?;
+        if let Some(session) = existing_session {
+            return Ok(SessionManager::try_from(&session)?);
         }
+
+        let one_time_key = self.claim_one_time_key(contact).await?;
+        self.create_outbound_session(&mut self.store.conn()?, contact, one_time_key)
     }
 
     pub fn my_other_devices(&self, conn: &mut DbConnection) -> Result<Vec<Contact>, ClientError> {
@@ -179,27 +282,34 @@ where
             .collect())
     }
 
+    /// Refresh the user's installations if they haven't been refreshed within the configured
+    /// interval, returning any newly discovered installations
     pub async fn refresh_user_installations_if_stale(
         &self,
         user_address: &str,
-    ) -> Result<(), ClientError> {
+    ) -> Result<Vec<Contact>, ClientError> {
         let user = self.store.get_user(user_address)?;
-        if user.is_none() || user.unwrap().last_refreshed < now() - INSTALLATION_REFRESH_INTERVAL_NS
+        if user.is_none()
+            || user.unwrap().last_refreshed < now() - self.installation_refresh_interval_ns
         {
-            self.refresh_user_installations(user_address).await?;
+            return self.refresh_user_installations(user_address).await;
         }
 
-        Ok(())
+        Ok(vec![])
     }
 
-    /// Fetch Installations from the Network and create unintialized sessions for newly discovered contacts
+    /// Fetch Installations from the Network and create unintialized sessions for newly discovered contacts.
+    /// Returns the contacts of the newly discovered installations, excluding revoked ones.
     // TODO: Reduce Visibility
-    pub async fn refresh_user_installations(&self, user_address: &str) -> Result<(), ClientError> {
+    pub async fn refresh_user_installations(
+        &self,
+        user_address: &str,
+    ) -> Result<Vec<Contact>, ClientError> {
         // Store the timestamp of when the refresh process begins
         let refresh_timestamp = now();
 
         let self_install_id = key_fingerprint(&self.account.identity_keys().curve25519);
-        let contacts = self.get_contacts(user_address).await?;
+        let (contacts, revocations) = self.get_contacts_and_revocations(user_address).await?;
         debug!(
             "Fetched contacts for address {}: {:?}",
             user_address, contacts
@@ -212,16 +322,39 @@ where
             .map(|v| (v.installation_id.clone(), v))
             .collect::<HashMap<_, _>>();
 
+        // Known installations pick up the fallback key rotations published since they were seen
+        let updated_contacts: Vec<(String, Vec<u8>)> = contacts
+            .iter()
+            .filter_map(|contact| {
+                let install = installation_map.get(&contact.installation_id())?;
+                let contact_bytes: Vec<u8> = contact.try_into().ok()?;
+                (install.contact != contact_bytes)
+                    .then(|| (install.installation_id.clone(), contact_bytes))
+            })
+            .collect();
+
         let new_installs: Vec<StoredInstallation> = contacts
             .iter()
             .filter(|contact| self_install_id != contact.installation_id())
             .filter(|contact| !installation_map.contains_key(&contact.installation_id()))
             .filter_map(|contact| StoredInstallation::new(contact).ok())
+            .map(|mut install| {
+                install.expires_at_ns = revocations.get(&install.installation_id).copied();
+                install
+            })
             .collect();
         debug!(
             "New installs for address {}: {:?}",
             user_address, new_installs
         );
+        let mut discovered = vec![];
+        let mut one_time_keys = HashMap::new();
+        for install in new_installs.iter().filter(|i| i.expires_at_ns.is_none()) {
+            let contact = install.get_contact()?;
+            let one_time_key = self.claim_one_time_key(&contact).await?;
+            one_time_keys.insert(install.installation_id.clone(), one_time_key);
+            discovered.push(contact);
+        }
 
         self.store
             .conn()?
@@ -236,7 +369,18 @@ where
                 )?;
                 for install in new_installs {
                     info!("Saving Install {}", install.installation_id);
-                    let session = self.create_uninitialized_session(&install.get_contact()?)?;
+                    // Revoked installations are remembered, but never sent messages
+                    if install.expires_at_ns.is_some() {
+                        self.store
+                            .insert_or_ignore_install(install, transaction_manager)?;
+                        continue;
+                    }
+                    let one_time_key = one_time_keys
+                        .get(&install.installation_id)
+                        .cloned()
+                        .flatten();
+                    let session =
+                        self.create_uninitialized_session(&install.get_contact()?, one_time_key)?;
 
                     self.store
                         .insert_or_ignore_install(install, transaction_manager)?;
@@ -246,6 +390,22 @@ where
                     )?;
                 }
 
+                for (installation_id, contact_bytes) in updated_contacts {
+                    self.store.update_installation_contact(
+                        transaction_manager,
+                        &installation_id,
+                        contact_bytes,
+                    )?;
+                }
+
+                for (installation_id, revoked_at_ns) in revocations.iter() {
+                    self.store.set_installation_expiry(
+                        transaction_manager,
+                        installation_id,
+                        *revoked_at_ns,
+                    )?;
+                }
+
                 self.store.update_user_refresh_timestamp(
                     transaction_manager,
                     user_address,
@@ -255,7 +415,72 @@ where
                 Ok(())
             })?;
 
-        Ok(())
+        if !discovered.is_empty() {
+            if let Err(err) = Conversations::invite_new_installations(self, &discovered).await {
+                log::error!("Could not invite new installations: {:?}", err);
+            }
+        }
+
+        Ok(discovered)
+    }
+
+    /// Refresh the stale installations of this wallet and of every user it has a conversation
+    /// with, returning any newly discovered installations
+    pub async fn refresh_conversation_installations(&self) -> Result<Vec<Contact>, ClientError> {
+        let mut user_addresses = vec![self.wallet_address()];
+        {
+            let conn = &mut self.store.conn()?;
+            let convos = self.store.get_conversations(
+                conn,
+                vec![
+                    ConversationState::Uninitialized,
+                    ConversationState::Invited,
+                    ConversationState::InviteReceived,
+                ],
+            )?;
+            for convo in convos {
+                if convo.convo_kind == ConversationKind::Group {
+                    user_addresses
+                        .extend(self.store.get_conversation_members(conn, &convo.convo_id)?);
+                } else {
+                    user_addresses.push(convo.peer_address);
+                }
+            }
+        }
+        user_addresses.sort();
+        user_addresses.dedup();
+
+        let mut discovered = vec![];
+        for user_address in user_addresses.iter() {
+            discovered.extend(
+                self.refresh_user_installations_if_stale(user_address)
+                    .await?,
+            );
+        }
+
+        Ok(discovered)
+    }
+
+    /// Refresh the installations of every conversation peer each `interval`, yielding newly
+    /// discovered installations so that they can be invited to existing conversations. Refreshing
+    /// stops when the stream is dropped.
+    pub fn stream_installation_refreshes(
+        &self,
+        interval: Duration,
+    ) -> impl Stream<Item = Vec<Contact>> + '_ {
+        stream::unfold(false, move |has_yielded| async move {
+            if has_yielded {
+                tokio::time::sleep(interval).await;
+            }
+            loop {
+                match self.refresh_conversation_installations().await {
+                    Ok(discovered) if !discovered.is_empty() => return Some((discovered, true)),
+                    Ok(_) => {}
+                    Err(err) => log::error!("Could not refresh installations: {:?}", err),
+                }
+                tokio::time::sleep(interval).await;
+            }
+        })
     }
 
     pub fn get_contacts_from_db(
@@ -264,9 +489,11 @@ where
         wallet_address: &str,
     ) -> Result<Vec<Contact>, ClientError> {
         let installations = self.store.get_installations(conn, wallet_address)?;
+        let now_ns = now();
 
         Ok(installations
             .into_iter()
+            .filter(|i| !i.is_expired(now_ns))
             .filter_map(|i| i.get_contact().ok())
             .collect())
     }
@@ -274,8 +501,9 @@ where
     pub fn create_uninitialized_session(
         &self,
         contact: &Contact,
+        one_time_key: Option<Curve25519PublicKey>,
     ) -> Result<SessionManager, ClientError> {
-        let olm_session = self.account.create_outbound_session(contact);
+        let olm_session = self.account.create_outbound_session(contact, one_time_key);
         Ok(SessionManager::from_olm_session(olm_session, contact)?)
     }
 
@@ -283,8 +511,9 @@ where
         &self,
         conn: &mut DbConnection,
         contact: &Contact,
+        one_time_key: Option<Curve25519PublicKey>,
     ) -> Result<SessionManager, ClientError> {
-        let olm_session = self.account.create_outbound_session(contact);
+        let olm_session = self.account.create_outbound_session(contact, one_time_key);
         let session = SessionManager::from_olm_session(olm_session, contact)?;
 
         session.store(conn)?;
@@ -305,16 +534,179 @@ where
 
         let session = SessionManager::from_olm_session(create_result.session, contact)?;
 
-        if let Err(e) = session.store(conn) {
-            match e {
-                StorageError::DieselResultError(_) => log::warn!("Session Already exists"), // TODO: Some thought is needed here, is this a critical error which should unroll?
-                other_error => return Err(other_error.into()),
-            }
+        // The account is saved with the session, since creating it may have consumed a one-time key
+        let inserted = self.store.insert_session_with_account(
+            conn,
+            StoredSession::try_from(&session)?,
+            &self.account,
+        )?;
+        if !inserted {
+            log::warn!("Session already exists for {}", contact.installation_id());
         }
 
         Ok((session, create_result.plaintext))
     }
 
+    /// Claim one of the contact's one-time keys from the network, if any are left
+    async fn claim_one_time_key(
+        &self,
+        contact: &Contact,
+    ) -> Result<Option<Curve25519PublicKey>, ClientError> {
+        let topic = build_installation_one_time_key_topic(&contact.installation_id());
+        let envelope = match self.api_client.claim_one_time_key(topic).await? {
+            Some(envelope) => envelope,
+            None => return Ok(None),
+        };
+
+        let bundle = match VmacOneTimeKeyTopupBundle::decode(envelope.message.as_slice()) {
+            Ok(bundle) => bundle,
+            Err(err) => {
+                log::error!("bad one-time key bundle: {:?}", err);
+                return Ok(None);
+            }
+        };
+        // Only keys published under the contact's own identity key can be trusted
+        if bundle.identity_key != Some(contact.identity_key()?) {
+            log::error!(
+                "one-time key bundle for {} has a mismatched identity key",
+                contact.installation_id()
+            );
+            return Ok(None);
+        }
+
+        let one_time_key = match bundle.one_time_keys.into_iter().next() {
+            Some(key) => key,
+            None => return Ok(None),
+        };
+        if let Err(err) = contact.verify_linked_key(&one_time_key) {
+            log::error!(
+                "bad one-time key for {}: {:?}",
+                contact.installation_id(),
+                err
+            );
+            return Ok(None);
+        }
+
+        Ok(Some(
+            ProtoWrapper {
+                proto: one_time_key,
+            }
+            .into(),
+        ))
+    }
+
+    /// Count the one-time keys this installation still has available on the network
+    async fn count_published_one_time_keys(&self) -> Result<usize, ClientError> {
+        let topic = build_installation_one_time_key_topic(&self.installation_id());
+        let envelopes = self.query_all_pages(topic, 0).await?;
+
+        Ok(envelopes
+            .iter()
+            .filter_map(|e| VmacOneTimeKeyTopupBundle::decode(e.message.as_slice()).ok())
+            .map(|bundle| bundle.one_time_keys.len())
+            .sum())
+    }
+
+    /// Rotate the fallback key on schedule, and drop the previous one after its grace period
+    pub async fn rotate_fallback_key_if_due(&self) -> Result<(), ClientError> {
+        if self.account.forget_expired_fallback_key(now()) {
+            self.store
+                .update_account(&mut self.store.conn()?, &self.account)?;
+        }
+        if self
+            .account
+            .fallback_key_rotation_due(now(), FALLBACK_KEY_ROTATION_INTERVAL_NS)
+        {
+            self.rotate_fallback_key().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn rotate_fallback_key(&self) -> Result<(), ClientError> {
+        let rotation = self
+            .account
+            .rotate_fallback_key(now() + FALLBACK_KEY_GRACE_PERIOD_NS);
+        // Save the new private key before publishing, so that the published key is never lost
+        self.store
+            .update_account(&mut self.store.conn()?, &self.account)?;
+        self.api_client
+            .publish(
+                "".to_string(),
+                PublishRequest {
+                    envelopes: vec![build_envelope(
+                        build_user_contact_topic(self.wallet_address()),
+                        rotation.encode_to_vec(),
+                    )],
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Revoke one of this wallet's installations, such as a lost device. Other clients stop
+    /// sending messages to it once they next refresh the wallet's installations.
+    pub async fn revoke_installation(
+        &self,
+        owner: &impl InboxOwner,
+        installation_id: &str,
+    ) -> Result<(), ClientError> {
+        if owner.get_address() != self.wallet_address() {
+            return Err(ClientError::Generic(
+                "installations can only be revoked by their own wallet".to_string(),
+            ));
+        }
+        let revocation = InstallationRevocation::new(owner, installation_id.to_string(), now())?;
+
+        self.api_client
+            .publish(
+                "".to_string(),
+                PublishRequest {
+                    envelopes: vec![build_envelope(
+                        build_user_contact_topic(self.wallet_address()),
+                        revocation.to_bytes().map_err(|e| e.to_string())?,
+                    )],
+                },
+            )
+            .await?;
+        self.store.set_installation_expiry(
+            &mut self.store.conn()?,
+            installation_id,
+            revocation.revoked_at_ns,
+        )?;
+
+        Ok(())
+    }
+
+    /// Publish a new batch of one-time keys once the number left on the network runs low
+    pub async fn topup_one_time_keys_if_low(&self) -> Result<(), ClientError> {
+        let remaining = self.count_published_one_time_keys().await?;
+        if remaining >= ONE_TIME_KEY_TOPUP_THRESHOLD {
+            return Ok(());
+        }
+
+        let bundle = self
+            .account
+            .generate_one_time_keys(ONE_TIME_KEY_BATCH_SIZE - remaining);
+        // Save the private keys before publishing, so that no published key can be lost
+        self.store
+            .update_account(&mut self.store.conn()?, &self.account)?;
+        self.api_client
+            .publish(
+                "".to_string(),
+                PublishRequest {
+                    envelopes: vec![build_envelope(
+                        build_installation_one_time_key_topic(&self.installation_id()),
+                        bundle.encode_to_vec(),
+                    )],
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
     async fn publish_user_contact(&self) -> Result<(), ClientError> {
         let envelope = self.build_contact_envelope()?;
         self.api_client
@@ -345,18 +737,129 @@ where
         start_time: u64,
         topic: String,
     ) -> Result<Vec<Envelope>, ClientError> {
-        let response = self
+        self.query_all_pages(topic, start_time).await
+    }
+
+    async fn query_all_pages(
+        &self,
+        topic: String,
+        start_time: u64,
+    ) -> Result<Vec<Envelope>, ClientError> {
+        let request = QueryRequest {
+            content_topics: vec![topic],
+            start_time_ns: start_time,
+            end_time_ns: 0,
+            paging_info: Some(PagingInfo {
+                limit: QUERY_PAGE_SIZE,
+                cursor: None,
+                direction: SortDirection::Ascending as i32,
+            }),
+        };
+
+        Ok(QueryPages::new(&self.api_client, request)
+            .collect_all()
+            .await?)
+    }
+
+    /// Search the text of received and sent messages in every conversation, best matches first.
+    /// A message matches if it has words starting with each word of `query`.
+    pub fn search_messages(
+        &self,
+        query: &str,
+        opts: &SearchMessagesOptions,
+    ) -> Result<Vec<MessageSearchHit>, ClientError> {
+        let hits = self
+            .store
+            .search_messages(&mut self.store.conn()?, query, opts)?;
+
+        Ok(hits
+            .into_iter()
+            .map(|(message, rank)| MessageSearchHit {
+                convo_id: message.convo_id.clone(),
+                message,
+                rank,
+            })
+            .collect())
+    }
+
+    /// Subscribe to this installation's message topic and yield each message once it has been
+    /// decrypted and stored. The stream ends when the underlying subscription is closed.
+    pub async fn stream_messages(
+        &self,
+    ) -> Result<impl Stream<Item = StoredMessage> + '_, ClientError> {
+        let subscription = self
             .api_client
-            .query(QueryRequest {
-                content_topics: vec![topic],
-                start_time_ns: start_time,
-                end_time_ns: 0,
-                // TODO: Pagination
-                paging_info: None,
+            .subscribe(SubscribeRequest {
+                content_topics: vec![build_installation_message_topic(&self.installation_id())],
             })
             .await?;
+        // Resent copies of a message are linked to the original, which is never yielded twice
+        let last_message_id = self.store.get_latest_message_id(&mut self.store.conn()?)?;
 
-        Ok(response.envelopes)
+        Ok(stream::unfold(
+            (subscription, VecDeque::new(), last_message_id),
+            move |(subscription, mut buffered, mut last_message_id)| async move {
+                loop {
+                    if let Some(message) = buffered.pop_front() {
+                        return Some((message, (subscription, buffered, last_message_id)));
+                    }
+                    if subscription.is_closed() {
+                        return None;
+                    }
+
+                    let envelopes = subscription.get_messages();
+                    if envelopes.is_empty() {
+                        tokio::time::sleep(STREAM_POLL_INTERVAL).await;
+                        continue;
+                    }
+
+                    match self.process_streamed_envelopes(envelopes, last_message_id) {
+                        Ok(messages) => {
+                            if let Some(latest) = messages.last() {
+                                last_message_id = latest.id;
+                            }
+                            buffered.extend(messages);
+                        }
+                        Err(err) => log::error!("Could not process streamed messages: {:?}", err),
+                    }
+                    // Sends the delivery receipts for what was just received
+                    if let Err(err) = Conversations::process_outbound_messages(self).await {
+                        log::error!("Could not process outbound messages: {:?}", err);
+                    }
+                }
+            },
+        ))
+    }
+
+    // Only messages decrypted from envelopes this subscription saved are returned, not those that
+    // were stored by a concurrent `receive` or another subscription
+    fn process_streamed_envelopes(
+        &self,
+        envelopes: Vec<Envelope>,
+        last_message_id: i32,
+    ) -> Result<Vec<StoredMessage>, ClientError> {
+        let mut inbound_message_ids = vec![];
+        {
+            let conn = &mut self.store.conn()?;
+            for envelope in envelopes {
+                let inbound_message: InboundMessage = envelope.into();
+                let inbound_message_id = inbound_message.id.clone();
+                if self.store.save_inbound_message(conn, inbound_message)? {
+                    inbound_message_ids.push(inbound_message_id);
+                }
+            }
+        } // Release the connection before processing, which acquires its own
+        if inbound_message_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        Conversations::process_inbound_messages(self)?;
+
+        Ok(self.store.get_received_messages_from_inbound(
+            &mut self.store.conn()?,
+            &inbound_message_ids,
+            last_message_id,
+        )?)
     }
 
     /// Search network for a specific InstallationContact
@@ -384,8 +887,25 @@ mod tests {
     use xmtp_proto::xmtp::v3::message_contents::vmac_unsigned_public_key::Union::Curve25519;
     use xmtp_proto::xmtp::v3::message_contents::vmac_unsigned_public_key::VodozemacCurve25519;
 
-    use crate::test_utils::test_utils::gen_test_client;
-    use crate::ClientBuilder;
+    use std::time::Duration;
+
+    use diesel::{sql_types::Binary, RunQueryDsl};
+    use futures::StreamExt;
+    use serde_json::json;
+    use xmtp_cryptography::utils::generate_local_wallet;
+
+    use super::{ONE_TIME_KEY_BATCH_SIZE, ONE_TIME_KEY_TOPUP_THRESHOLD};
+    use crate::codecs::reaction::{Reaction, ReactionAction, ReactionSchema};
+    use crate::conversation::{SearchMessagesOptions, SecretConversation};
+    use crate::conversations::Conversations;
+    use crate::mock_xmtp_api_client::MockXmtpApiClient;
+    use crate::storage::EncryptedMessageStore;
+    use crate::test_utils::test_utils::{
+        gen_test_client, gen_test_client_on_network, gen_test_conversation, gen_two_test_clients,
+    };
+    use crate::types::networking::{PublishRequest, XmtpApiClient};
+    use crate::utils::build_envelope;
+    use crate::{Client, ClientBuilder};
 
     #[tokio::test]
     async fn registration() {
@@ -450,6 +970,357 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn download_latest_from_topic_paginates() {
+        let client = ClientBuilder::new_test().build().unwrap();
+        let topic = "test-topic".to_string();
+        let envelopes = (0..250u32)
+            .map(|i| build_envelope(topic.clone(), i.to_be_bytes().to_vec()))
+            .collect();
+        client
+            .api_client
+            .publish("".to_string(), PublishRequest { envelopes })
+            .await
+            .unwrap();
+
+        let downloaded = client.download_latest_from_topic(0, topic).await.unwrap();
+        assert_eq!(downloaded.len(), 250);
+    }
+
+    #[tokio::test]
+    async fn one_time_keys_are_claimed_and_topped_up() {
+        let (alice, mut bob) = gen_two_test_clients().await;
+        assert_eq!(
+            bob.count_published_one_time_keys().await.unwrap(),
+            ONE_TIME_KEY_BATCH_SIZE
+        );
+
+        // Discovering Bob's installation claims one of his keys for the new session
+        alice
+            .refresh_user_installations(&bob.wallet_address())
+            .await
+            .unwrap();
+        let mut remaining = ONE_TIME_KEY_BATCH_SIZE - 1;
+        assert_eq!(
+            bob.count_published_one_time_keys().await.unwrap(),
+            remaining
+        );
+
+        while remaining >= ONE_TIME_KEY_TOPUP_THRESHOLD {
+            assert!(alice
+                .claim_one_time_key(&bob.account.contact())
+                .await
+                .unwrap()
+                .is_some());
+            remaining -= 1;
+        }
+        bob.topup_one_time_keys_if_low().await.unwrap();
+        assert_eq!(
+            bob.count_published_one_time_keys().await.unwrap(),
+            ONE_TIME_KEY_BATCH_SIZE
+        );
+
+        // The private halves of the new keys were persisted
+        let stored_account = bob.store.get_account().unwrap().unwrap();
+        assert_eq!(json!(stored_account), json!(bob.account));
+    }
+
+    #[tokio::test]
+    async fn sealed_sender_key_is_published_for_older_accounts() {
+        let wallet = generate_local_wallet();
+        let api_client = MockXmtpApiClient::new();
+        let mut store = EncryptedMessageStore::default();
+
+        let mut client: Client<MockXmtpApiClient> = ClientBuilder::new(wallet.clone().into())
+            .api_client(api_client.clone())
+            .store(store.clone())
+            .build()
+            .unwrap();
+        client.init().await.unwrap();
+        let installation_id = client.installation_id();
+        drop(client);
+
+        // Persist the account as it was before it had a sealed sender key
+        let mut account = json!(store.get_account().unwrap().unwrap());
+        account.as_object_mut().unwrap().remove("sealed_sender_key");
+        diesel::sql_query("UPDATE accounts SET serialized_key = ?")
+            .bind::<Binary, _>(serde_json::to_vec(&account).unwrap())
+            .execute(&mut store.conn().unwrap())
+            .unwrap();
+
+        let mut client: Client<MockXmtpApiClient> = ClientBuilder::new(wallet.into())
+            .api_client(api_client)
+            .store(store.clone())
+            .build()
+            .unwrap();
+        client.init().await.unwrap();
+        let sealed_sender_key = client.account.contact().vmac_sealed_sender_key();
+
+        // The generated key was saved, and its bundle replaced the one published before
+        let stored_account = store.get_account().unwrap().unwrap();
+        assert_eq!(
+            stored_account.contact().vmac_sealed_sender_key(),
+            sealed_sender_key
+        );
+        let contacts = client.get_contacts(&client.wallet_address()).await.unwrap();
+        assert_eq!(contacts.len(), 1);
+        assert_eq!(contacts[0].installation_id(), installation_id);
+        assert_eq!(contacts[0].vmac_sealed_sender_key(), sealed_sender_key);
+    }
+
+    #[tokio::test]
+    async fn fallback_key_rotation_is_published() {
+        let (alice, mut bob) = gen_two_test_clients().await;
+        let original_fallback_key = bob.account.contact().vmac_fallback_key();
+        alice
+            .refresh_user_installations(&bob.wallet_address())
+            .await
+            .unwrap();
+
+        // Not due yet for a freshly generated account
+        bob.rotate_fallback_key_if_due().await.unwrap();
+        assert_eq!(
+            bob.account.contact().vmac_fallback_key(),
+            original_fallback_key
+        );
+
+        bob.rotate_fallback_key().await.unwrap();
+        let rotated_fallback_key = bob.account.contact().vmac_fallback_key();
+        assert_ne!(rotated_fallback_key, original_fallback_key);
+
+        // Other clients see the rotated key on the existing installation
+        let contacts = alice.get_contacts(&bob.wallet_address()).await.unwrap();
+        assert_eq!(contacts.len(), 1);
+        assert_eq!(contacts[0].installation_id(), bob.installation_id());
+        assert_eq!(contacts[0].vmac_fallback_key(), rotated_fallback_key);
+
+        // And the stored installation is updated on the next refresh
+        alice
+            .refresh_user_installations(&bob.wallet_address())
+            .await
+            .unwrap();
+        let conn = &mut alice.store.conn().unwrap();
+        let contacts = alice
+            .get_contacts_from_db(conn, &bob.wallet_address())
+            .unwrap();
+        assert_eq!(contacts.len(), 1);
+        assert_eq!(contacts[0].vmac_fallback_key(), rotated_fallback_key);
+
+        let stored_account = bob.store.get_account().unwrap().unwrap();
+        assert_eq!(json!(stored_account), json!(bob.account));
+    }
+
+    #[tokio::test]
+    async fn revoked_installation_is_skipped() {
+        let wallet = generate_local_wallet();
+        let api_client = MockXmtpApiClient::new();
+        let mut alice_phone: Client<MockXmtpApiClient> = ClientBuilder::new(wallet.clone().into())
+            .api_client(api_client.clone())
+            .build()
+            .unwrap();
+        alice_phone.init().await.unwrap();
+        let mut alice_laptop: Client<MockXmtpApiClient> = ClientBuilder::new(wallet.clone().into())
+            .api_client(api_client.clone())
+            .build()
+            .unwrap();
+        alice_laptop.init().await.unwrap();
+        let bob = gen_test_client_on_network(api_client).await;
+        let alice = alice_laptop.wallet_address();
+
+        bob.refresh_user_installations(&alice).await.unwrap();
+        alice_laptop
+            .refresh_user_installations(&alice)
+            .await
+            .unwrap();
+        {
+            let conn = &mut bob.store.conn().unwrap();
+            assert_eq!(bob.get_contacts_from_db(conn, &alice).unwrap().len(), 2);
+            assert_eq!(
+                bob.store.get_latest_sessions(&alice, conn).unwrap().len(),
+                2
+            );
+        }
+
+        // Only the wallet which owns the installation can revoke it
+        assert!(bob
+            .revoke_installation(&wallet, &alice_phone.installation_id())
+            .await
+            .is_err());
+        alice_laptop
+            .revoke_installation(&wallet, &alice_phone.installation_id())
+            .await
+            .unwrap();
+        assert!(alice_laptop
+            .my_other_devices(&mut alice_laptop.store.conn().unwrap())
+            .unwrap()
+            .is_empty());
+
+        let contacts = bob.get_contacts(&alice).await.unwrap();
+        assert_eq!(contacts.len(), 1);
+        assert_eq!(
+            contacts[0].installation_id(),
+            alice_laptop.installation_id()
+        );
+
+        // Refreshing marks the known installation as expired, so it is skipped in fan-out
+        bob.refresh_user_installations(&alice).await.unwrap();
+        let conn = &mut bob.store.conn().unwrap();
+        let contacts = bob.get_contacts_from_db(conn, &alice).unwrap();
+        assert_eq!(contacts.len(), 1);
+        assert_eq!(
+            contacts[0].installation_id(),
+            alice_laptop.installation_id()
+        );
+        let sessions = bob.store.get_latest_sessions(&alice, conn).unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(
+            sessions[0].peer_installation_id,
+            alice_laptop.installation_id()
+        );
+    }
+
+    #[tokio::test]
+    async fn refresh_interval_is_configurable() {
+        let wallet = generate_local_wallet();
+        let api_client = MockXmtpApiClient::new();
+        let mut bob_phone: Client<MockXmtpApiClient> = ClientBuilder::new(wallet.clone().into())
+            .api_client(api_client.clone())
+            .build()
+            .unwrap();
+        bob_phone.init().await.unwrap();
+        let mut alice: Client<MockXmtpApiClient> = ClientBuilder::new_test()
+            .api_client(api_client.clone())
+            .installation_refresh_interval_ns(60 * 60 * 1_000_000_000)
+            .build()
+            .unwrap();
+        alice.init().await.unwrap();
+        let bob = bob_phone.wallet_address();
+
+        let discovered = alice
+            .refresh_user_installations_if_stale(&bob)
+            .await
+            .unwrap();
+        assert_eq!(discovered.len(), 1);
+        assert_eq!(discovered[0].installation_id(), bob_phone.installation_id());
+
+        let mut bob_laptop: Client<MockXmtpApiClient> = ClientBuilder::new(wallet.into())
+            .api_client(api_client)
+            .build()
+            .unwrap();
+        bob_laptop.init().await.unwrap();
+
+        // Refreshed too recently to go back to the network
+        assert!(alice
+            .refresh_user_installations_if_stale(&bob)
+            .await
+            .unwrap()
+            .is_empty());
+        alice.installation_refresh_interval_ns = 0;
+        let discovered = alice
+            .refresh_user_installations_if_stale(&bob)
+            .await
+            .unwrap();
+        assert_eq!(discovered.len(), 1);
+        assert_eq!(
+            discovered[0].installation_id(),
+            bob_laptop.installation_id()
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_installation_refreshes() {
+        let wallet = generate_local_wallet();
+        let api_client = MockXmtpApiClient::new();
+        let mut bob_phone: Client<MockXmtpApiClient> = ClientBuilder::new(wallet.clone().into())
+            .api_client(api_client.clone())
+            .build()
+            .unwrap();
+        bob_phone.init().await.unwrap();
+        let alice = gen_test_client_on_network(api_client.clone()).await;
+        SecretConversation::new(&alice, bob_phone.wallet_address()).unwrap();
+        alice
+            .refresh_user_installations(&bob_phone.wallet_address())
+            .await
+            .unwrap();
+
+        let mut bob_laptop: Client<MockXmtpApiClient> = ClientBuilder::new(wallet.into())
+            .api_client(api_client)
+            .build()
+            .unwrap();
+        bob_laptop.init().await.unwrap();
+
+        let stream = alice.stream_installation_refreshes(Duration::from_millis(10));
+        futures::pin_mut!(stream);
+        let discovered = stream.next().await.unwrap();
+        assert_eq!(discovered.len(), 1);
+        assert_eq!(
+            discovered[0].installation_id(),
+            bob_laptop.installation_id()
+        );
+    }
+
+    #[tokio::test]
+    async fn search_messages() {
+        let (alice, bob) = gen_two_test_clients().await;
+        let conversation = gen_test_conversation(&alice, &bob.wallet_address()).await;
+        conversation.send_text("Lunch at noon?").await.unwrap();
+        conversation.send_text("The meeting moved").await.unwrap();
+        conversation
+            .send_content(Reaction {
+                reference: "message_id".to_string(),
+                action: ReactionAction::Added,
+                content: "lunch".to_string(),
+                schema: ReactionSchema::Custom,
+            })
+            .await
+            .unwrap();
+        Conversations::receive(&bob).unwrap();
+
+        let texts = |client: &Client<MockXmtpApiClient>, query: &str| -> Vec<String> {
+            client
+                .search_messages(query, &SearchMessagesOptions::default())
+                .unwrap()
+                .iter()
+                .map(|hit| hit.message.get_text().unwrap())
+                .collect()
+        };
+        // Only text content is indexed, not the fallback of the reaction
+        assert_eq!(texts(&bob, "lunch"), vec!["Lunch at noon?"]);
+        assert_eq!(texts(&bob, "lun NOON"), vec!["Lunch at noon?"]);
+        assert_eq!(texts(&alice, "meeting"), vec!["The meeting moved"]);
+        assert!(texts(&bob, "dinner").is_empty());
+        assert!(texts(&bob, "  ").is_empty());
+        // Search syntax is matched literally rather than failing
+        assert!(texts(&bob, "\"meeting OR NEAR(").is_empty());
+
+        let hits = bob
+            .search_messages(
+                "the",
+                &SearchMessagesOptions {
+                    limit: Some(1),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].convo_id, conversation.convo_id());
+
+        let from = |sender_address: String| SearchMessagesOptions {
+            sender_addresses: Some(vec![sender_address]),
+            ..Default::default()
+        };
+        assert_eq!(
+            bob.search_messages("lunch", &from(alice.wallet_address()))
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(bob
+            .search_messages("lunch", &from(bob.wallet_address()))
+            .unwrap()
+            .is_empty());
+    }
+
     #[tokio::test]
     async fn test_roundtrip_encrypt() {}
 }
@@ -0,0 +1,91 @@
+use super::{CodecError, ContentCodec};
+use std::collections::HashMap;
+use xmtp_proto::xmtp::message_contents::{ContentTypeId, EncodedContent};
+
+/// A file sent inline with the message
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attachment {
+    pub filename: String,
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+pub struct AttachmentCodec {}
+impl AttachmentCodec {
+    const AUTHORITY_ID: &str = "xmtp.org";
+    const TYPE_ID: &str = "attachment";
+    const FILENAME_KEY: &str = "filename";
+    const MIME_TYPE_KEY: &str = "mimeType";
+}
+
+impl ContentCodec<Attachment> for AttachmentCodec {
+    fn content_type() -> ContentTypeId {
+        ContentTypeId {
+            authority_id: AttachmentCodec::AUTHORITY_ID.to_string(),
+            type_id: AttachmentCodec::TYPE_ID.to_string(),
+            version_major: 1,
+            version_minor: 0,
+        }
+    }
+
+    fn encode(attachment: Attachment) -> Result<EncodedContent, CodecError> {
+        Ok(EncodedContent {
+            r#type: Some(AttachmentCodec::content_type()),
+            fallback: Some(format!(
+                "Can’t display \"{}\". This app doesn’t support attachments.",
+                attachment.filename
+            )),
+            parameters: HashMap::from([
+                (
+                    AttachmentCodec::FILENAME_KEY.to_string(),
+                    attachment.filename,
+                ),
+                (
+                    AttachmentCodec::MIME_TYPE_KEY.to_string(),
+                    attachment.mime_type,
+                ),
+            ]),
+            compression: None,
+            content: attachment.data,
+        })
+    }
+
+    fn decode(content: EncodedContent) -> Result<Attachment, CodecError> {
+        let parameter =
+            |key: &str| {
+                content.parameters.get(key).cloned().ok_or_else(|| {
+                    CodecError::Decode(format!("Missing attachment parameter {}", key))
+                })
+            };
+
+        Ok(Attachment {
+            filename: parameter(AttachmentCodec::FILENAME_KEY)?,
+            mime_type: parameter(AttachmentCodec::MIME_TYPE_KEY)?,
+            data: content.content,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Attachment, AttachmentCodec};
+    use crate::codecs::ContentCodec;
+
+    #[test]
+    fn can_encode_and_decode_attachment() {
+        let attachment = Attachment {
+            filename: "hello.txt".to_string(),
+            mime_type: "text/plain".to_string(),
+            data: b"Hello, world!".to_vec(),
+        };
+        let encoded = AttachmentCodec::encode(attachment.clone()).unwrap();
+        assert_eq!(
+            AttachmentCodec::decode(encoded.clone()).unwrap(),
+            attachment
+        );
+
+        let mut missing_filename = encoded;
+        missing_filename.parameters.remove("filename");
+        assert!(AttachmentCodec::decode(missing_filename).is_err());
+    }
+}
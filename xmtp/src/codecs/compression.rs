@@ -0,0 +1,177 @@
+//! Compression of `EncodedContent.content`, using the algorithms in the proto `Compression`
+//! enum. Deflate uses the zlib format and gzip the gzip format, as other XMTP clients do.
+
+use std::io::{Read, Write};
+
+use flate2::{
+    read::{GzDecoder, ZlibDecoder},
+    write::{GzEncoder, ZlibEncoder},
+};
+use xmtp_proto::xmtp::message_contents::{Compression, EncodedContent};
+
+use super::CodecError;
+
+/// Content smaller than this is not worth compressing
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 10 * 1024;
+/// Content which decompresses to more than this is rejected rather than held in memory
+pub const DEFAULT_MAX_DECOMPRESSED_SIZE: usize = 50 * 1024 * 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompressionConfig {
+    pub compression: Compression,
+    /// Content is only compressed if it is larger than this many bytes
+    pub threshold: usize,
+    pub max_decompressed_size: usize,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            compression: Compression::Deflate,
+            threshold: DEFAULT_COMPRESSION_THRESHOLD,
+            max_decompressed_size: DEFAULT_MAX_DECOMPRESSED_SIZE,
+        }
+    }
+}
+
+impl CompressionConfig {
+    /// Compress `content` if it is above the threshold and not compressed already
+    pub fn compress(&self, mut content: EncodedContent) -> Result<EncodedContent, CodecError> {
+        if content.compression.is_some() || content.content.len() <= self.threshold {
+            return Ok(content);
+        }
+        content.content = compress(&content.content, self.compression)?;
+        content.compression = Some(self.compression as i32);
+        Ok(content)
+    }
+
+    /// Decompress `content` if it is compressed
+    pub fn decompress(&self, mut content: EncodedContent) -> Result<EncodedContent, CodecError> {
+        let compression = match content.compression {
+            Some(compression) => compression,
+            None => return Ok(content),
+        };
+        let compression = Compression::from_i32(compression)
+            .ok_or_else(|| CodecError::Decode(format!("Unknown compression {}", compression)))?;
+        content.content = decompress(&content.content, compression, self.max_decompressed_size)?;
+        content.compression = None;
+        Ok(content)
+    }
+}
+
+pub fn compress(data: &[u8], compression: Compression) -> Result<Vec<u8>, CodecError> {
+    let encode_err = |err: std::io::Error| CodecError::Encode(err.to_string());
+    match compression {
+        Compression::Deflate => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(data).map_err(encode_err)?;
+            encoder.finish().map_err(encode_err)
+        }
+        Compression::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(data).map_err(encode_err)?;
+            encoder.finish().map_err(encode_err)
+        }
+    }
+}
+
+/// Decompress `data`, failing once more than `max_size` bytes have been produced
+pub fn decompress(
+    data: &[u8],
+    compression: Compression,
+    max_size: usize,
+) -> Result<Vec<u8>, CodecError> {
+    let decoder: Box<dyn Read + '_> = match compression {
+        Compression::Deflate => Box::new(ZlibDecoder::new(data)),
+        Compression::Gzip => Box::new(GzDecoder::new(data)),
+    };
+    let mut decompressed = Vec::new();
+    // Reading one byte past the limit tells an oversized payload apart from one exactly at it
+    decoder
+        .take(max_size as u64 + 1)
+        .read_to_end(&mut decompressed)
+        .map_err(|err| CodecError::Decode(err.to_string()))?;
+    if decompressed.len() > max_size {
+        return Err(CodecError::Decode(format!(
+            "Content decompresses to more than {} bytes",
+            max_size
+        )));
+    }
+    Ok(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::{GzDecoder, ZlibDecoder};
+    use xmtp_proto::xmtp::message_contents::Compression;
+
+    use super::{compress, decompress, CompressionConfig};
+    use crate::codecs::{text::TextCodec, ContentCodec};
+
+    fn long_text() -> String {
+        "Hello, world! ".repeat(2000)
+    }
+
+    #[test]
+    fn compression_enum_values() {
+        // Other clients put these values on the wire
+        assert_eq!(Compression::Deflate as i32, 0);
+        assert_eq!(Compression::Gzip as i32, 1);
+        assert_eq!(Compression::from_i32(0), Some(Compression::Deflate));
+        assert_eq!(Compression::from_i32(1), Some(Compression::Gzip));
+        assert_eq!(Compression::from_i32(2), None);
+    }
+
+    #[test]
+    fn compressed_formats_are_standard() {
+        let data = long_text().into_bytes();
+
+        let mut inflated = Vec::new();
+        ZlibDecoder::new(compress(&data, Compression::Deflate).unwrap().as_slice())
+            .read_to_end(&mut inflated)
+            .unwrap();
+        assert_eq!(inflated, data);
+
+        let mut gunzipped = Vec::new();
+        GzDecoder::new(compress(&data, Compression::Gzip).unwrap().as_slice())
+            .read_to_end(&mut gunzipped)
+            .unwrap();
+        assert_eq!(gunzipped, data);
+    }
+
+    #[test]
+    fn compresses_above_threshold() {
+        for compression in [Compression::Deflate, Compression::Gzip] {
+            let config = CompressionConfig {
+                compression,
+                ..Default::default()
+            };
+
+            let short = TextCodec::encode("Hello".to_string()).unwrap();
+            assert_eq!(config.compress(short.clone()).unwrap(), short);
+
+            let long = TextCodec::encode(long_text()).unwrap();
+            let compressed = config.compress(long.clone()).unwrap();
+            assert_eq!(compressed.compression, Some(compression as i32));
+            assert!(compressed.content.len() < long.content.len());
+            assert_eq!(config.decompress(compressed).unwrap(), long);
+        }
+    }
+
+    #[test]
+    fn rejects_decompression_bombs() {
+        let data = vec![0u8; 1024 * 1024];
+        for compression in [Compression::Deflate, Compression::Gzip] {
+            let compressed = compress(&data, compression).unwrap();
+            assert!(compressed.len() < 10 * 1024);
+            assert!(decompress(&compressed, compression, data.len()).is_ok());
+            assert!(decompress(&compressed, compression, data.len() - 1).is_err());
+        }
+
+        let mut unknown = TextCodec::encode(long_text()).unwrap();
+        unknown.compression = Some(7);
+        assert!(CompressionConfig::default().decompress(unknown).is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use super::{CodecError, ContentCodec};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use xmtp_proto::xmtp::message_contents::{ContentTypeId, EncodedContent};
+
+/// Signals that the sender has stored the messages with ids `message_ids`. Like read receipts,
+/// delivery receipts have no fallback.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DeliveryReceipt {
+    pub message_ids: Vec<String>,
+}
+
+pub struct DeliveryReceiptCodec {}
+impl DeliveryReceiptCodec {
+    const AUTHORITY_ID: &str = "xmtp.org";
+    const TYPE_ID: &str = "deliveryReceipt";
+}
+
+impl ContentCodec<DeliveryReceipt> for DeliveryReceiptCodec {
+    fn content_type() -> ContentTypeId {
+        ContentTypeId {
+            authority_id: DeliveryReceiptCodec::AUTHORITY_ID.to_string(),
+            type_id: DeliveryReceiptCodec::TYPE_ID.to_string(),
+            version_major: 1,
+            version_minor: 0,
+        }
+    }
+
+    fn encode(receipt: DeliveryReceipt) -> Result<EncodedContent, CodecError> {
+        let content =
+            serde_json::to_vec(&receipt).map_err(|err| CodecError::Encode(err.to_string()))?;
+
+        Ok(EncodedContent {
+            r#type: Some(DeliveryReceiptCodec::content_type()),
+            parameters: HashMap::new(),
+            fallback: None,
+            compression: None,
+            content,
+        })
+    }
+
+    fn decode(content: EncodedContent) -> Result<DeliveryReceipt, CodecError> {
+        serde_json::from_slice(&content.content).map_err(|err| CodecError::Decode(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DeliveryReceipt, DeliveryReceiptCodec};
+    use crate::codecs::ContentCodec;
+
+    #[test]
+    fn can_encode_and_decode_delivery_receipt() {
+        let receipt = DeliveryReceipt {
+            message_ids: vec!["first".to_string(), "second".to_string()],
+        };
+        let encoded = DeliveryReceiptCodec::encode(receipt.clone()).unwrap();
+        assert_eq!(encoded.r#type, Some(DeliveryReceiptCodec::content_type()));
+        assert!(encoded.fallback.is_none());
+
+        let decoded = DeliveryReceiptCodec::decode(encoded).unwrap();
+        assert_eq!(decoded.message_ids, ["first", "second"]);
+        assert_eq!(decoded, receipt);
+    }
+}
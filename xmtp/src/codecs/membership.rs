@@ -0,0 +1,87 @@
+use super::{CodecError, ContentCodec};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use xmtp_proto::xmtp::message_contents::{ContentTypeId, EncodedContent};
+
+/// A change to the members of a group conversation. `members` is the complete member list after
+/// the change, so that a member who missed earlier changes still ends up with the right list.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct GroupMembershipChange {
+    pub members: Vec<String>,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+pub struct GroupMembershipCodec {}
+impl GroupMembershipCodec {
+    const AUTHORITY_ID: &str = "xmtp.org";
+    const TYPE_ID: &str = "groupMembership";
+
+    pub fn is_membership_change(content: &EncodedContent) -> bool {
+        content.r#type.as_ref().map_or(false, |content_type| {
+            content_type.authority_id == GroupMembershipCodec::AUTHORITY_ID
+                && content_type.type_id == GroupMembershipCodec::TYPE_ID
+        })
+    }
+}
+
+impl ContentCodec<GroupMembershipChange> for GroupMembershipCodec {
+    fn content_type() -> ContentTypeId {
+        ContentTypeId {
+            authority_id: GroupMembershipCodec::AUTHORITY_ID.to_string(),
+            type_id: GroupMembershipCodec::TYPE_ID.to_string(),
+            version_major: 1,
+            version_minor: 0,
+        }
+    }
+
+    fn encode(change: GroupMembershipChange) -> Result<EncodedContent, CodecError> {
+        let fallback = format!(
+            "Group members changed. Added: [{}] Removed: [{}]",
+            change.added.join(", "),
+            change.removed.join(", ")
+        );
+        let content =
+            serde_json::to_vec(&change).map_err(|err| CodecError::Encode(err.to_string()))?;
+
+        Ok(EncodedContent {
+            r#type: Some(GroupMembershipCodec::content_type()),
+            parameters: HashMap::new(),
+            fallback: Some(fallback),
+            compression: None,
+            content,
+        })
+    }
+
+    fn decode(content: EncodedContent) -> Result<GroupMembershipChange, CodecError> {
+        if !GroupMembershipCodec::is_membership_change(&content) {
+            return Err(CodecError::Decode(
+                "Content is not a group membership change".to_string(),
+            ));
+        }
+
+        serde_json::from_slice(&content.content).map_err(|err| CodecError::Decode(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GroupMembershipChange, GroupMembershipCodec};
+    use crate::codecs::{text::TextCodec, ContentCodec};
+
+    #[test]
+    fn can_encode_and_decode_membership_change() {
+        let change = GroupMembershipChange {
+            members: vec!["0x01".to_string(), "0x02".to_string()],
+            added: vec!["0x02".to_string()],
+            removed: vec!["0x03".to_string()],
+        };
+        let encoded = GroupMembershipCodec::encode(change.clone()).unwrap();
+        assert!(GroupMembershipCodec::is_membership_change(&encoded));
+        assert_eq!(GroupMembershipCodec::decode(encoded).unwrap(), change);
+
+        let text = TextCodec::encode("0x01".to_string()).unwrap();
+        assert!(!GroupMembershipCodec::is_membership_change(&text));
+        assert!(GroupMembershipCodec::decode(text).is_err());
+    }
+}
@@ -1,14 +1,28 @@
+pub mod attachment;
+pub mod compression;
+pub mod delivery_receipt;
+pub mod membership;
+pub mod reaction;
+pub mod read_receipt;
+pub mod registry;
+pub mod remote_attachment;
+pub mod reply;
+pub mod retention;
 pub mod text;
 
 use thiserror::Error;
 use xmtp_proto::xmtp::message_contents::{ContentTypeId, EncodedContent};
 
+pub use self::registry::{CodecRegistry, CustomContent, DynContentCodec, MessageContent};
+
 #[derive(Debug, Error)]
 pub enum CodecError {
     #[error("encode error {0}")]
     Encode(String),
     #[error("decode error {0}")]
     Decode(String),
+    #[error("no codec registered for {0}")]
+    UnsupportedContentType(String),
 }
 
 pub trait ContentCodec<T> {
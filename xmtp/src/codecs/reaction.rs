@@ -0,0 +1,96 @@
+use super::{CodecError, ContentCodec};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use xmtp_proto::xmtp::message_contents::{ContentTypeId, EncodedContent};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReactionAction {
+    Added,
+    Removed,
+}
+
+/// How the `content` of a reaction should be interpreted
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReactionSchema {
+    /// A unicode emoji, e.g. "👍"
+    Unicode,
+    /// An emoji shortcode, e.g. ":thumbsup:"
+    Shortcode,
+    /// Anything else the sending app understands
+    Custom,
+}
+
+/// A reaction to the message with id `reference`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Reaction {
+    pub reference: String,
+    pub action: ReactionAction,
+    pub content: String,
+    pub schema: ReactionSchema,
+}
+
+pub struct ReactionCodec {}
+impl ReactionCodec {
+    const AUTHORITY_ID: &str = "xmtp.org";
+    const TYPE_ID: &str = "reaction";
+}
+
+impl ContentCodec<Reaction> for ReactionCodec {
+    fn content_type() -> ContentTypeId {
+        ContentTypeId {
+            authority_id: ReactionCodec::AUTHORITY_ID.to_string(),
+            type_id: ReactionCodec::TYPE_ID.to_string(),
+            version_major: 1,
+            version_minor: 0,
+        }
+    }
+
+    fn encode(reaction: Reaction) -> Result<EncodedContent, CodecError> {
+        let fallback = match reaction.action {
+            ReactionAction::Added => {
+                format!("Reacted “{}” to an earlier message", reaction.content)
+            }
+            ReactionAction::Removed => {
+                format!("Removed “{}” from an earlier message", reaction.content)
+            }
+        };
+        let content =
+            serde_json::to_vec(&reaction).map_err(|err| CodecError::Encode(err.to_string()))?;
+
+        Ok(EncodedContent {
+            r#type: Some(ReactionCodec::content_type()),
+            parameters: HashMap::new(),
+            fallback: Some(fallback),
+            compression: None,
+            content,
+        })
+    }
+
+    fn decode(content: EncodedContent) -> Result<Reaction, CodecError> {
+        serde_json::from_slice(&content.content).map_err(|err| CodecError::Decode(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Reaction, ReactionAction, ReactionCodec, ReactionSchema};
+    use crate::codecs::ContentCodec;
+
+    #[test]
+    fn can_encode_and_decode_reaction() {
+        let reaction = Reaction {
+            reference: "message_id".to_string(),
+            action: ReactionAction::Added,
+            content: "👍".to_string(),
+            schema: ReactionSchema::Unicode,
+        };
+        let encoded = ReactionCodec::encode(reaction.clone()).unwrap();
+        assert_eq!(
+            encoded.fallback.as_deref(),
+            Some("Reacted “👍” to an earlier message")
+        );
+        assert_eq!(ReactionCodec::decode(encoded).unwrap(), reaction);
+    }
+}
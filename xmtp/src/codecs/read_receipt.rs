@@ -0,0 +1,53 @@
+use super::{CodecError, ContentCodec};
+use std::collections::HashMap;
+use xmtp_proto::xmtp::message_contents::{ContentTypeId, EncodedContent};
+
+/// Signals that the sender has read every message in the conversation sent before this one.
+/// Read receipts have no fallback, since there is nothing useful to show in their place.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReadReceipt {}
+
+pub struct ReadReceiptCodec {}
+impl ReadReceiptCodec {
+    const AUTHORITY_ID: &str = "xmtp.org";
+    const TYPE_ID: &str = "readReceipt";
+}
+
+impl ContentCodec<ReadReceipt> for ReadReceiptCodec {
+    fn content_type() -> ContentTypeId {
+        ContentTypeId {
+            authority_id: ReadReceiptCodec::AUTHORITY_ID.to_string(),
+            type_id: ReadReceiptCodec::TYPE_ID.to_string(),
+            version_major: 1,
+            version_minor: 0,
+        }
+    }
+
+    fn encode(_receipt: ReadReceipt) -> Result<EncodedContent, CodecError> {
+        Ok(EncodedContent {
+            r#type: Some(ReadReceiptCodec::content_type()),
+            parameters: HashMap::new(),
+            fallback: None,
+            compression: None,
+            content: vec![],
+        })
+    }
+
+    fn decode(_content: EncodedContent) -> Result<ReadReceipt, CodecError> {
+        Ok(ReadReceipt {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReadReceipt, ReadReceiptCodec};
+    use crate::codecs::ContentCodec;
+
+    #[test]
+    fn can_encode_and_decode_read_receipt() {
+        let encoded = ReadReceiptCodec::encode(ReadReceipt {}).unwrap();
+        assert_eq!(encoded.r#type, Some(ReadReceiptCodec::content_type()));
+        assert!(encoded.fallback.is_none());
+        assert_eq!(ReadReceiptCodec::decode(encoded).unwrap(), ReadReceipt {});
+    }
+}
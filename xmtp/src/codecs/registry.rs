@@ -0,0 +1,673 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+    sync::Arc,
+};
+
+use xmtp_proto::xmtp::message_contents::{ContentTypeId, EncodedContent};
+
+use super::{
+    attachment::{Attachment, AttachmentCodec},
+    compression::CompressionConfig,
+    delivery_receipt::{DeliveryReceipt, DeliveryReceiptCodec},
+    membership::{GroupMembershipChange, GroupMembershipCodec},
+    reaction::{Reaction, ReactionCodec},
+    read_receipt::{ReadReceipt, ReadReceiptCodec},
+    remote_attachment::{RemoteAttachment, RemoteAttachmentCodec},
+    reply::{Reply, ReplyCodec},
+    retention::{RetentionPolicy, RetentionPolicyCodec},
+    text::TextCodec,
+    CodecError, ContentCodec,
+};
+
+/// The content of a message, decoded by a [`CodecRegistry`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MessageContent {
+    Text(String),
+    Reaction(Reaction),
+    Reply(Reply),
+    Attachment(Attachment),
+    RemoteAttachment(RemoteAttachment),
+    ReadReceipt(ReadReceipt),
+    DeliveryReceipt(DeliveryReceipt),
+    MembershipChange(GroupMembershipChange),
+    RetentionPolicy(RetentionPolicy),
+    /// Content decoded by a codec the application registered
+    Custom(CustomContent),
+    /// Content with no registered codec, which can only be shown using its fallback text
+    Unknown {
+        content_type: Option<ContentTypeId>,
+        fallback: Option<String>,
+    },
+}
+
+impl MessageContent {
+    /// The decoded value, if it is a `T`
+    pub fn downcast<T: Any + Clone>(&self) -> Option<T> {
+        let value: &dyn Any = match self {
+            MessageContent::Text(text) => text,
+            MessageContent::Reaction(reaction) => reaction,
+            MessageContent::Reply(reply) => reply,
+            MessageContent::Attachment(attachment) => attachment,
+            MessageContent::RemoteAttachment(attachment) => attachment,
+            MessageContent::ReadReceipt(receipt) => receipt,
+            MessageContent::DeliveryReceipt(receipt) => receipt,
+            MessageContent::MembershipChange(change) => change,
+            MessageContent::RetentionPolicy(policy) => policy,
+            MessageContent::Custom(custom) => return custom.downcast_ref().cloned(),
+            MessageContent::Unknown { .. } => return None,
+        };
+        value.downcast_ref().cloned()
+    }
+}
+
+trait CustomValue: Any + fmt::Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn eq_value(&self, other: &dyn CustomValue) -> bool;
+}
+
+impl<T: Any + fmt::Debug + PartialEq + Send + Sync> CustomValue for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_value(&self, other: &dyn CustomValue) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+}
+
+/// A value of a content type defined by the application
+#[derive(Clone, Debug)]
+pub struct CustomContent {
+    pub content_type: ContentTypeId,
+    value: Arc<dyn CustomValue>,
+}
+
+impl CustomContent {
+    pub fn new<T: Any + fmt::Debug + PartialEq + Send + Sync>(
+        content_type: ContentTypeId,
+        value: T,
+    ) -> Self {
+        Self {
+            content_type,
+            value: Arc::new(value),
+        }
+    }
+
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.as_any().downcast_ref()
+    }
+}
+
+impl PartialEq for CustomContent {
+    fn eq(&self, other: &Self) -> bool {
+        self.content_type == other.content_type && self.value.eq_value(other.value.as_ref())
+    }
+}
+
+/// An object safe [`ContentCodec`], so that codecs can be chosen at runtime. Any `ContentCodec`
+/// can be registered with [`CodecRegistry::register`] instead of implementing this directly.
+pub trait DynContentCodec: Send + Sync {
+    fn content_type(&self) -> ContentTypeId;
+
+    /// The type of value this codec encodes
+    fn value_type(&self) -> TypeId;
+
+    /// Whether content of another major version of this codec's type can be decoded. Major
+    /// versions are incompatible unless a codec says otherwise.
+    fn decodes_version(&self, version_major: u32) -> bool {
+        version_major == self.content_type().version_major
+    }
+
+    fn encode(&self, content: Box<dyn Any>) -> Result<EncodedContent, CodecError>;
+    fn decode(&self, content: EncodedContent) -> Result<MessageContent, CodecError>;
+}
+
+struct CodecAdapter<T, C> {
+    wrap: fn(ContentTypeId, T) -> MessageContent,
+    _codec: PhantomData<fn() -> (T, C)>,
+}
+
+impl<T: Any, C: ContentCodec<T>> DynContentCodec for CodecAdapter<T, C> {
+    fn content_type(&self) -> ContentTypeId {
+        C::content_type()
+    }
+
+    fn value_type(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn encode(&self, content: Box<dyn Any>) -> Result<EncodedContent, CodecError> {
+        let content = content.downcast::<T>().map_err(|_| {
+            CodecError::Encode("Content does not match the codec's type".to_string())
+        })?;
+        C::encode(*content)
+    }
+
+    fn decode(&self, content: EncodedContent) -> Result<MessageContent, CodecError> {
+        let content_type = content.r#type.clone().unwrap_or_else(C::content_type);
+        Ok((self.wrap)(content_type, C::decode(content)?))
+    }
+}
+
+/// Identifies a codec. Minor versions of a content type are backwards compatible and share a
+/// codec, while every major version needs one of its own.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct ContentTypeKey {
+    authority_id: String,
+    type_id: String,
+    version_major: u32,
+}
+
+impl From<&ContentTypeId> for ContentTypeKey {
+    fn from(content_type: &ContentTypeId) -> Self {
+        Self {
+            authority_id: content_type.authority_id.clone(),
+            type_id: content_type.type_id.clone(),
+            version_major: content_type.version_major,
+        }
+    }
+}
+
+fn describe(content_type: &ContentTypeId) -> String {
+    format!(
+        "{}/{}:{}.{}",
+        content_type.authority_id,
+        content_type.type_id,
+        content_type.version_major,
+        content_type.version_minor
+    )
+}
+
+fn custom_content<T: Any + fmt::Debug + PartialEq + Send + Sync>(
+    content_type: ContentTypeId,
+    value: T,
+) -> MessageContent {
+    MessageContent::Custom(CustomContent::new(content_type, value))
+}
+
+/// Encodes and decodes content with codecs chosen at runtime. Decoding picks the codec for the
+/// content's type and major version, or else the newest major version that can decode it, and
+/// encoding picks the codec most recently registered for the value's type. The default registry
+/// contains every codec in this module, and applications can register codecs for their own
+/// content types alongside them.
+///
+/// Encoded content above the compression threshold is compressed, and compressed content is
+/// decompressed before it is decoded.
+#[derive(Clone)]
+pub struct CodecRegistry {
+    codecs: HashMap<ContentTypeKey, Arc<dyn DynContentCodec>>,
+    encoders: HashMap<TypeId, ContentTypeKey>,
+    compression: CompressionConfig,
+}
+
+impl CodecRegistry {
+    pub fn new() -> Self {
+        Self {
+            codecs: HashMap::new(),
+            encoders: HashMap::new(),
+            compression: CompressionConfig::default(),
+        }
+    }
+
+    pub fn compression(&self) -> &CompressionConfig {
+        &self.compression
+    }
+
+    pub fn set_compression(&mut self, compression: CompressionConfig) {
+        self.compression = compression;
+    }
+
+    /// Compress content encoded without the registry, such as with a [`ContentCodec`] directly
+    pub fn compress(&self, content: EncodedContent) -> Result<EncodedContent, CodecError> {
+        self.compression.compress(content)
+    }
+
+    /// Register `C` for an application defined content type. Decoded values are returned as
+    /// [`MessageContent::Custom`].
+    pub fn register<T, C>(&mut self)
+    where
+        T: Any + fmt::Debug + PartialEq + Send + Sync,
+        C: ContentCodec<T> + 'static,
+    {
+        self.register_with::<T, C>(custom_content::<T>);
+    }
+
+    fn register_with<T: Any, C: ContentCodec<T> + 'static>(
+        &mut self,
+        wrap: fn(ContentTypeId, T) -> MessageContent,
+    ) {
+        self.register_dyn(Arc::new(CodecAdapter::<T, C> {
+            wrap,
+            _codec: PhantomData,
+        }));
+    }
+
+    /// Register a codec, replacing any codec already registered for the same major version of
+    /// its content type
+    pub fn register_dyn(&mut self, codec: Arc<dyn DynContentCodec>) {
+        let key = ContentTypeKey::from(&codec.content_type());
+        let value_type = codec.value_type();
+        let replaced = self.codecs.insert(key.clone(), codec);
+        self.encoders.insert(value_type, key.clone());
+
+        // Values of the replaced codec's type are encoded by another of its codecs, if any remain
+        if let Some(replaced) = replaced.filter(|replaced| replaced.value_type() != value_type) {
+            let replaced_type = replaced.value_type();
+            if self.encoders.get(&replaced_type) == Some(&key) {
+                match self.newest_codec_for(replaced_type) {
+                    Some(key) => self.encoders.insert(replaced_type, key),
+                    None => self.encoders.remove(&replaced_type),
+                };
+            }
+        }
+    }
+
+    fn newest_codec_for(&self, value_type: TypeId) -> Option<ContentTypeKey> {
+        self.codecs
+            .iter()
+            .filter(|(_, codec)| codec.value_type() == value_type)
+            .map(|(key, _)| key)
+            .max_by_key(|key| key.version_major)
+            .cloned()
+    }
+
+    pub fn supports(&self, content_type: &ContentTypeId) -> bool {
+        self.find_codec(content_type).is_some()
+    }
+
+    fn find_codec(&self, content_type: &ContentTypeId) -> Option<&Arc<dyn DynContentCodec>> {
+        if let Some(codec) = self.codecs.get(&ContentTypeKey::from(content_type)) {
+            return Some(codec);
+        }
+        // Another major version may still be able to decode it, and the newest one is preferred
+        self.codecs
+            .iter()
+            .filter(|(key, codec)| {
+                key.authority_id == content_type.authority_id
+                    && key.type_id == content_type.type_id
+                    && codec.decodes_version(content_type.version_major)
+            })
+            .max_by_key(|(key, _)| key.version_major)
+            .map(|(_, codec)| codec)
+    }
+
+    pub fn encode<T: Any>(&self, content: T) -> Result<EncodedContent, CodecError> {
+        let codec = self
+            .encoders
+            .get(&TypeId::of::<T>())
+            .and_then(|key| self.codecs.get(key))
+            .ok_or_else(|| {
+                CodecError::UnsupportedContentType(std::any::type_name::<T>().to_string())
+            })?;
+        self.compress(codec.encode(Box::new(content))?)
+    }
+
+    /// Decode `content`, or return [`MessageContent::Unknown`] with its fallback text if no
+    /// registered codec understands its type or major version
+    pub fn decode(&self, content: EncodedContent) -> Result<MessageContent, CodecError> {
+        let content = self.compression.decompress(content)?;
+        match content
+            .r#type
+            .as_ref()
+            .and_then(|content_type| self.find_codec(content_type))
+        {
+            Some(codec) => codec.decode(content),
+            None => {
+                log::debug!(
+                    "No codec for content type {:?}, using fallback",
+                    content.r#type.as_ref().map(describe)
+                );
+                Ok(MessageContent::Unknown {
+                    content_type: content.r#type,
+                    fallback: content.fallback,
+                })
+            }
+        }
+    }
+
+    /// Decode `content` as a `T`, failing if it has any other type
+    pub fn decode_as<T: Any + Clone>(&self, content: EncodedContent) -> Result<T, CodecError> {
+        match self.decode(content)? {
+            MessageContent::Unknown { content_type, .. } => {
+                Err(CodecError::UnsupportedContentType(
+                    content_type
+                        .as_ref()
+                        .map_or_else(|| "missing content type".to_string(), describe),
+                ))
+            }
+            decoded => decoded.downcast().ok_or_else(|| {
+                CodecError::Decode(format!("Content is not a {}", std::any::type_name::<T>()))
+            }),
+        }
+    }
+}
+
+impl Default for CodecRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry.register_with::<_, TextCodec>(|_, text| MessageContent::Text(text));
+        registry
+            .register_with::<_, ReactionCodec>(|_, reaction| MessageContent::Reaction(reaction));
+        registry.register_with::<_, ReplyCodec>(|_, reply| MessageContent::Reply(reply));
+        registry.register_with::<_, AttachmentCodec>(|_, attachment| {
+            MessageContent::Attachment(attachment)
+        });
+        registry.register_with::<_, RemoteAttachmentCodec>(|_, attachment| {
+            MessageContent::RemoteAttachment(attachment)
+        });
+        registry.register_with::<_, ReadReceiptCodec>(|_, receipt| {
+            MessageContent::ReadReceipt(receipt)
+        });
+        registry.register_with::<_, DeliveryReceiptCodec>(|_, receipt| {
+            MessageContent::DeliveryReceipt(receipt)
+        });
+        registry.register_with::<_, GroupMembershipCodec>(|_, change| {
+            MessageContent::MembershipChange(change)
+        });
+        registry.register_with::<_, RetentionPolicyCodec>(|_, policy| {
+            MessageContent::RetentionPolicy(policy)
+        });
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        any::{Any, TypeId},
+        collections::HashMap,
+        marker::PhantomData,
+        sync::Arc,
+    };
+
+    use xmtp_proto::xmtp::message_contents::{Compression, ContentTypeId, EncodedContent};
+
+    use super::{
+        custom_content, CodecAdapter, CodecRegistry, CustomContent, DynContentCodec, MessageContent,
+    };
+    use crate::codecs::{
+        compression::CompressionConfig,
+        reaction::{Reaction, ReactionAction, ReactionCodec, ReactionSchema},
+        text::TextCodec,
+        CodecError, ContentCodec,
+    };
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Poll {
+        question: String,
+    }
+
+    struct PollCodec {}
+    impl ContentCodec<Poll> for PollCodec {
+        fn content_type() -> ContentTypeId {
+            ContentTypeId {
+                authority_id: "example.com".to_string(),
+                type_id: "poll".to_string(),
+                version_major: 1,
+                version_minor: 0,
+            }
+        }
+
+        fn encode(poll: Poll) -> Result<EncodedContent, CodecError> {
+            Ok(EncodedContent {
+                r#type: Some(PollCodec::content_type()),
+                parameters: HashMap::new(),
+                fallback: Some(format!("Poll: {}", poll.question)),
+                compression: None,
+                content: poll.question.into_bytes(),
+            })
+        }
+
+        fn decode(content: EncodedContent) -> Result<Poll, CodecError> {
+            Ok(Poll {
+                question: String::from_utf8(content.content)
+                    .map_err(|err| CodecError::Decode(err.to_string()))?,
+            })
+        }
+    }
+
+    fn poll() -> Poll {
+        Poll {
+            question: "Lunch?".to_string(),
+        }
+    }
+
+    #[test]
+    fn registry_decodes_known_content() {
+        let registry = CodecRegistry::default();
+        let text = TextCodec::encode("Hello".to_string()).unwrap();
+        assert_eq!(
+            registry.decode(text).unwrap(),
+            MessageContent::Text("Hello".to_string())
+        );
+
+        let reaction = Reaction {
+            reference: "message_id".to_string(),
+            action: ReactionAction::Removed,
+            content: ":thumbsup:".to_string(),
+            schema: ReactionSchema::Shortcode,
+        };
+        let encoded = ReactionCodec::encode(reaction.clone()).unwrap();
+        assert_eq!(
+            registry.decode(encoded).unwrap(),
+            MessageContent::Reaction(reaction)
+        );
+    }
+
+    #[test]
+    fn registry_falls_back_for_unknown_content() {
+        let content = PollCodec::encode(poll()).unwrap();
+        assert_eq!(
+            CodecRegistry::default().decode(content).unwrap(),
+            MessageContent::Unknown {
+                content_type: Some(PollCodec::content_type()),
+                fallback: Some("Poll: Lunch?".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn registry_decodes_custom_content() {
+        let mut registry = CodecRegistry::default();
+        registry.register::<Poll, PollCodec>();
+
+        let encoded = registry.encode(poll()).unwrap();
+        assert_eq!(encoded.r#type, Some(PollCodec::content_type()));
+        assert_eq!(
+            registry.decode(encoded.clone()).unwrap(),
+            MessageContent::Custom(CustomContent::new(PollCodec::content_type(), poll()))
+        );
+        assert_eq!(registry.decode_as::<Poll>(encoded.clone()).unwrap(), poll());
+        assert!(registry.decode_as::<String>(encoded).is_err());
+
+        let text = registry.encode("Hello".to_string()).unwrap();
+        assert_eq!(registry.decode_as::<String>(text).unwrap(), "Hello");
+        assert!(matches!(
+            registry.encode(1u8),
+            Err(CodecError::UnsupportedContentType(_))
+        ));
+    }
+
+    #[test]
+    fn registry_compresses_large_content() {
+        let registry = CodecRegistry::default();
+        let text = "Hello, world! ".repeat(2000);
+        let encoded = registry.encode(text.clone()).unwrap();
+        assert_eq!(encoded.compression, Some(Compression::Deflate as i32));
+        assert_eq!(registry.decode_as::<String>(encoded).unwrap(), text);
+
+        let mut gzip = CodecRegistry::default();
+        gzip.set_compression(CompressionConfig {
+            compression: Compression::Gzip,
+            threshold: 0,
+            ..Default::default()
+        });
+        let encoded = gzip.encode("Hello".to_string()).unwrap();
+        assert_eq!(encoded.compression, Some(Compression::Gzip as i32));
+        // Whatever the sender chose can be decoded
+        assert_eq!(registry.decode_as::<String>(encoded).unwrap(), "Hello");
+    }
+
+    #[test]
+    fn registry_negotiates_versions() {
+        let mut registry = CodecRegistry::default();
+        registry.register::<Poll, PollCodec>();
+
+        // Minor versions are compatible
+        let mut newer_minor = PollCodec::encode(poll()).unwrap();
+        newer_minor.r#type.as_mut().unwrap().version_minor = 3;
+        assert_eq!(registry.decode_as::<Poll>(newer_minor).unwrap(), poll());
+
+        // An unknown major version falls back
+        let mut newer_major = PollCodec::encode(poll()).unwrap();
+        newer_major.r#type.as_mut().unwrap().version_major = 2;
+        assert!(!registry.supports(newer_major.r#type.as_ref().unwrap()));
+        assert!(matches!(
+            registry.decode(newer_major.clone()).unwrap(),
+            MessageContent::Unknown { fallback: Some(fallback), .. } if fallback == "Poll: Lunch?"
+        ));
+        assert!(matches!(
+            registry.decode_as::<Poll>(newer_major),
+            Err(CodecError::UnsupportedContentType(_))
+        ));
+    }
+
+    // A later major version of the poll type, which marks the polls it decodes
+    struct PollV2Codec {}
+    impl ContentCodec<Poll> for PollV2Codec {
+        fn content_type() -> ContentTypeId {
+            ContentTypeId {
+                version_major: 2,
+                ..PollCodec::content_type()
+            }
+        }
+
+        fn encode(poll: Poll) -> Result<EncodedContent, CodecError> {
+            Ok(EncodedContent {
+                r#type: Some(PollV2Codec::content_type()),
+                ..PollCodec::encode(poll)?
+            })
+        }
+
+        fn decode(content: EncodedContent) -> Result<Poll, CodecError> {
+            let poll = PollCodec::decode(content)?;
+            Ok(Poll {
+                question: format!("v2: {}", poll.question),
+            })
+        }
+    }
+
+    // Decodes every major version of the wrapped codec's content type
+    struct AnyVersion(Arc<dyn DynContentCodec>);
+    impl DynContentCodec for AnyVersion {
+        fn content_type(&self) -> ContentTypeId {
+            self.0.content_type()
+        }
+
+        fn value_type(&self) -> TypeId {
+            self.0.value_type()
+        }
+
+        fn decodes_version(&self, _version_major: u32) -> bool {
+            true
+        }
+
+        fn encode(&self, content: Box<dyn Any>) -> Result<EncodedContent, CodecError> {
+            self.0.encode(content)
+        }
+
+        fn decode(&self, content: EncodedContent) -> Result<MessageContent, CodecError> {
+            self.0.decode(content)
+        }
+    }
+
+    fn any_version<C: ContentCodec<Poll> + 'static>() -> Arc<dyn DynContentCodec> {
+        Arc::new(AnyVersion(Arc::new(CodecAdapter::<Poll, C> {
+            wrap: custom_content::<Poll>,
+            _codec: PhantomData,
+        })))
+    }
+
+    #[test]
+    fn registry_prefers_newest_compatible_version() {
+        let mut newer_major = PollCodec::encode(poll()).unwrap();
+        newer_major.r#type.as_mut().unwrap().version_major = 3;
+
+        // Iteration order differs between registries, and must not affect the choice
+        for _ in 0..20 {
+            let mut registry = CodecRegistry::new();
+            registry.register_dyn(any_version::<PollCodec>());
+            registry.register_dyn(any_version::<PollV2Codec>());
+            assert_eq!(
+                registry
+                    .decode_as::<Poll>(newer_major.clone())
+                    .unwrap()
+                    .question,
+                "v2: Lunch?"
+            );
+        }
+    }
+
+    #[test]
+    fn registry_forgets_replaced_encoders() {
+        // Takes over the text content type with another value type
+        struct ShoutCodec {}
+        impl ContentCodec<Poll> for ShoutCodec {
+            fn content_type() -> ContentTypeId {
+                TextCodec::content_type()
+            }
+
+            fn encode(poll: Poll) -> Result<EncodedContent, CodecError> {
+                TextCodec::encode(poll.question.to_uppercase())
+            }
+
+            fn decode(content: EncodedContent) -> Result<Poll, CodecError> {
+                Ok(Poll {
+                    question: TextCodec::decode(content)?,
+                })
+            }
+        }
+
+        let mut registry = CodecRegistry::default();
+        registry.register::<Poll, ShoutCodec>();
+        assert!(matches!(
+            registry.encode("Hello".to_string()),
+            Err(CodecError::UnsupportedContentType(_))
+        ));
+        assert_eq!(
+            registry
+                .decode_as::<Poll>(registry.encode(poll()).unwrap())
+                .unwrap(),
+            Poll {
+                question: "LUNCH?".to_string()
+            }
+        );
+
+        // Another major version still encodes the replaced codec's values
+        let mut registry = CodecRegistry::new();
+        registry.register::<Poll, PollCodec>();
+        registry.register::<Poll, PollV2Codec>();
+        registry.register::<String, TextCodecAsV2>();
+        let encoded = registry.encode(poll()).unwrap();
+        assert_eq!(encoded.r#type, Some(PollCodec::content_type()));
+    }
+
+    // Encodes strings under version 2 of the poll type
+    struct TextCodecAsV2 {}
+    impl ContentCodec<String> for TextCodecAsV2 {
+        fn content_type() -> ContentTypeId {
+            PollV2Codec::content_type()
+        }
+
+        fn encode(text: String) -> Result<EncodedContent, CodecError> {
+            PollV2Codec::encode(Poll { question: text })
+        }
+
+        fn decode(content: EncodedContent) -> Result<String, CodecError> {
+            Ok(PollCodec::decode(content)?.question)
+        }
+    }
+}
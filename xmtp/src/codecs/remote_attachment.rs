@@ -0,0 +1,326 @@
+//! Remote attachments are encrypted and uploaded by the app, so that only a URL and the key
+//! travel in the message.
+//!
+//! This follows the `xmtp.org/remoteStaticAttachment` format used by the other XMTP SDKs. The
+//! attachment is encoded with `AttachmentCodec` and encrypted with AES-256-GCM under a key derived
+//! by HKDF-SHA256 from a random secret and salt, with no info. The SHA-256 digest of the encrypted
+//! payload is checked before anything downloaded from the URL is decrypted.
+//!
+//! Payload format: `ciphertext || tag (16)`
+
+use super::{
+    attachment::{Attachment, AttachmentCodec},
+    CodecError, ContentCodec,
+};
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use prost::Message;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use xmtp_proto::xmtp::message_contents::{ContentTypeId, EncodedContent};
+
+use crate::crypto::hkdf_sha256;
+
+const SECRET_LENGTH: usize = 32;
+const SALT_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 12;
+
+/// An attachment to be downloaded from `url` and decrypted with [`RemoteAttachment::decrypt`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoteAttachment {
+    pub url: String,
+    /// Hex encoded SHA-256 digest of the encrypted payload
+    pub content_digest: String,
+    pub secret: Vec<u8>,
+    pub salt: Vec<u8>,
+    pub nonce: Vec<u8>,
+    pub scheme: String,
+    pub content_length: Option<u64>,
+    pub filename: Option<String>,
+}
+
+/// An attachment encrypted for upload, along with everything needed to decrypt it again
+#[derive(Clone, Debug, PartialEq)]
+pub struct EncryptedAttachment {
+    pub payload: Vec<u8>,
+    pub content_digest: String,
+    pub secret: Vec<u8>,
+    pub salt: Vec<u8>,
+    pub nonce: Vec<u8>,
+    pub filename: String,
+}
+
+fn cipher(secret: &[u8], salt: &[u8]) -> Aes256Gcm {
+    let mut key = [0u8; 32];
+    hkdf_sha256(secret, salt, &[], &mut key);
+
+    Aes256Gcm::new(&key.into())
+}
+
+fn digest(payload: &[u8]) -> String {
+    hex::encode(Sha256::digest(payload))
+}
+
+impl EncryptedAttachment {
+    /// Encrypt `attachment` under a fresh random key
+    pub fn encrypt(attachment: Attachment) -> Result<Self, CodecError> {
+        let mut rng = rand::thread_rng();
+        let mut secret = vec![0u8; SECRET_LENGTH];
+        let mut salt = vec![0u8; SALT_LENGTH];
+        let mut nonce = vec![0u8; NONCE_LENGTH];
+        rng.fill_bytes(&mut secret);
+        rng.fill_bytes(&mut salt);
+        rng.fill_bytes(&mut nonce);
+
+        let filename = attachment.filename.clone();
+        let plaintext = AttachmentCodec::encode(attachment)?.encode_to_vec();
+        let payload = cipher(&secret, &salt)
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| CodecError::Encode("Could not encrypt remote attachment".to_string()))?;
+
+        Ok(Self {
+            content_digest: digest(&payload),
+            payload,
+            secret,
+            salt,
+            nonce,
+            filename,
+        })
+    }
+}
+
+impl RemoteAttachment {
+    /// Describe `encrypted` once its payload has been uploaded to `url`
+    pub fn new(url: String, encrypted: &EncryptedAttachment) -> Self {
+        RemoteAttachment {
+            url,
+            content_digest: encrypted.content_digest.clone(),
+            secret: encrypted.secret.clone(),
+            salt: encrypted.salt.clone(),
+            nonce: encrypted.nonce.clone(),
+            scheme: RemoteAttachmentCodec::SCHEME_HTTPS.to_string(),
+            content_length: Some(encrypted.payload.len() as u64),
+            filename: Some(encrypted.filename.clone()),
+        }
+    }
+
+    /// Verify and decrypt a payload downloaded from `url`
+    pub fn decrypt(&self, payload: &[u8]) -> Result<Attachment, CodecError> {
+        if digest(payload) != self.content_digest {
+            return Err(CodecError::Decode(
+                "Remote attachment does not match its digest".to_string(),
+            ));
+        }
+        if self.nonce.len() != NONCE_LENGTH {
+            return Err(CodecError::Decode(
+                "Invalid remote attachment nonce".to_string(),
+            ));
+        }
+
+        let plaintext = cipher(&self.secret, &self.salt)
+            .decrypt(Nonce::from_slice(&self.nonce), payload)
+            .map_err(|_| CodecError::Decode("Could not decrypt remote attachment".to_string()))?;
+
+        let content = EncodedContent::decode(plaintext.as_slice())
+            .map_err(|err| CodecError::Decode(err.to_string()))?;
+        AttachmentCodec::decode(content)
+    }
+}
+
+pub struct RemoteAttachmentCodec {}
+impl RemoteAttachmentCodec {
+    const AUTHORITY_ID: &str = "xmtp.org";
+    const TYPE_ID: &str = "remoteStaticAttachment";
+    const SCHEME_HTTPS: &str = "https://";
+    const CONTENT_DIGEST_KEY: &str = "contentDigest";
+    const SECRET_KEY: &str = "secret";
+    const SALT_KEY: &str = "salt";
+    const NONCE_KEY: &str = "nonce";
+    const SCHEME_KEY: &str = "scheme";
+    const CONTENT_LENGTH_KEY: &str = "contentLength";
+    const FILENAME_KEY: &str = "filename";
+}
+
+impl ContentCodec<RemoteAttachment> for RemoteAttachmentCodec {
+    fn content_type() -> ContentTypeId {
+        ContentTypeId {
+            authority_id: RemoteAttachmentCodec::AUTHORITY_ID.to_string(),
+            type_id: RemoteAttachmentCodec::TYPE_ID.to_string(),
+            version_major: 1,
+            version_minor: 0,
+        }
+    }
+
+    fn encode(attachment: RemoteAttachment) -> Result<EncodedContent, CodecError> {
+        if attachment.scheme != RemoteAttachmentCodec::SCHEME_HTTPS
+            || !attachment
+                .url
+                .starts_with(RemoteAttachmentCodec::SCHEME_HTTPS)
+        {
+            return Err(CodecError::Encode(
+                "Remote attachments must be served over https".to_string(),
+            ));
+        }
+
+        let mut parameters = HashMap::from([
+            (
+                RemoteAttachmentCodec::CONTENT_DIGEST_KEY.to_string(),
+                attachment.content_digest,
+            ),
+            (
+                RemoteAttachmentCodec::SECRET_KEY.to_string(),
+                hex::encode(attachment.secret),
+            ),
+            (
+                RemoteAttachmentCodec::SALT_KEY.to_string(),
+                hex::encode(attachment.salt),
+            ),
+            (
+                RemoteAttachmentCodec::NONCE_KEY.to_string(),
+                hex::encode(attachment.nonce),
+            ),
+            (
+                RemoteAttachmentCodec::SCHEME_KEY.to_string(),
+                attachment.scheme,
+            ),
+        ]);
+        if let Some(content_length) = attachment.content_length {
+            parameters.insert(
+                RemoteAttachmentCodec::CONTENT_LENGTH_KEY.to_string(),
+                content_length.to_string(),
+            );
+        }
+        let fallback = match &attachment.filename {
+            Some(filename) => {
+                parameters.insert(
+                    RemoteAttachmentCodec::FILENAME_KEY.to_string(),
+                    filename.clone(),
+                );
+                format!(
+                    "Can’t display \"{}\". This app doesn’t support attachments.",
+                    filename
+                )
+            }
+            None => {
+                "Can’t display this attachment. This app doesn’t support attachments.".to_string()
+            }
+        };
+
+        Ok(EncodedContent {
+            r#type: Some(RemoteAttachmentCodec::content_type()),
+            parameters,
+            fallback: Some(fallback),
+            compression: None,
+            content: attachment.url.into_bytes(),
+        })
+    }
+
+    fn decode(content: EncodedContent) -> Result<RemoteAttachment, CodecError> {
+        let parameter = |key: &str| {
+            content.parameters.get(key).cloned().ok_or_else(|| {
+                CodecError::Decode(format!("Missing remote attachment parameter {}", key))
+            })
+        };
+        let hex_parameter = |key: &str| {
+            hex::decode(parameter(key)?).map_err(|err| CodecError::Decode(err.to_string()))
+        };
+        let content_length = match content
+            .parameters
+            .get(RemoteAttachmentCodec::CONTENT_LENGTH_KEY)
+        {
+            Some(length) => Some(
+                length
+                    .parse()
+                    .map_err(|_| CodecError::Decode("Invalid content length".to_string()))?,
+            ),
+            None => None,
+        };
+
+        Ok(RemoteAttachment {
+            content_digest: parameter(RemoteAttachmentCodec::CONTENT_DIGEST_KEY)?,
+            secret: hex_parameter(RemoteAttachmentCodec::SECRET_KEY)?,
+            salt: hex_parameter(RemoteAttachmentCodec::SALT_KEY)?,
+            nonce: hex_parameter(RemoteAttachmentCodec::NONCE_KEY)?,
+            scheme: parameter(RemoteAttachmentCodec::SCHEME_KEY)?,
+            content_length,
+            filename: content
+                .parameters
+                .get(RemoteAttachmentCodec::FILENAME_KEY)
+                .cloned(),
+            url: String::from_utf8(content.content)
+                .map_err(|err| CodecError::Decode(err.to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EncryptedAttachment, RemoteAttachment, RemoteAttachmentCodec};
+    use crate::codecs::{attachment::Attachment, ContentCodec};
+
+    fn test_attachment() -> Attachment {
+        Attachment {
+            filename: "hello.txt".to_string(),
+            mime_type: "text/plain".to_string(),
+            data: b"Hello, world!".to_vec(),
+        }
+    }
+
+    #[test]
+    fn can_encode_and_decode_remote_attachment() {
+        let encrypted = EncryptedAttachment::encrypt(test_attachment()).unwrap();
+        let remote = RemoteAttachment::new("https://example.com/hello".to_string(), &encrypted);
+        let encoded = RemoteAttachmentCodec::encode(remote.clone()).unwrap();
+        let decoded = RemoteAttachmentCodec::decode(encoded).unwrap();
+        assert_eq!(decoded, remote);
+        assert_eq!(
+            decoded.decrypt(&encrypted.payload).unwrap(),
+            test_attachment()
+        );
+
+        let mut insecure = remote;
+        insecure.url = "http://example.com/hello".to_string();
+        assert!(RemoteAttachmentCodec::encode(insecure).is_err());
+    }
+
+    #[test]
+    fn rejects_tampered_payload() {
+        let encrypted = EncryptedAttachment::encrypt(test_attachment()).unwrap();
+        let remote = RemoteAttachment::new("https://example.com/hello".to_string(), &encrypted);
+
+        let mut tampered = encrypted.payload.clone();
+        tampered[0] ^= 1;
+        assert!(remote.decrypt(&tampered).is_err());
+
+        // A payload matching a forged digest still fails authentication
+        let mut forged = remote;
+        forged.content_digest = super::digest(&tampered);
+        assert!(forged.decrypt(&tampered).is_err());
+    }
+
+    #[test]
+    fn decrypts_payload_from_another_implementation() {
+        // Encrypted outside this crate with HKDF-SHA256 and AES-256-GCM, as the other SDKs do
+        let payload = hex::decode(
+            "f7c0f02e720f494cafe4950e64397a19978b55365a20f71c9e672f0f9f4ccb8e698f3d1a324417ed726d\
+             afc88758944227e7d392de9009526ee86ae98dc33864a1d55a45a005f6be11aa7ca414be0aa50b7540c4\
+             d802d5e41d6e48a70f0ae38f6eb95403003ef644",
+        )
+        .unwrap();
+        let remote = RemoteAttachment {
+            url: "https://example.com/hello".to_string(),
+            content_digest: "1b485cf10af63b367f1584c0066b4f74fa4f5b5a75efcca28b6c50c496f5395d"
+                .to_string(),
+            secret: vec![1; 32],
+            salt: vec![2; 32],
+            nonce: vec![3; 12],
+            scheme: "https://".to_string(),
+            content_length: Some(payload.len() as u64),
+            filename: Some("hello.txt".to_string()),
+        };
+        assert_eq!(remote.decrypt(&payload).unwrap(), test_attachment());
+    }
+}
@@ -0,0 +1,87 @@
+use super::{text::TextCodec, CodecError, ContentCodec};
+use prost::Message;
+use std::collections::HashMap;
+use xmtp_proto::xmtp::message_contents::{ContentTypeId, EncodedContent};
+
+/// A reply to the message with id `reference`. The reply itself can be any content, and is
+/// decoded separately with its own codec.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reply {
+    pub reference: String,
+    pub content: EncodedContent,
+}
+
+pub struct ReplyCodec {}
+impl ReplyCodec {
+    const AUTHORITY_ID: &str = "xmtp.org";
+    const TYPE_ID: &str = "reply";
+    const REFERENCE_KEY: &str = "reference";
+}
+
+impl ContentCodec<Reply> for ReplyCodec {
+    fn content_type() -> ContentTypeId {
+        ContentTypeId {
+            authority_id: ReplyCodec::AUTHORITY_ID.to_string(),
+            type_id: ReplyCodec::TYPE_ID.to_string(),
+            version_major: 1,
+            version_minor: 0,
+        }
+    }
+
+    fn encode(reply: Reply) -> Result<EncodedContent, CodecError> {
+        let fallback = match TextCodec::decode(reply.content.clone()) {
+            Ok(text) => format!("Replied with “{}” to an earlier message", text),
+            Err(_) => reply
+                .content
+                .fallback
+                .clone()
+                .unwrap_or_else(|| "Replied to an earlier message".to_string()),
+        };
+
+        Ok(EncodedContent {
+            r#type: Some(ReplyCodec::content_type()),
+            parameters: HashMap::from([(ReplyCodec::REFERENCE_KEY.to_string(), reply.reference)]),
+            fallback: Some(fallback),
+            compression: None,
+            content: reply.content.encode_to_vec(),
+        })
+    }
+
+    fn decode(content: EncodedContent) -> Result<Reply, CodecError> {
+        let reference = content
+            .parameters
+            .get(ReplyCodec::REFERENCE_KEY)
+            .cloned()
+            .ok_or_else(|| CodecError::Decode("Reply is missing a reference".to_string()))?;
+        let inner = EncodedContent::decode(content.content.as_slice())
+            .map_err(|err| CodecError::Decode(err.to_string()))?;
+
+        Ok(Reply {
+            reference,
+            content: inner,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Reply, ReplyCodec};
+    use crate::codecs::{text::TextCodec, ContentCodec};
+
+    #[test]
+    fn can_encode_and_decode_reply() {
+        let reply = Reply {
+            reference: "message_id".to_string(),
+            content: TextCodec::encode("Hi back".to_string()).unwrap(),
+        };
+        let encoded = ReplyCodec::encode(reply.clone()).unwrap();
+        assert_eq!(
+            encoded.fallback.as_deref(),
+            Some("Replied with “Hi back” to an earlier message")
+        );
+
+        let decoded = ReplyCodec::decode(encoded).unwrap();
+        assert_eq!(decoded, reply);
+        assert_eq!(TextCodec::decode(decoded.content).unwrap(), "Hi back");
+    }
+}
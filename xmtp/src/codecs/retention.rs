@@ -0,0 +1,102 @@
+use super::{CodecError, ContentCodec};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use xmtp_proto::xmtp::message_contents::{ContentTypeId, EncodedContent};
+
+/// Sets how long messages in a conversation are kept after they were sent. Every member applies
+/// the most recent policy they receive. `None` keeps messages until they are deleted.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RetentionPolicy {
+    pub retention_ns: Option<i64>,
+}
+
+impl RetentionPolicy {
+    /// Messages can only be kept for a positive time
+    pub fn is_valid(&self) -> bool {
+        self.retention_ns
+            .map_or(true, |retention_ns| retention_ns > 0)
+    }
+}
+
+pub struct RetentionPolicyCodec {}
+impl RetentionPolicyCodec {
+    const AUTHORITY_ID: &str = "xmtp.org";
+    const TYPE_ID: &str = "retentionPolicy";
+}
+
+impl ContentCodec<RetentionPolicy> for RetentionPolicyCodec {
+    fn content_type() -> ContentTypeId {
+        ContentTypeId {
+            authority_id: RetentionPolicyCodec::AUTHORITY_ID.to_string(),
+            type_id: RetentionPolicyCodec::TYPE_ID.to_string(),
+            version_major: 1,
+            version_minor: 0,
+        }
+    }
+
+    fn encode(policy: RetentionPolicy) -> Result<EncodedContent, CodecError> {
+        let fallback = match policy.retention_ns {
+            Some(retention_ns) => format!(
+                "Messages now disappear {} seconds after they are sent",
+                retention_ns / 1_000_000_000
+            ),
+            None => "Turned off disappearing messages".to_string(),
+        };
+        let content =
+            serde_json::to_vec(&policy).map_err(|err| CodecError::Encode(err.to_string()))?;
+
+        Ok(EncodedContent {
+            r#type: Some(RetentionPolicyCodec::content_type()),
+            parameters: HashMap::new(),
+            fallback: Some(fallback),
+            compression: None,
+            content,
+        })
+    }
+
+    fn decode(content: EncodedContent) -> Result<RetentionPolicy, CodecError> {
+        let policy: RetentionPolicy = serde_json::from_slice(&content.content)
+            .map_err(|err| CodecError::Decode(err.to_string()))?;
+        if !policy.is_valid() {
+            return Err(CodecError::Decode(format!(
+                "Retention must be positive, got {:?}",
+                policy.retention_ns
+            )));
+        }
+        Ok(policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RetentionPolicy, RetentionPolicyCodec};
+    use crate::codecs::ContentCodec;
+
+    #[test]
+    fn can_encode_and_decode_retention_policy() {
+        let policy = RetentionPolicy {
+            retention_ns: Some(60 * 60 * 1_000_000_000),
+        };
+        let encoded = RetentionPolicyCodec::encode(policy).unwrap();
+        assert_eq!(
+            encoded.fallback.as_deref(),
+            Some("Messages now disappear 3600 seconds after they are sent")
+        );
+        assert_eq!(RetentionPolicyCodec::decode(encoded).unwrap(), policy);
+
+        let off = RetentionPolicy { retention_ns: None };
+        let encoded = RetentionPolicyCodec::encode(off).unwrap();
+        assert_eq!(RetentionPolicyCodec::decode(encoded).unwrap(), off);
+
+        let negative = RetentionPolicy {
+            retention_ns: Some(-1),
+        };
+        assert!(!negative.is_valid());
+        assert!(!RetentionPolicy {
+            retention_ns: Some(0)
+        }
+        .is_valid());
+        let encoded = RetentionPolicyCodec::encode(negative).unwrap();
+        assert!(RetentionPolicyCodec::decode(encoded).is_err());
+    }
+}
@@ -1,16 +1,17 @@
 use prost::{DecodeError, EncodeError, Message};
 use thiserror::Error;
 
-use vodozemac::Curve25519PublicKey;
+use vodozemac::{Curve25519PublicKey, Ed25519PublicKey, Ed25519Signature};
 use xmtp_proto::xmtp::v3::message_contents::{
     installation_contact_bundle::Version as ContactBundleVersionProto,
     vmac_account_linked_key::Association as AssociationProto, vmac_unsigned_public_key,
     Eip191Association as Eip191AssociationProto, InstallationContactBundle, VmacAccountLinkedKey,
+    VmacFallbackKeyRotation, VmacInstallationLinkedKey, VmacInstallationPublicKeyBundleV1,
 };
 
 use crate::{
     association::{Association, AssociationError},
-    utils::key_fingerprint,
+    utils::{base64_encode, key_fingerprint},
     vmac_protos::ProtoWrapper,
 };
 
@@ -20,6 +21,10 @@ pub enum ContactError {
     Association(#[from] AssociationError),
     #[error("bad data")]
     BadData,
+    #[error("invalid signature")]
+    InvalidSignature,
+    #[error("stale fallback key rotation")]
+    StaleRotation,
     #[error("decode error")]
     Decode(#[from] DecodeError),
     #[error("encode error")]
@@ -44,7 +49,18 @@ impl Contact {
         };
         // .association() will return an error if it fails to validate
         // If you try and create with a wallet address that doesn't match the signature, this will fail
+        // Bundles from before association text version 2 have no Ed25519 or sealed sender key,
+        // and are rejected here as unsupported
         contact.association()?;
+        contact.vmac_ed25519_key()?;
+        contact.verify_linked_key(&contact.sealed_sender_key()?)?;
+        match &contact.bundle.version {
+            Some(ContactBundleVersionProto::V1(VmacInstallationPublicKeyBundleV1 {
+                fallback_key: Some(key),
+                ..
+            })) => contact.verify_linked_key(key)?,
+            _ => return Err(ContactError::BadData),
+        }
 
         Ok(contact)
     }
@@ -71,13 +87,18 @@ impl Contact {
 
     pub fn association(&self) -> Result<Association, ContactError> {
         let ik = self.identity_key()?;
-        let key_bytes = match ik.clone().key {
+        let mut key_bytes = match ik.clone().key {
             Some(key) => match key.union {
                 Some(vmac_unsigned_public_key::Union::Curve25519(key)) => key.bytes,
                 None => return Err(ContactError::BadData),
             },
             None => return Err(ContactError::BadData),
         };
+        // The association covers both identity keys, see `VmacAccount::bytes_to_sign`
+        match &self.bundle.version {
+            Some(ContactBundleVersionProto::V1(v1)) => key_bytes.extend(&v1.ed25519_key),
+            None => return Err(ContactError::BadData),
+        }
         let proto_association = extract_proto_association(ik)?;
 
         // This will validate that the signature matches the wallet address
@@ -117,6 +138,99 @@ impl Contact {
 
         proto_key.into()
     }
+
+    pub fn vmac_ed25519_key(&self) -> Result<Ed25519PublicKey, ContactError> {
+        let key_bytes: [u8; 32] = match &self.bundle.version {
+            Some(ContactBundleVersionProto::V1(v1)) => v1
+                .ed25519_key
+                .as_slice()
+                .try_into()
+                .map_err(|_| ContactError::BadData)?,
+            None => return Err(ContactError::BadData),
+        };
+
+        Ed25519PublicKey::from_slice(&key_bytes).map_err(|_| ContactError::BadData)
+    }
+
+    /// Check a signature made by this installation with `Account::sign`
+    pub fn verify_signature(&self, message: &[u8], signature: &[u8]) -> Result<(), ContactError> {
+        let signature =
+            Ed25519Signature::from_slice(signature).map_err(|_| ContactError::InvalidSignature)?;
+
+        self.vmac_ed25519_key()?
+            .verify(base64_encode(message).as_bytes(), &signature)
+            .map_err(|_| ContactError::InvalidSignature)
+    }
+
+    /// Check that a key was published by this installation, with `Account::installation_linked_key`
+    pub fn verify_linked_key(&self, key: &VmacInstallationLinkedKey) -> Result<(), ContactError> {
+        match (&key.key, &key.signature) {
+            (Some(unsigned_key), Some(signature)) => {
+                self.verify_signature(&unsigned_key.encode_to_vec(), &signature.bytes)
+            }
+            (Some(_), None) => Err(ContactError::InvalidSignature),
+            (None, _) => Err(ContactError::BadData),
+        }
+    }
+
+    fn sealed_sender_key(&self) -> Result<VmacInstallationLinkedKey, ContactError> {
+        match &self.bundle.version {
+            Some(ContactBundleVersionProto::V1(VmacInstallationPublicKeyBundleV1 {
+                sealed_sender_key: Some(key),
+                ..
+            })) if key.key.is_some() => Ok(key.clone()),
+            _ => Err(ContactError::BadData),
+        }
+    }
+
+    pub fn vmac_sealed_sender_key(&self) -> Curve25519PublicKey {
+        let proto_key = ProtoWrapper {
+            proto: self.sealed_sender_key().unwrap(),
+        };
+
+        proto_key.into()
+    }
+
+    /// When the installation created its current fallback key, as covered by the key's signature
+    pub fn fallback_key_created_ns(&self) -> Option<u64> {
+        match &self.bundle.version {
+            Some(ContactBundleVersionProto::V1(v1)) => v1
+                .fallback_key
+                .as_ref()?
+                .key
+                .as_ref()
+                .map(|key| key.created_ns),
+            None => None,
+        }
+    }
+
+    /// Replace the fallback key with one from a rotation published by the same installation
+    pub fn apply_fallback_key_rotation(
+        &mut self,
+        rotation: VmacFallbackKeyRotation,
+    ) -> Result<(), ContactError> {
+        if rotation.identity_key != Some(self.identity_key()?) {
+            return Err(ContactError::BadData);
+        }
+        let fallback_key = match rotation.fallback_key {
+            Some(key) => key,
+            None => return Err(ContactError::BadData),
+        };
+        // The identity key is public, so only the signature shows the rotation came from its owner
+        self.verify_linked_key(&fallback_key)?;
+
+        // Old rotations stay validly signed, so replaying one must not roll the key back
+        if fallback_key.key.as_ref().map(|key| key.created_ns) <= self.fallback_key_created_ns() {
+            return Err(ContactError::StaleRotation);
+        }
+
+        match self.bundle.version.as_mut() {
+            Some(ContactBundleVersionProto::V1(v1)) => v1.fallback_key = Some(fallback_key),
+            None => return Err(ContactError::BadData),
+        }
+
+        Ok(())
+    }
 }
 
 impl PartialEq for Contact {
@@ -171,9 +285,11 @@ fn extract_proto_association(
 
 #[cfg(test)]
 mod tests {
+    use xmtp_proto::xmtp::v3::message_contents::installation_contact_bundle::Version as ContactBundleVersionProto;
+
     use crate::account::{tests::test_wallet_signer, Account};
 
-    use super::Contact;
+    use super::{Contact, ContactError};
 
     #[test]
     fn serialize_round_trip() {
@@ -185,6 +301,127 @@ mod tests {
         assert_eq!(contact_2_bytes, contact_bytes);
     }
 
+    #[test]
+    fn apply_fallback_key_rotation() {
+        let account = Account::generate(test_wallet_signer).unwrap();
+        let other_account = Account::generate(test_wallet_signer).unwrap();
+        let mut contact = account.contact();
+        let original_fallback_key = contact.vmac_fallback_key();
+
+        let rotation = account.rotate_fallback_key(0);
+        contact.apply_fallback_key_rotation(rotation).unwrap();
+        assert_ne!(contact.vmac_fallback_key(), original_fallback_key);
+        assert_eq!(
+            contact.vmac_fallback_key(),
+            account.contact().vmac_fallback_key()
+        );
+
+        // Rotations from another installation are rejected
+        let rotation = other_account.rotate_fallback_key(0);
+        assert!(contact.apply_fallback_key_rotation(rotation).is_err());
+        assert_eq!(
+            contact.vmac_fallback_key(),
+            account.contact().vmac_fallback_key()
+        );
+    }
+
+    #[test]
+    fn reject_forged_fallback_key_rotation() {
+        let account = Account::generate(test_wallet_signer).unwrap();
+        let other_account = Account::generate(test_wallet_signer).unwrap();
+        let mut contact = account.contact();
+        let original_fallback_key = contact.vmac_fallback_key();
+
+        // Another installation's signed key, presented under this installation's identity key
+        let mut rotation = other_account.rotate_fallback_key(0);
+        rotation.identity_key = Some(contact.identity_key().unwrap());
+        assert!(contact
+            .apply_fallback_key_rotation(rotation.clone())
+            .is_err());
+
+        // An unsigned key
+        rotation.fallback_key.as_mut().unwrap().signature = None;
+        assert!(contact.apply_fallback_key_rotation(rotation).is_err());
+
+        // A genuine signature moved onto a different key
+        let mut rotation = account.rotate_fallback_key(0);
+        rotation.fallback_key.as_mut().unwrap().key = other_account
+            .rotate_fallback_key(0)
+            .fallback_key
+            .unwrap()
+            .key;
+        assert!(contact.apply_fallback_key_rotation(rotation).is_err());
+
+        assert_eq!(contact.vmac_fallback_key(), original_fallback_key);
+    }
+
+    #[test]
+    fn reject_replayed_fallback_key_rotation() {
+        let account = Account::generate(test_wallet_signer).unwrap();
+        let mut contact = account.contact();
+
+        let first_rotation = account.rotate_fallback_key(0);
+        let second_rotation = account.rotate_fallback_key(0);
+        contact
+            .apply_fallback_key_rotation(second_rotation.clone())
+            .unwrap();
+        let latest_fallback_key = contact.vmac_fallback_key();
+
+        // Both rotations are genuinely signed, but neither is newer than the stored key
+        assert!(matches!(
+            contact.apply_fallback_key_rotation(first_rotation),
+            Err(ContactError::StaleRotation)
+        ));
+        assert!(matches!(
+            contact.apply_fallback_key_rotation(second_rotation),
+            Err(ContactError::StaleRotation)
+        ));
+        assert_eq!(contact.vmac_fallback_key(), latest_fallback_key);
+        assert_eq!(
+            contact.vmac_fallback_key(),
+            account.contact().vmac_fallback_key()
+        );
+    }
+
+    #[test]
+    fn verify_signature() {
+        let account = Account::generate(test_wallet_signer).unwrap();
+        let other_account = Account::generate(test_wallet_signer).unwrap();
+        let signature = account.sign(b"hello").unwrap().to_bytes();
+
+        let contact = account.contact();
+        contact.verify_signature(b"hello", &signature).unwrap();
+        assert!(contact.verify_signature(b"goodbye", &signature).is_err());
+        assert!(contact
+            .verify_signature(b"hello", &signature[..10])
+            .is_err());
+        assert!(other_account
+            .contact()
+            .verify_signature(b"hello", &signature)
+            .is_err());
+    }
+
+    #[test]
+    fn reject_substituted_ed25519_key() {
+        let account = Account::generate(test_wallet_signer).unwrap();
+        let other_account = Account::generate(test_wallet_signer).unwrap();
+
+        let mut bundle = account.contact().bundle;
+        match bundle.version.as_mut() {
+            Some(ContactBundleVersionProto::V1(v1)) => {
+                v1.ed25519_key = other_account
+                    .contact()
+                    .vmac_ed25519_key()
+                    .unwrap()
+                    .as_bytes()
+                    .to_vec()
+            }
+            None => panic!("expected a v1 bundle"),
+        }
+
+        assert!(Contact::new(bundle, account.addr()).is_err());
+    }
+
     #[test]
     fn get_association() {
         let account = Account::generate(test_wallet_signer).unwrap();
@@ -1,25 +1,29 @@
+use std::{any::Any, time::Duration};
+
 use crate::{
     client::ClientError,
-    codecs::{text::TextCodec, CodecError, ContentCodec},
+    codecs::{retention::RetentionPolicy, text::TextCodec, CodecError, ContentCodec},
     contact::Contact,
     conversations::Conversations,
-    invitation::{Invitation, InvitationError},
+    invitation::InvitationError,
     message::PayloadError,
     session::SessionError,
     storage::{
-        now, ConversationState, DbConnection, MessageState, NewStoredMessage, StorageError,
-        StoredConversation, StoredMessage, StoredUser,
+        now, ConsentState, ConversationKind, ConversationState, DbConnection, DeliveryStatus,
+        InvalidMessageReason, MessageState, NewStoredMessage, StorageError, StoredConversation,
+        StoredMessage, StoredUser,
     },
-    types::networking::PublishRequest,
     types::networking::XmtpApiClient,
     types::Address,
-    utils::{build_envelope, build_user_invite_topic},
     Client, Save, Store,
 };
 
+use futures::{future, Stream, StreamExt};
 use prost::{DecodeError, Message};
+use rand::RngCore;
 // use async_trait::async_trait;
 use thiserror::Error;
+use xmtp_proto::xmtp::message_contents::ContentTypeId;
 
 #[derive(Debug, Error)]
 pub enum ConversationError {
@@ -45,16 +49,71 @@ pub enum ConversationError {
     Networking(#[from] crate::types::networking::Error),
     #[error("Payload:{0}")]
     Payload(#[from] PayloadError),
+    #[error("Invalid message: {0:?}")]
+    InvalidMessage(InvalidMessageReason),
     #[error("error:{0}")]
     Generic(String),
 }
 
+impl ConversationError {
+    /// Whether sending a message could succeed if it is attempted again later, such as once the
+    /// network is reachable or the recipient has registered an installation
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Client(err) => matches!(
+                err,
+                ClientError::PublishError(_)
+                    | ClientError::Storage(_)
+                    | ClientError::Ddd(_)
+                    | ClientError::QueryError(_)
+            ),
+            Self::Storage(_) | Self::Diesel(_) | Self::NoSessions(_) | Self::Networking(_) => true,
+            Self::Invitation(_)
+            | Self::Codec(_)
+            | Self::Decode(_)
+            | Self::DecodeVmac(_)
+            | Self::Session(_)
+            | Self::Payload(_)
+            | Self::InvalidMessage(_)
+            | Self::Generic(_) => false,
+        }
+    }
+}
+
 pub fn convo_id(self_addr: String, peer_addr: String) -> String {
     let mut members = [self_addr, peer_addr];
     members.sort();
     format!(":{}:{}", members[0], members[1])
 }
 
+const GROUP_CONVO_ID_PREFIX: &str = "group-";
+
+// Group members change over time, so unlike direct conversations the id is random. The creator is
+// part of the id, so that every member agrees on who may remove members.
+pub fn new_group_convo_id(creator: &str) -> String {
+    let mut bytes = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    format!(
+        "{}{}-{}",
+        GROUP_CONVO_ID_PREFIX,
+        hex::encode(bytes),
+        creator
+    )
+}
+
+/// The address that created a group, if its id records one
+pub fn group_creator_from_convo_id(convo_id: &str) -> Option<&str> {
+    let (_, creator) = convo_id
+        .strip_prefix(GROUP_CONVO_ID_PREFIX)?
+        .split_once('-')?;
+
+    Some(creator)
+}
+
+pub fn is_group_convo_id(convo_id: &str) -> bool {
+    convo_id.starts_with(GROUP_CONVO_ID_PREFIX)
+}
+
 pub fn peer_addr_from_convo_id(
     convo_id: &str,
     self_addr: &str,
@@ -73,11 +132,54 @@ pub fn peer_addr_from_convo_id(
     }
 }
 
+pub fn is_convo_member(convo_id: &str, address: &str) -> bool {
+    let segments = convo_id.split(':').collect::<Vec<&str>>();
+    segments.len() == 3 && segments[1..].contains(&address)
+}
+
+/// The order messages are listed in
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SortDirection {
+    /// Oldest first
+    #[default]
+    Ascending,
+    /// Newest first
+    Descending,
+}
+
+/// The position of a message in a listing. Messages sent at the same time are ordered by their
+/// local id, so a page which ends part way through them is continued without skipping or
+/// repeating any.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MessageCursor {
+    pub sent_at_ns: i64,
+    pub id: i32,
+}
+
+impl From<&StoredMessage> for MessageCursor {
+    fn from(message: &StoredMessage) -> Self {
+        Self {
+            sent_at_ns: message.sent_at_ns,
+            id: message.id,
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct ListMessagesOptions {
     pub start_time_ns: Option<i64>,
     pub end_time_ns: Option<i64>,
     pub limit: Option<i64>,
+    /// Only list messages after this one in `direction`. Pass the last message of a page to get
+    /// the next page.
+    pub cursor: Option<MessageCursor>,
+    pub direction: SortDirection,
+    /// Only list messages sent by one of these addresses
+    pub sender_addresses: Option<Vec<String>>,
+    /// Only list messages whose content has one of these types, in any version
+    pub content_types: Option<Vec<ContentTypeId>>,
+    /// Also list delivery and read receipts, which are hidden by default
+    pub include_receipts: bool,
 }
 
 impl ListMessagesOptions {
@@ -86,10 +188,30 @@ impl ListMessagesOptions {
             start_time_ns,
             end_time_ns,
             limit,
+            ..Default::default()
         }
     }
 }
 
+/// Narrows [`crate::Client::search_messages`]. Results are ranked rather than paged, so unlike
+/// `ListMessagesOptions` there is no cursor or direction.
+#[derive(Default)]
+pub struct SearchMessagesOptions {
+    pub start_time_ns: Option<i64>,
+    pub end_time_ns: Option<i64>,
+    pub limit: Option<i64>,
+    /// Only find messages sent by one of these addresses
+    pub sender_addresses: Option<Vec<String>>,
+}
+
+/// The delivery status of a message for one recipient installation
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecipientStatus {
+    pub recipient_address: String,
+    pub installation_id: String,
+    pub status: DeliveryStatus,
+}
+
 // I had to pick a name for this, and it seems like we are hovering around SecretConversation ATM
 // May very well change
 pub struct SecretConversation<'c, A>
@@ -127,7 +249,11 @@ where
                 peer_address: obj.peer_address(),
                 convo_id: obj.convo_id(),
                 created_at: now(),
-                convo_state: ConversationState::Uninitialized as i32,
+                convo_state: ConversationState::Uninitialized,
+                convo_kind: ConversationKind::Direct,
+                // Starting a conversation is consent to it
+                consent_state: ConsentState::Allowed,
+                retention_ns: None,
             },
         )?;
 
@@ -142,12 +268,57 @@ where
         self.peer_address.clone()
     }
 
+    fn stored_conversation(&self) -> Result<StoredConversation, ConversationError> {
+        self.client
+            .store
+            .get_conversation(&self.convo_id())?
+            .ok_or_else(|| ConversationError::Generic("conversation not found".into()))
+    }
+
+    pub fn consent_state(&self) -> Result<ConsentState, ConversationError> {
+        Ok(self.stored_conversation()?.consent_state)
+    }
+
+    /// How long messages are kept after they were sent, or `None` if they are kept until deleted
+    pub fn retention(&self) -> Result<Option<Duration>, ConversationError> {
+        Ok(self
+            .stored_conversation()?
+            .retention_ns
+            .map(|retention_ns| Duration::from_nanos(retention_ns as u64)))
+    }
+
+    /// Make messages disappear `retention` after they were sent, for every member of the
+    /// conversation. `None` keeps messages until they are deleted.
+    pub async fn set_retention(
+        &self,
+        retention: Option<Duration>,
+    ) -> Result<(), ConversationError> {
+        let retention_ns = match retention {
+            Some(retention) => Some(i64::try_from(retention.as_nanos()).map_err(|_| {
+                ConversationError::Generic(format!("retention of {:?} is too long", retention))
+            })?),
+            None => None,
+        };
+        let policy = RetentionPolicy { retention_ns };
+        if !policy.is_valid() {
+            return Err(ConversationError::Generic(
+                "retention must be positive".to_string(),
+            ));
+        }
+        self.client.store.set_conversation_retention(
+            &mut self.client.store.conn()?,
+            &self.convo_id(),
+            retention_ns,
+        )?;
+        self.send_content(policy).await
+    }
+
     pub async fn send(&self, content_bytes: Vec<u8>) -> Result<(), ConversationError> {
         NewStoredMessage::new(
             self.convo_id(),
             self.client.account.addr(),
             content_bytes,
-            MessageState::Unprocessed as i32,
+            MessageState::Unprocessed,
             now(),
         )
         .store(&mut self.client.store.conn().unwrap())?;
@@ -159,9 +330,17 @@ where
         Ok(())
     }
 
+    /// Encode `content` with the client's codec for its type and send it
+    pub async fn send_content<T: Any>(&self, content: T) -> Result<(), ConversationError> {
+        let encoded_content = self.client.codecs.encode(content)?;
+        self.send(encoded_content.encode_to_vec()).await
+    }
+
     pub async fn send_text(&self, text: &str) -> Result<(), ConversationError> {
-        // TODO support other codecs
-        let encoded_content = TextCodec::encode(text.to_string())?;
+        let encoded_content = self
+            .client
+            .codecs
+            .compress(TextCodec::encode(text.to_string())?)?;
         let content_bytes = encoded_content.encode_to_vec();
 
         self.send(content_bytes).await
@@ -176,14 +355,52 @@ where
             conn,
             Some(vec![MessageState::Received, MessageState::LocallyCommitted]),
             Some(self.convo_id().as_str()),
-            opts.start_time_ns,
-            opts.end_time_ns,
-            opts.limit,
+            opts,
         )?;
 
         Ok(messages)
     }
 
+    /// How far a message sent from this installation has got towards each installation it was
+    /// sent to. Messages which were never sent from this installation have no recipients.
+    pub fn message_status(
+        &self,
+        message_id: &str,
+    ) -> Result<Vec<RecipientStatus>, ConversationError> {
+        let conn = &mut self.client.store.conn()?;
+        match self
+            .client
+            .store
+            .get_message_by_message_id(conn, message_id)?
+        {
+            Some(message) if message.convo_id == self.convo_id() => {}
+            _ => return Ok(vec![]),
+        }
+
+        self.client
+            .store
+            .get_message_payloads(conn, message_id)?
+            .into_iter()
+            .map(|payload| {
+                Ok(RecipientStatus {
+                    recipient_address: payload.recipient_address,
+                    installation_id: payload.recipient_installation_id,
+                    status: payload.delivery_status,
+                })
+            })
+            .collect()
+    }
+
+    /// Stream messages received in this conversation as they arrive
+    pub async fn stream(
+        &self,
+    ) -> Result<impl Stream<Item = StoredMessage> + 'c, ConversationError> {
+        let convo_id = self.convo_id();
+        let messages = self.client.stream_messages().await?;
+
+        Ok(messages.filter(move |message| future::ready(message.convo_id == convo_id)))
+    }
+
     fn members(&self, conn: &mut DbConnection) -> Result<Vec<Contact>, ConversationError> {
         let my_installations = self.client.my_other_devices(conn)?;
         let peer_installations = self
@@ -197,37 +414,17 @@ where
         self.client
             .refresh_user_installations(self.peer_address().as_str())
             .await?;
-        let inner_invite_bytes = Invitation::build_inner_invite_bytes(self.peer_address.clone())?;
-        let conn = &mut self.client.store.conn()?;
-        for contact in self.members(conn)?.iter() {
-            let id = contact.installation_id();
-
-            let mut session = self.client.get_session(conn, contact)?;
-            let invitation = Invitation::build(
-                self.client.account.contact(),
-                &mut session,
-                &inner_invite_bytes,
-            )?;
-
-            let envelope = build_envelope(build_user_invite_topic(id), invitation.try_into()?);
-
-            self.client
-                .api_client
-                // TODO: API authentication
-                .publish(
-                    "".to_string(),
-                    PublishRequest {
-                        envelopes: vec![envelope],
-                    },
-                )
-                .await
-                .map_err(|e| ConversationError::Generic(format!("initialize:{}", e)))?;
-
-            session.save(conn)?;
+        // Connections aren't held across network calls, which may need one of their own
+        let members = self.members(&mut self.client.store.conn()?)?;
+        for contact in members.iter() {
+            let mut session = self.client.get_session(contact).await?;
+            Conversations::send_invitation(self.client, &mut session, contact, &self.peer_address)
+                .await?;
+            session.save(&mut self.client.store.conn()?)?;
         }
 
         self.client.store.set_conversation_state(
-            conn,
+            &mut self.client.store.conn()?,
             self.convo_id().as_str(),
             ConversationState::Invited,
         )?;
@@ -238,16 +435,33 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use futures::StreamExt;
     use prost::Message;
     use xmtp_proto::xmtp::message_contents::EncodedContent;
 
     use crate::{
-        codecs::{text::TextCodec, ContentCodec},
-        conversation::ListMessagesOptions,
+        codecs::{
+            delivery_receipt::DeliveryReceipt, read_receipt::ReadReceipt, reply::Reply,
+            text::TextCodec, ContentCodec, MessageContent,
+        },
+        conversation::{ListMessagesOptions, SecretConversation},
         conversations::Conversations,
+        storage::{clock::ManualClock, now, DeliveryStatus, InboundMessageStatus},
         test_utils::test_utils::{gen_test_client, gen_test_conversation, gen_two_test_clients},
+        types::networking::{Error as NetworkingError, ErrorKind},
     };
 
+    use super::ConversationError;
+
+    #[test]
+    fn network_errors_are_retryable() {
+        let err: ConversationError = NetworkingError::new(ErrorKind::PublishError).into();
+        assert!(err.is_retryable());
+        assert!(!ConversationError::Generic("invite".to_string()).is_retryable());
+    }
+
     #[tokio::test]
     async fn test_local_conversation_creation() {
         let client = gen_test_client().await;
@@ -271,6 +485,110 @@ mod tests {
         assert!(TextCodec::decode(content).unwrap() == "Hello, world!");
     }
 
+    #[tokio::test]
+    async fn test_receive_typed_content() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let alice_to_bob = gen_test_conversation(&alice_client, &bob_client.wallet_address()).await;
+        let reply = Reply {
+            reference: "message_id".to_string(),
+            content: TextCodec::encode("Hello, world!".to_string()).unwrap(),
+        };
+        alice_to_bob.send_content(reply.clone()).await.unwrap();
+
+        Conversations::receive(&bob_client).unwrap();
+        let bob_to_alice =
+            SecretConversation::new(&bob_client, alice_client.wallet_address()).unwrap();
+        let messages = bob_to_alice
+            .list_messages(&ListMessagesOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            bob_client.decode_message(&messages[0]).unwrap(),
+            MessageContent::Reply(reply.clone())
+        );
+        assert_eq!(
+            messages[0].decode::<Reply>(bob_client.codecs()).unwrap(),
+            reply
+        );
+    }
+
+    #[tokio::test]
+    async fn test_message_status() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let alice_to_bob = gen_test_conversation(&alice_client, &bob_client.wallet_address()).await;
+        let statuses = |message_id: &str| {
+            alice_to_bob
+                .message_status(message_id)
+                .unwrap()
+                .into_iter()
+                .map(|recipient| recipient.status)
+                .collect::<Vec<_>>()
+        };
+
+        // Message requests are not acknowledged
+        alice_to_bob.send_text("Hello, world!").await.unwrap();
+        Conversations::receive(&bob_client).unwrap();
+        Conversations::process_outbound_messages(&bob_client)
+            .await
+            .unwrap();
+        Conversations::receive(&alice_client).unwrap();
+
+        // Once Bob allows Alice, he acknowledges her messages when his outbound messages are
+        // processed
+        Conversations::allow(&bob_client, &alice_client.wallet_address()).unwrap();
+        alice_to_bob.send_text("Are you there?").await.unwrap();
+        Conversations::receive(&bob_client).unwrap();
+        Conversations::process_outbound_messages(&bob_client)
+            .await
+            .unwrap();
+        Conversations::receive(&alice_client).unwrap();
+
+        let sent = alice_to_bob
+            .list_messages(&ListMessagesOptions::default())
+            .await
+            .unwrap();
+        let (request_id, message_id) = (&sent[0].message_id, &sent[1].message_id);
+        assert_eq!(statuses(request_id), vec![DeliveryStatus::Published]);
+        assert_eq!(statuses(message_id), vec![DeliveryStatus::Delivered]);
+
+        let bob_to_alice =
+            SecretConversation::new(&bob_client, alice_client.wallet_address()).unwrap();
+        bob_to_alice.send_content(ReadReceipt {}).await.unwrap();
+        Conversations::receive(&alice_client).unwrap();
+        assert_eq!(statuses(request_id), vec![DeliveryStatus::Read]);
+        assert_eq!(statuses(message_id), vec![DeliveryStatus::Read]);
+
+        // Receipts are only listed when asked for
+        let listed = alice_to_bob
+            .list_messages(&ListMessagesOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 2);
+        let received = alice_to_bob
+            .list_messages(&ListMessagesOptions {
+                include_receipts: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(received.len(), 4);
+        assert!(received[2..].iter().all(|receipt| receipt
+            .decode::<DeliveryReceipt>(bob_client.codecs())
+            .is_ok()
+            || receipt.decode::<ReadReceipt>(bob_client.codecs()).is_ok()));
+
+        // Bob's receipts were not sent from Alice's installation
+        let receipt = received
+            .iter()
+            .find(|message| message.addr_from == bob_client.wallet_address())
+            .unwrap();
+        assert!(alice_to_bob
+            .message_status(&receipt.message_id)
+            .unwrap()
+            .is_empty());
+    }
+
     #[tokio::test]
     async fn test_list_messages() {
         let (client, recipient) = gen_two_test_clients().await;
@@ -290,4 +608,107 @@ mod tests {
 
         assert_eq!(results.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let bob_to_alice =
+            SecretConversation::new(&bob_client, alice_client.wallet_address()).unwrap();
+        let stream = bob_to_alice.stream().await.unwrap();
+        futures::pin_mut!(stream);
+
+        let alice_to_bob =
+            SecretConversation::new(&alice_client, bob_client.wallet_address()).unwrap();
+        alice_to_bob.send_text("Hello, world!").await.unwrap();
+
+        let message = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("timed out waiting for message")
+            .unwrap();
+        assert_eq!(message.convo_id, bob_to_alice.convo_id());
+        assert_eq!(message.get_text().unwrap(), "Hello, world!");
+    }
+
+    #[tokio::test]
+    async fn stream_skips_messages_received_elsewhere() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let bob_to_alice =
+            SecretConversation::new(&bob_client, alice_client.wallet_address()).unwrap();
+        let stream = bob_to_alice.stream().await.unwrap();
+        futures::pin_mut!(stream);
+
+        let alice_to_bob =
+            SecretConversation::new(&alice_client, bob_client.wallet_address()).unwrap();
+        alice_to_bob.send_text("Hello, world!").await.unwrap();
+        // The envelope is saved by `receive` before the subscription sees it
+        Conversations::receive(&bob_client).unwrap();
+
+        let next = tokio::time::timeout(Duration::from_millis(500), stream.next()).await;
+        assert!(next.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_disappearing_messages() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let alice_to_bob = gen_test_conversation(&alice_client, &bob_client.wallet_address()).await;
+        let retention = Duration::from_secs(60 * 60);
+        assert!(alice_to_bob
+            .set_retention(Some(Duration::ZERO))
+            .await
+            .is_err());
+        assert!(alice_to_bob
+            .set_retention(Some(Duration::MAX))
+            .await
+            .is_err());
+        assert_eq!(alice_to_bob.retention().unwrap(), None);
+        alice_to_bob.set_retention(Some(retention)).await.unwrap();
+        alice_to_bob.send_text("Hello, world!").await.unwrap();
+        assert_eq!(alice_to_bob.retention().unwrap(), Some(retention));
+
+        Conversations::receive(&bob_client).unwrap();
+        let bob_to_alice =
+            SecretConversation::new(&bob_client, alice_client.wallet_address()).unwrap();
+        assert_eq!(bob_to_alice.retention().unwrap(), Some(retention));
+        let bob_messages = || {
+            bob_client
+                .store
+                .get_stored_messages(
+                    &mut bob_client.store.conn().unwrap(),
+                    None,
+                    Some(&bob_to_alice.convo_id()),
+                    &ListMessagesOptions::default(),
+                )
+                .unwrap()
+                .len()
+        };
+        let processed = || {
+            bob_client
+                .store
+                .get_inbound_messages(
+                    &mut bob_client.store.conn().unwrap(),
+                    InboundMessageStatus::Processed,
+                )
+                .unwrap()
+                .len()
+        };
+        assert_eq!(bob_messages(), 2);
+        assert_eq!(processed(), 2);
+
+        let clock = ManualClock::new(now());
+        clock.advance(retention / 2);
+        assert_eq!(bob_client.store.sweep_expired_messages(&clock).unwrap(), 0);
+        clock.advance(retention);
+        assert_eq!(bob_client.store.sweep_expired_messages(&clock).unwrap(), 2);
+        assert_eq!(bob_messages(), 0);
+        assert_eq!(processed(), 0);
+        // Alice's copies are swept by her own store
+        assert_eq!(
+            alice_client.store.sweep_expired_messages(&clock).unwrap(),
+            2
+        );
+
+        bob_to_alice.set_retention(None).await.unwrap();
+        Conversations::receive(&alice_client).unwrap();
+        assert_eq!(alice_to_bob.retention().unwrap(), None);
+    }
 }
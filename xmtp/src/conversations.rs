@@ -1,12 +1,13 @@
 use std::time::Duration;
 
 use diesel::Connection;
-use futures::executor::block_on;
+use futures::{executor::block_on, stream, Stream};
 use log::info;
 use prost::Message;
 use vodozemac::olm::{self, OlmMessage};
 use xmtp_proto::xmtp::{
     message_api::v1::{Envelope, PublishRequest},
+    message_contents::EncodedContent,
     v3::message_contents::{
         EdDsaSignature, InvitationV1, PadlockMessageEnvelope, PadlockMessageHeader,
         PadlockMessagePayload, PadlockMessagePayloadVersion, PadlockMessageSealedMetadata,
@@ -14,33 +15,69 @@ use xmtp_proto::xmtp::{
 };
 
 use crate::{
+    client::ClientError,
+    codecs::{
+        delivery_receipt::{DeliveryReceipt, DeliveryReceiptCodec},
+        membership::{GroupMembershipChange, GroupMembershipCodec},
+        ContentCodec, MessageContent,
+    },
     contact::Contact,
-    conversation::{convo_id, peer_addr_from_convo_id, ConversationError, SecretConversation},
+    conversation::{
+        convo_id, is_convo_member, is_group_convo_id, peer_addr_from_convo_id, ConversationError,
+        ListMessagesOptions, SecretConversation,
+    },
+    group_conversation::{is_authorized_membership_change, GroupConversation},
     invitation::Invitation,
     message::DecodedInboundMessage,
+    sealed_sender,
     session::SessionManager,
     storage::{
-        now, ConversationState, DbConnection, InboundInvite, InboundInviteStatus, InboundMessage,
-        InboundMessageStatus, MessageState, NewStoredMessage, OutboundPayloadState, RefreshJob,
-        RefreshJobKind, StorageError, StoredConversation, StoredMessage, StoredOutboundPayload,
-        StoredSession, StoredUser,
+        compute_message_id, content_type_name, now, ConsentState, ConversationKind,
+        ConversationState, DbConnection, DeliveryStatus, InboundInvite, InboundInviteStatus,
+        InboundMessage, InboundMessageStatus, InvalidMessageReason, MessageState, NewStoredMessage,
+        OutboundPayloadState, RefreshJob, RefreshJobKind, StorageError, StoredConversation,
+        StoredInstallation, StoredMessage, StoredOutboundPayload, StoredSession, StoredUser,
     },
     types::networking::XmtpApiClient,
-    utils::{base64_encode, build_installation_message_topic},
+    utils::{build_envelope, build_installation_message_topic, build_user_invite_topic},
     vmac_protos::ProtoWrapper,
-    Client,
+    Client, Fetch, Save, Store,
 };
 
 const PADDING_TIME_NS: i64 = 30 * 1000 * 1000 * 1000;
+// Delay before the first retry of a failed send, doubled for every attempt after that
+const RETRY_BASE_DELAY_NS: i64 = 1000 * 1000 * 1000;
+const RETRY_MAX_DELAY_NS: i64 = 60 * 60 * 1000 * 1000 * 1000;
+// Long enough for a batch to be published. A payload still locked after this is assumed to have
+// been abandoned by a crashed process, and is published again.
+const OUTBOUND_PAYLOAD_LEASE_NS: i64 = 60 * 1000 * 1000 * 1000;
+const OUTBOUND_PAYLOAD_BATCH_SIZE: i64 = 50;
+// How far back messages are resent to installations discovered after they were sent
+const BACKFILL_PERIOD_NS: i64 = 7 * 24 * 60 * 60 * 1000 * 1000 * 1000;
 
 pub struct Conversations<A: XmtpApiClient> {
     _phantom: std::marker::PhantomData<A>,
 }
 
 impl<A: XmtpApiClient> Conversations<A> {
+    /// Direct conversations which the user has not blocked
     pub async fn list(
         client: &Client<A>,
         refresh_from_network: bool,
+    ) -> Result<Vec<SecretConversation<A>>, ConversationError> {
+        Conversations::list_with_consent(
+            client,
+            refresh_from_network,
+            &[ConsentState::Allowed, ConsentState::Unknown],
+        )
+        .await
+    }
+
+    /// Direct conversations in one of `consent_states`, e.g. only `Unknown` for message requests
+    pub async fn list_with_consent(
+        client: &Client<A>,
+        refresh_from_network: bool,
+        consent_states: &[ConsentState],
     ) -> Result<Vec<SecretConversation<A>>, ConversationError> {
         if refresh_from_network {
             Conversations::save_invites(client)?;
@@ -62,6 +99,13 @@ impl<A: XmtpApiClient> Conversations<A> {
 
         log::debug!("Retrieved {:?} convos from the database", convos.len());
         for convo in convos {
+            // Groups are listed separately, see `Conversations::list_groups`
+            if convo.convo_kind == ConversationKind::Group {
+                continue;
+            }
+            if !consent_states.contains(&convo.consent_state) {
+                continue;
+            }
             let peer_address = peer_addr_from_convo_id(&convo.convo_id, &client.account.addr())?;
 
             let convo = SecretConversation::new(client, peer_address)?;
@@ -71,6 +115,49 @@ impl<A: XmtpApiClient> Conversations<A> {
         Ok(secret_convos)
     }
 
+    /// Group conversations which have been created locally or joined through a membership change
+    pub fn list_groups(client: &Client<A>) -> Result<Vec<GroupConversation<A>>, ConversationError> {
+        let convos: Vec<StoredConversation> = client.store.get_conversations(
+            &mut client.store.conn()?,
+            vec![
+                ConversationState::InviteReceived,
+                ConversationState::Invited,
+            ],
+        )?;
+
+        convos
+            .into_iter()
+            .filter(|convo| convo.convo_kind == ConversationKind::Group)
+            .filter(|convo| convo.consent_state != ConsentState::Denied)
+            .map(|convo| GroupConversation::from_convo_id(client, convo.convo_id))
+            .collect()
+    }
+
+    /// Allow the conversation with `peer_address`, surfacing any messages they send from now on
+    pub fn allow(client: &Client<A>, peer_address: &str) -> Result<(), ConversationError> {
+        Conversations::set_consent(client, peer_address, ConsentState::Allowed)
+    }
+
+    /// Block `peer_address`. Messages they send from now on, including to groups, are stored as
+    /// `MessageState::Ignored` and not surfaced.
+    pub fn block(client: &Client<A>, peer_address: &str) -> Result<(), ConversationError> {
+        Conversations::set_consent(client, peer_address, ConsentState::Denied)
+    }
+
+    fn set_consent(
+        client: &Client<A>,
+        peer_address: &str,
+        state: ConsentState,
+    ) -> Result<(), ConversationError> {
+        // Creates the conversation if there isn't one, so peers can be blocked before they write
+        let convo = SecretConversation::new(client, peer_address.to_string())?;
+        client
+            .store
+            .set_consent_state(&mut client.store.conn()?, &convo.convo_id(), state)?;
+
+        Ok(())
+    }
+
     pub fn receive(client: &Client<A>) -> Result<(), ConversationError> {
         if Conversations::save_inbound_messages(client).is_err() {
             log::warn!("Saving messages did not complete successfully");
@@ -120,15 +207,17 @@ impl<A: XmtpApiClient> Conversations<A> {
                 let payload_id = msg.id.clone();
                 match Conversations::process_inbound_message(client, transaction_manager, msg) {
                     Ok(status) => {
-                        info!(
-                            "message processed: {:?}. Status: {:?}",
-                            payload_id,
-                            status.clone()
-                        );
+                        info!("message processed: {:?}. Status: {:?}", payload_id, status);
                         client
                             .store
                             .set_msg_status(transaction_manager, payload_id, status)?;
                     }
+                    Err(ConversationError::InvalidMessage(reason)) => {
+                        log::warn!("Rejected invalid msg {:?}: {:?}", payload_id, reason);
+                        client
+                            .store
+                            .set_msg_invalid(transaction_manager, payload_id, reason)?;
+                    }
                     Err(err) => {
                         log::error!("Error processing msg: {:?}", err);
                         return Err(StorageError::Unknown(err.to_string()));
@@ -144,7 +233,14 @@ impl<A: XmtpApiClient> Conversations<A> {
         conn: &mut DbConnection,
         msg: InboundMessage,
     ) -> Result<InboundMessageStatus, ConversationError> {
-        let payload = DecodedInboundMessage::try_from(msg.clone())?;
+        let payload = DecodedInboundMessage::try_from((msg.clone(), &client.account))?;
+        if payload.recipient_address != client.wallet_address()
+            || payload.recipient_installation_id != client.installation_id()
+        {
+            return Err(ConversationError::InvalidMessage(
+                InvalidMessageReason::RecipientMismatch,
+            ));
+        }
         let olm_message = (&payload).try_into()?;
 
         let existing_sessions = client
@@ -153,6 +249,12 @@ impl<A: XmtpApiClient> Conversations<A> {
 
         // Attempt to decrypt with existing sessions
         for raw_session in existing_sessions {
+            // The sender installation must belong to the claimed sender address
+            if raw_session.user_address != payload.sender_address {
+                return Err(ConversationError::InvalidMessage(
+                    InvalidMessageReason::SenderMismatch,
+                ));
+            }
             let mut session = match SessionManager::try_from(&raw_session) {
                 Ok(s) => s,
                 Err(e) => {
@@ -163,7 +265,14 @@ impl<A: XmtpApiClient> Conversations<A> {
 
             match session.decrypt(&olm_message, conn) {
                 Ok(p) => {
-                    Conversations::process_plaintext(client, conn, &p, &payload)?;
+                    let sender = Conversations::<A>::get_installation_contact(
+                        conn,
+                        &payload.sender_installation_id,
+                    )?
+                    .ok_or(ConversationError::InvalidMessage(
+                        InvalidMessageReason::UnknownSender,
+                    ))?;
+                    Conversations::process_plaintext(client, conn, &p, &sender, &payload)?;
                     return Ok(InboundMessageStatus::Processed);
                 }
                 Err(_) => continue,
@@ -184,34 +293,297 @@ impl<A: XmtpApiClient> Conversations<A> {
         client: &Client<A>,
         conn: &mut DbConnection,
         bytes: &Vec<u8>,
+        sender: &Contact,
         payload: &DecodedInboundMessage,
     ) -> Result<(), ConversationError> {
         let message_obj =
             PadlockMessagePayload::decode(bytes.as_slice()).map_err(ConversationError::Decode)?;
 
-        //TODO: Validate message
+        Conversations::validate_payload(client, conn, &message_obj, sender, payload)?;
+        let membership_change = Conversations::<A>::decode_membership_change(&message_obj);
+
+        // The sender is blocked if either the conversation or their direct conversation is denied
+        let blocked = client.store.any_conversation_denied(
+            conn,
+            vec![
+                message_obj.convo_id.clone(),
+                convo_id(payload.sender_address.clone(), client.wallet_address()),
+            ],
+        )?;
+        let state = if blocked {
+            MessageState::Ignored
+        } else {
+            MessageState::Received
+        };
 
         let stored_message = NewStoredMessage::new(
-            message_obj.convo_id,
+            message_obj.convo_id.clone(),
             payload.sender_address.clone(),
-            message_obj.content_bytes,
-            MessageState::Received as i32,
+            message_obj.content_bytes.clone(),
+            state,
             payload.sent_at_ns,
         );
 
+        let message_id = stored_message.message_id.clone();
+        // Linked even for duplicates, so that every copy of the payload expires with the message
+        client
+            .store
+            .link_inbound_message(conn, &payload.inbound_message_id, &message_id)?;
+        if !client
+            .store
+            .insert_or_ignore_message(conn, stored_message)?
+        {
+            log::debug!("Ignoring duplicate of message {}", message_id);
+            return Ok(());
+        }
+        if blocked {
+            log::debug!("Ignoring message {} from blocked peer", message_id);
+            return Ok(());
+        }
+
+        if let Some(change) = membership_change {
+            Conversations::apply_membership_change(
+                client,
+                conn,
+                &message_obj.convo_id,
+                &payload.sender_address,
+                change,
+            )?;
+        }
+        Conversations::apply_control_message(client, conn, &message_obj, &message_id, payload)?;
+
+        Ok(())
+    }
+
+    // Receipts move the messages this installation sent to the receipt's sender forward, and
+    // retention policies change how long the conversation's messages are kept. Any other message
+    // from another user in an allowed conversation is acknowledged with a delivery receipt.
+    fn apply_control_message(
+        client: &Client<A>,
+        conn: &mut DbConnection,
+        message: &PadlockMessagePayload,
+        message_id: &str,
+        payload: &DecodedInboundMessage,
+    ) -> Result<(), ConversationError> {
+        let content = match EncodedContent::decode(message.content_bytes.as_slice()) {
+            Ok(content) => content,
+            Err(_) => return Ok(()),
+        };
+        match client.codecs.decode(content) {
+            Ok(MessageContent::DeliveryReceipt(receipt)) => {
+                client.store.advance_delivery_status(
+                    conn,
+                    &payload.sender_installation_id,
+                    receipt.message_ids,
+                    DeliveryStatus::Delivered,
+                )?;
+            }
+            Ok(MessageContent::ReadReceipt(_)) => {
+                client.store.mark_read_up_to(
+                    conn,
+                    &payload.sender_installation_id,
+                    &message.convo_id,
+                    payload.sent_at_ns,
+                )?;
+            }
+            Ok(MessageContent::RetentionPolicy(policy)) if policy.is_valid() => {
+                client.store.set_conversation_retention(
+                    conn,
+                    &message.convo_id,
+                    policy.retention_ns,
+                )?;
+            }
+            Ok(MessageContent::RetentionPolicy(policy)) => {
+                log::warn!(
+                    "Ignoring invalid retention policy in {}: {:?}",
+                    message.convo_id,
+                    policy
+                );
+            }
+            Ok(MessageContent::MembershipChange(_)) => {}
+            _ if payload.sender_address != client.wallet_address() => {
+                Conversations::queue_delivery_receipt(client, conn, &message.convo_id, message_id)?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    // Stored like any other message, and sent to the message's sender the next time outbound
+    // messages are processed. Message requests are not acknowledged, so that receipts don't tell
+    // unknown senders that the address is in use.
+    fn queue_delivery_receipt(
+        client: &Client<A>,
+        conn: &mut DbConnection,
+        convo_id: &str,
+        message_id: &str,
+    ) -> Result<(), ConversationError> {
+        let allowed = client
+            .store
+            .get_conversation_with_conn(conn, convo_id)?
+            .map_or(false, |convo| convo.consent_state == ConsentState::Allowed);
+        if !allowed {
+            return Ok(());
+        }
+
+        let receipt = client.codecs.encode(DeliveryReceipt {
+            message_ids: vec![message_id.to_string()],
+        })?;
+        NewStoredMessage::new(
+            convo_id.to_string(),
+            client.wallet_address(),
+            receipt.encode_to_vec(),
+            MessageState::Unprocessed,
+            now(),
+        )
+        .store(conn)?;
+
+        Ok(())
+    }
+
+    // Only group conversations carry membership changes. Content that can't be decoded is stored
+    // like any other message, as it is for direct conversations.
+    fn decode_membership_change(message: &PadlockMessagePayload) -> Option<GroupMembershipChange> {
+        if !is_group_convo_id(&message.convo_id) {
+            return None;
+        }
+        let content = EncodedContent::decode(message.content_bytes.as_slice()).ok()?;
+        if !GroupMembershipCodec::is_membership_change(&content) {
+            return None;
+        }
+
+        match GroupMembershipCodec::decode(content) {
+            Ok(change) => Some(change),
+            Err(err) => {
+                log::warn!(
+                    "Malformed membership change in {}: {:?}",
+                    message.convo_id,
+                    err
+                );
+                None
+            }
+        }
+    }
+
+    fn apply_membership_change(
+        client: &Client<A>,
+        conn: &mut DbConnection,
+        convo_id: &str,
+        sender_address: &str,
+        change: GroupMembershipChange,
+    ) -> Result<(), ConversationError> {
+        client.store.insert_or_ignore_user_with_conn(
+            conn,
+            StoredUser {
+                user_address: sender_address.to_string(),
+                created_at: now(),
+                last_refreshed: 0,
+            },
+        )?;
+        // A no-op unless this change is the invitation to a group we haven't seen before
+        client.store.insert_or_ignore_conversation_with_conn(
+            conn,
+            StoredConversation {
+                convo_id: convo_id.to_string(),
+                peer_address: sender_address.to_string(),
+                created_at: now(),
+                convo_state: ConversationState::InviteReceived,
+                convo_kind: ConversationKind::Group,
+                consent_state: ConsentState::Unknown,
+                retention_ns: None,
+            },
+        )?;
         client
             .store
-            .insert_or_ignore_message(conn, stored_message)?;
+            .set_conversation_members(conn, convo_id, &change.members)?;
+
+        Ok(())
+    }
+
+    /// Checks the decrypted payload against the unsealed metadata, as described on the
+    /// `PadlockMessagePayload` proto
+    fn validate_payload(
+        client: &Client<A>,
+        conn: &mut DbConnection,
+        message: &PadlockMessagePayload,
+        sender: &Contact,
+        payload: &DecodedInboundMessage,
+    ) -> Result<(), ConversationError> {
+        // The header is only bound to the encrypted payload by this signature
+        let signature_valid = match &message.header_signature {
+            Some(signature) => sender
+                .verify_signature(&payload.header_bytes, &signature.bytes)
+                .is_ok(),
+            None => false,
+        };
+        if !signature_valid {
+            return Err(ConversationError::InvalidMessage(
+                InvalidMessageReason::InvalidSignature,
+            ));
+        }
+
+        let is_member = if is_group_convo_id(&message.convo_id) {
+            Conversations::is_group_member(client, conn, message, payload)?
+        } else {
+            is_convo_member(&message.convo_id, &payload.sender_address)
+                && is_convo_member(&message.convo_id, &payload.recipient_address)
+        };
+        if !is_member {
+            return Err(ConversationError::InvalidMessage(
+                InvalidMessageReason::ConversationMismatch,
+            ));
+        }
+
+        if let Some(change) = Conversations::<A>::decode_membership_change(message) {
+            let members = client
+                .store
+                .get_conversation_members(conn, &message.convo_id)?;
+            if !is_authorized_membership_change(
+                &message.convo_id,
+                &members,
+                &payload.sender_address,
+                &payload.recipient_address,
+                &change,
+            ) {
+                return Err(ConversationError::InvalidMessage(
+                    InvalidMessageReason::UnauthorizedMembershipChange,
+                ));
+            }
+        }
 
         Ok(())
     }
 
+    /// Both parties must be members of the group. An unknown group can only be joined through a
+    /// membership change that lists them both.
+    fn is_group_member(
+        client: &Client<A>,
+        conn: &mut DbConnection,
+        message: &PadlockMessagePayload,
+        payload: &DecodedInboundMessage,
+    ) -> Result<bool, ConversationError> {
+        let mut members = client
+            .store
+            .get_conversation_members(conn, &message.convo_id)?;
+        if members.is_empty() {
+            members = match Conversations::<A>::decode_membership_change(message) {
+                Some(change) => change.members,
+                None => return Ok(false),
+            };
+        }
+
+        Ok(members.contains(&payload.sender_address)
+            && members.contains(&payload.recipient_address))
+    }
+
     fn process_prekey_message(
         client: &Client<A>,
         conn: &mut DbConnection,
         msg: olm::PreKeyMessage,
         payload: &DecodedInboundMessage,
     ) -> Result<(), ConversationError> {
+        // Only returns a contact whose installation is associated with the sender address
         let network_contact = block_on(client.download_contact_for_installation(
             &payload.sender_address,
             &payload.sender_installation_id,
@@ -220,14 +592,27 @@ impl<A: XmtpApiClient> Conversations<A> {
         let contact = match network_contact {
             Some(contact) => contact,
             None => {
-                return Err(ConversationError::Generic(
-                    "No contact for Prekey Messag".into(),
+                return Err(ConversationError::InvalidMessage(
+                    InvalidMessageReason::UnknownSender,
                 ))
             }
         };
 
         let (_, plaintext) = client.create_inbound_session(conn, &contact, msg)?;
-        Conversations::process_plaintext(client, conn, &plaintext, payload)?;
+        // Remember the installation so that later messages on this session can be verified
+        client.store.insert_or_ignore_user_with_conn(
+            conn,
+            StoredUser {
+                user_address: contact.wallet_address.clone(),
+                created_at: now(),
+                last_refreshed: 0,
+            },
+        )?;
+        client.store.insert_or_ignore_install(
+            StoredInstallation::new(&contact).map_err(ClientError::from)?,
+            conn,
+        )?;
+        Conversations::process_plaintext(client, conn, &plaintext, &contact, payload)?;
         Ok(())
     }
 
@@ -263,11 +648,7 @@ impl<A: XmtpApiClient> Conversations<A> {
                 let invite_id = invite.id.clone();
                 match Conversations::process_inbound_invite(client, transaction_manager, invite) {
                     Ok(status) => {
-                        log::debug!(
-                            "Invite processed: {:?}. Status: {:?}",
-                            invite_id,
-                            status.clone()
-                        );
+                        log::debug!("Invite processed: {:?}. Status: {:?}", invite_id, status);
                         client
                             .store
                             .set_invite_status(transaction_manager, invite_id, status)?;
@@ -355,6 +736,13 @@ impl<A: XmtpApiClient> Conversations<A> {
             },
         )?;
 
+        // Conversations started from our other devices are already allowed, any other peer has to
+        // be allowed by the user
+        let consent_state = if invitation.inviter.wallet_address == client.wallet_address() {
+            ConsentState::Allowed
+        } else {
+            ConsentState::Unknown
+        };
         // Create the conversation if doesn't exist
         client.store.insert_or_ignore_conversation_with_conn(
             conn,
@@ -365,7 +753,10 @@ impl<A: XmtpApiClient> Conversations<A> {
                 ),
                 peer_address,
                 created_at: now(),
-                convo_state: ConversationState::InviteReceived as i32,
+                convo_state: ConversationState::InviteReceived,
+                convo_kind: ConversationKind::Direct,
+                consent_state,
+                retention_ns: None,
             },
         )?;
 
@@ -425,6 +816,31 @@ impl<A: XmtpApiClient> Conversations<A> {
         }
     }
 
+    fn get_installation_contact(
+        conn: &mut DbConnection,
+        installation_id: &str,
+    ) -> Result<Option<Contact>, ConversationError> {
+        let installation: Option<StoredInstallation> = conn.fetch_one(installation_id)?;
+        match installation {
+            Some(installation) => Ok(Some(installation.get_contact().map_err(ClientError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn get_session_contact(
+        conn: &mut DbConnection,
+        session: &SessionManager,
+    ) -> Result<Contact, ConversationError> {
+        Conversations::<A>::get_installation_contact(conn, &session.installation_id())?.ok_or_else(
+            || {
+                ConversationError::Generic(format!(
+                    "no installation for session: {}",
+                    session.installation_id()
+                ))
+            },
+        )
+    }
+
     fn get_start_time(job: &RefreshJob) -> i64 {
         // Adjust for padding and ensure start_time > 0
         std::cmp::max(job.last_run - PADDING_TIME_NS, 0)
@@ -433,6 +849,7 @@ impl<A: XmtpApiClient> Conversations<A> {
     fn create_outbound_payload(
         client: &Client<A>,
         session: &mut SessionManager,
+        recipient: &Contact,
         message: &StoredMessage,
     ) -> Result<StoredOutboundPayload, ConversationError> {
         let is_prekey_message = !session.has_received_message();
@@ -444,16 +861,27 @@ impl<A: XmtpApiClient> Conversations<A> {
             recipient_installation_id: session.installation_id(),
             is_prekey_message,
         };
-        // TODO encrypted sealed metadata using sealed sender
-        let sealed_metadata = metadata.encode_to_vec();
+
+        Conversations::seal_outbound_payload(client, session, recipient, &metadata, message)
+    }
+
+    fn seal_outbound_payload(
+        client: &Client<A>,
+        session: &mut SessionManager,
+        recipient: &Contact,
+        metadata: &PadlockMessageSealedMetadata,
+        message: &StoredMessage,
+    ) -> Result<StoredOutboundPayload, ConversationError> {
+        let sealed_metadata = sealed_sender::seal(metadata, &recipient.vmac_sealed_sender_key());
         let message_header = PadlockMessageHeader {
-            sent_ns: message.created_at as u64,
+            sent_ns: message.sent_at_ns as u64,
             sealed_metadata,
         };
         let header_bytes = message_header.encode_to_vec();
-        // TODO expose a vmac method to sign bytes rather than string
-        // https://matrix-org.github.io/vodozemac/vodozemac/olm/struct.Account.html#method.sign
-        let header_signature = client.account.sign(&base64_encode(&header_bytes));
+        let header_signature = client
+            .account
+            .sign(&header_bytes)
+            .map_err(|e| ConversationError::Generic(e.to_string()))?;
         let header_signature = EdDsaSignature {
             bytes: header_signature.to_bytes().to_vec(),
         };
@@ -478,8 +906,11 @@ impl<A: XmtpApiClient> Conversations<A> {
             message.created_at,
             build_installation_message_topic(&session.installation_id()),
             envelope.encode_to_vec(),
-            OutboundPayloadState::Pending as i32,
+            OutboundPayloadState::Pending,
             0,
+            message.message_id.clone(),
+            metadata.recipient_user_address.clone(),
+            metadata.recipient_installation_id.clone(),
         ))
     }
 
@@ -487,24 +918,40 @@ impl<A: XmtpApiClient> Conversations<A> {
         client: &Client<A>,
         message: &StoredMessage,
     ) -> Result<(), ConversationError> {
-        let peer_address = peer_addr_from_convo_id(&message.convo_id, &client.wallet_address())?;
+        let recipient_addresses = Conversations::get_recipient_addresses(client, message)?;
 
         // Refresh remote installations
-        client
-            .refresh_user_installations_if_stale(&peer_address)
-            .await?;
+        for recipient_address in recipient_addresses.iter() {
+            client
+                .refresh_user_installations_if_stale(recipient_address)
+                .await?;
+        }
         client.store.conn().unwrap().transaction(
             |transaction| -> Result<(), ConversationError> {
-                let my_sessions = client
-                    .store
-                    .get_latest_sessions(&client.wallet_address(), transaction)?;
-                let their_user_addr =
-                    peer_addr_from_convo_id(&message.convo_id, &client.wallet_address())?;
-                let their_sessions = client
-                    .store
-                    .get_latest_sessions(&their_user_addr, transaction)?;
-                if their_sessions.is_empty() {
-                    return Err(ConversationError::NoSessions(their_user_addr));
+                // Receipts are only of use to their recipients, not this user's other installations
+                let my_sessions = if is_delivery_receipt(message) {
+                    vec![]
+                } else {
+                    client
+                        .store
+                        .get_latest_sessions(&client.wallet_address(), transaction)?
+                };
+                let mut their_sessions = Vec::new();
+                for recipient_address in recipient_addresses.iter() {
+                    let sessions = client
+                        .store
+                        .get_latest_sessions(recipient_address, transaction)?;
+                    if sessions.is_empty() {
+                        log::warn!(
+                            "No sessions for {} in {}",
+                            recipient_address,
+                            message.convo_id
+                        );
+                    }
+                    their_sessions.extend(sessions);
+                }
+                if their_sessions.is_empty() && !recipient_addresses.is_empty() {
+                    return Err(ConversationError::NoSessions(recipient_addresses.join(",")));
                 }
 
                 let mut outbound_payloads = Vec::new();
@@ -516,8 +963,13 @@ impl<A: XmtpApiClient> Conversations<A> {
                         continue;
                     }
                     let mut session = SessionManager::try_from(stored_session)?;
-                    let outbound_payload =
-                        Conversations::create_outbound_payload(client, &mut session, message)?;
+                    let recipient = Conversations::<A>::get_session_contact(transaction, &session)?;
+                    let outbound_payload = Conversations::create_outbound_payload(
+                        client,
+                        &mut session,
+                        &recipient,
+                        message,
+                    )?;
                     let updated_session = StoredSession::try_from(&session)?;
                     outbound_payloads.push(outbound_payload);
                     updated_sessions.push(updated_session);
@@ -537,84 +989,466 @@ impl<A: XmtpApiClient> Conversations<A> {
         Ok(())
     }
 
-    pub async fn process_outbound_messages(client: &Client<A>) -> Result<(), ConversationError> {
-        //Refresh self installations
+    /// Encrypt an invitation to the conversation with `peer_address` and publish it to the
+    /// installation's invite topic. The caller is responsible for saving the session.
+    pub(crate) async fn send_invitation(
+        client: &Client<A>,
+        session: &mut SessionManager,
+        contact: &Contact,
+        peer_address: &str,
+    ) -> Result<(), ConversationError> {
+        let envelope = build_envelope(
+            build_user_invite_topic(contact.installation_id()),
+            Conversations::build_invitation(client, session, peer_address)?,
+        );
+
         client
-            .refresh_user_installations_if_stale(&client.wallet_address())
+            .api_client
+            // TODO: API authentication
+            .publish(
+                "".to_string(),
+                PublishRequest {
+                    envelopes: vec![envelope],
+                },
+            )
             .await?;
-        let mut messages = client.store.get_unprocessed_messages()?;
-        log::debug!("Processing {} messages", messages.len());
-        messages.sort_by(|a, b| a.created_at.cmp(&b.created_at));
-        for message in messages {
-            if let Err(e) = Conversations::process_outbound_message(client, &message).await {
-                log::error!(
-                    "Couldn't process message with ID {} because of error: {:?}",
-                    message.id,
-                    e
-                );
-                // TODO update message status to failed on non-retryable errors so that we don't retry it next time
+
+        Ok(())
+    }
+
+    fn build_invitation(
+        client: &Client<A>,
+        session: &mut SessionManager,
+        peer_address: &str,
+    ) -> Result<Vec<u8>, ConversationError> {
+        let inner_invite_bytes = Invitation::build_inner_invite_bytes(peer_address.to_string())?;
+        let invitation = Invitation::build(client.account.contact(), session, &inner_invite_bytes)?;
+
+        Ok(invitation.try_into()?)
+    }
+
+    /// Bring newly discovered installations into the existing conversations with their user.
+    /// Each installation is invited and sent the messages this installation has sent within the
+    /// backfill period. Other participants backfill their own messages once they discover it.
+    /// Every invitation is attempted, and the first failure is returned once the queued payloads
+    /// have been published.
+    pub async fn invite_new_installations(
+        client: &Client<A>,
+        contacts: &[Contact],
+    ) -> Result<(), ConversationError> {
+        let mut first_error = None;
+        for contact in contacts {
+            if contact.installation_id() == client.installation_id() {
+                continue;
+            }
+            for convo in
+                Conversations::get_conversations_with_user(client, &contact.wallet_address)?
+            {
+                if let Err(err) = Conversations::invite_installation(client, &convo, contact).await
+                {
+                    first_error.get_or_insert(err);
+                }
             }
         }
 
         Conversations::publish_outbound_payloads(client).await?;
-        Ok(())
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
     }
 
-    pub async fn publish_outbound_payloads(client: &Client<A>) -> Result<(), ConversationError> {
-        let unsent_payloads = client.store.fetch_and_lock_outbound_payloads(
-            OutboundPayloadState::Pending,
-            Duration::from_secs(60).as_nanos() as i64,
+    fn get_conversations_with_user(
+        client: &Client<A>,
+        user_address: &str,
+    ) -> Result<Vec<StoredConversation>, ConversationError> {
+        let conn = &mut client.store.conn()?;
+        let convos = client.store.get_conversations(
+            conn,
+            vec![
+                ConversationState::InviteReceived,
+                ConversationState::Invited,
+            ],
         )?;
 
-        if unsent_payloads.is_empty() {
-            return Ok(());
+        let mut matching = vec![];
+        for convo in convos {
+            let is_participant = if convo.convo_kind == ConversationKind::Group {
+                client
+                    .store
+                    .get_conversation_members(conn, &convo.convo_id)?
+                    .iter()
+                    .any(|member| member == user_address)
+            } else {
+                is_convo_member(&convo.convo_id, user_address)
+            };
+            if is_participant {
+                matching.push(convo);
+            }
         }
 
-        let envelopes = unsent_payloads
-            .iter()
-            .map(|payload| Envelope {
-                content_topic: payload.content_topic.clone(),
-                timestamp_ns: payload.created_at_ns as u64,
-                message: payload.payload.clone(),
-            })
-            .collect();
-
-        // TODO: API tokens
-        client
-            .api_client
-            .publish("".to_string(), PublishRequest { envelopes })
-            .await?;
+        Ok(matching)
+    }
 
-        let payload_ids = unsent_payloads
-            .iter()
-            .map(|payload| payload.created_at_ns)
-            .collect();
-        client.store.update_and_unlock_outbound_payloads(
-            payload_ids,
-            OutboundPayloadState::ServerAcknowledged,
-        )?;
-        Ok(())
+    // The invitation, the backfilled messages and the updated session are committed together,
+    // and published along with every other pending payload. The session is set up first, as that
+    // may claim a one-time key from the network.
+    async fn invite_installation(
+        client: &Client<A>,
+        convo: &StoredConversation,
+        contact: &Contact,
+    ) -> Result<(), ConversationError> {
+        let session = client.get_session(contact).await?;
+        client.store.conn()?.transaction(|conn| {
+            Conversations::queue_invitation(client, conn, convo, contact, session)
+        })
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use prost::Message;
-    use xmtp_proto::xmtp::message_api::v1::QueryRequest;
+    fn queue_invitation(
+        client: &Client<A>,
+        conn: &mut DbConnection,
+        convo: &StoredConversation,
+        contact: &Contact,
+        mut session: SessionManager,
+    ) -> Result<(), ConversationError> {
+        let self_address = client.wallet_address();
+        let mut outbound_payloads = vec![];
+
+        let mut messages = vec![];
+        if convo.convo_kind == ConversationKind::Group {
+            // Groups are joined through a membership change, which is sent to the new
+            // installation only and not stored as a message of its own
+            let change = GroupMembershipChange {
+                members: client
+                    .store
+                    .get_conversation_members(conn, &convo.convo_id)?,
+                added: vec![],
+                removed: vec![],
+            };
+            let sent_at_ns = now();
+            let content = GroupMembershipCodec::encode(change)?.encode_to_vec();
+            messages.push(StoredMessage {
+                id: 0,
+                created_at: sent_at_ns,
+                sent_at_ns,
+                message_id: compute_message_id(
+                    &convo.convo_id,
+                    &self_address,
+                    sent_at_ns,
+                    &content,
+                ),
+                convo_id: convo.convo_id.clone(),
+                addr_from: self_address.clone(),
+                content,
+                state: MessageState::LocallyCommitted,
+                send_attempts: 0,
+                max_send_attempts: 0,
+                next_attempt_ns: 0,
+                error_reason: None,
+                content_type: content_type_name(&GroupMembershipCodec::content_type()),
+            });
+        } else {
+            let peer_address = peer_addr_from_convo_id(&convo.convo_id, &self_address)?;
+            outbound_payloads.push(StoredOutboundPayload::new(
+                now(),
+                build_user_invite_topic(contact.installation_id()),
+                Conversations::build_invitation(client, &mut session, &peer_address)?,
+                OutboundPayloadState::Pending,
+                0,
+                String::new(),
+                contact.wallet_address.clone(),
+                contact.installation_id(),
+            ));
+        }
+
+        messages.extend(
+            client
+                .store
+                .get_stored_messages(
+                    conn,
+                    Some(vec![MessageState::LocallyCommitted]),
+                    Some(&convo.convo_id),
+                    &ListMessagesOptions {
+                        start_time_ns: Some(now() - BACKFILL_PERIOD_NS),
+                        sender_addresses: Some(vec![self_address.clone()]),
+                        ..Default::default()
+                    },
+                )?
+                .into_iter()
+                // Earlier membership changes are superseded by the one sent above
+                .filter(|message| {
+                    EncodedContent::decode(message.content.as_slice()).map_or(true, |content| {
+                        !GroupMembershipCodec::is_membership_change(&content)
+                    })
+                }),
+        );
+
+        for message in messages.iter() {
+            outbound_payloads.push(Conversations::create_outbound_payload(
+                client,
+                &mut session,
+                contact,
+                message,
+            )?);
+        }
+        client.store.commit_outbound_payloads(
+            outbound_payloads,
+            vec![StoredSession::try_from(&session)?],
+            conn,
+        )?;
+
+        Ok(())
+    }
+
+    // Every other member of a group, or the peer of a direct conversation. Delivery receipts only
+    // go to the senders of the messages they acknowledge, rather than to the whole group.
+    fn get_recipient_addresses(
+        client: &Client<A>,
+        message: &StoredMessage,
+    ) -> Result<Vec<String>, ConversationError> {
+        let self_address = client.wallet_address();
+        if is_delivery_receipt(message) {
+            let receipt: DeliveryReceipt = message.decode(&client.codecs)?;
+            let conn = &mut client.store.conn()?;
+            let mut senders = vec![];
+            for message_id in receipt.message_ids.iter() {
+                if let Some(acknowledged) =
+                    client.store.get_message_by_message_id(conn, message_id)?
+                {
+                    if acknowledged.addr_from != self_address
+                        && !senders.contains(&acknowledged.addr_from)
+                    {
+                        senders.push(acknowledged.addr_from);
+                    }
+                }
+            }
+            return Ok(senders);
+        }
+        if !is_group_convo_id(&message.convo_id) {
+            return Ok(vec![peer_addr_from_convo_id(
+                &message.convo_id,
+                &self_address,
+            )?]);
+        }
+
+        Ok(client
+            .store
+            .get_conversation_members(&mut client.store.conn()?, &message.convo_id)?
+            .into_iter()
+            .filter(|member| *member != self_address)
+            .collect())
+    }
+
+    pub async fn process_outbound_messages(client: &Client<A>) -> Result<(), ConversationError> {
+        //Refresh self installations
+        client
+            .refresh_user_installations_if_stale(&client.wallet_address())
+            .await?;
+        let mut messages = client.store.get_unprocessed_messages()?;
+        // Messages which failed recently are backing off
+        let now_ns = now();
+        messages.retain(|message| message.next_attempt_ns <= now_ns);
+        log::debug!("Processing {} messages", messages.len());
+        messages.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        for message in messages {
+            if let Err(e) = Conversations::process_outbound_message(client, &message).await {
+                log::error!(
+                    "Couldn't process message with ID {} because of error: {:?}",
+                    message.id,
+                    e
+                );
+                if let Err(err) = Conversations::record_send_failure(client, &message, &e) {
+                    log::error!(
+                        "Couldn't record failure of message {}: {:?}",
+                        message.id,
+                        err
+                    );
+                }
+            }
+        }
+
+        Conversations::publish_outbound_payloads(client).await?;
+        Ok(())
+    }
+
+    fn record_send_failure(
+        client: &Client<A>,
+        message: &StoredMessage,
+        err: &ConversationError,
+    ) -> Result<(), ConversationError> {
+        let conn = &mut client.store.conn()?;
+        let send_attempts = message.send_attempts + 1;
+        if err.is_retryable() && send_attempts < message.max_send_attempts {
+            client.store.set_message_retry(
+                conn,
+                message.id,
+                send_attempts,
+                now() + Conversations::<A>::retry_delay_ns(send_attempts),
+                &err.to_string(),
+            )?;
+        } else {
+            client
+                .store
+                .set_message_failed(conn, message.id, send_attempts, &err.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    fn retry_delay_ns(send_attempts: i32) -> i64 {
+        let exponent = (send_attempts - 1).max(0) as u32;
+        RETRY_BASE_DELAY_NS
+            .saturating_mul(2i64.saturating_pow(exponent))
+            .min(RETRY_MAX_DELAY_NS)
+    }
+
+    /// Outbound messages which will not be attempted again unless retried
+    pub fn list_failed_messages(
+        client: &Client<A>,
+    ) -> Result<Vec<StoredMessage>, ConversationError> {
+        Ok(client
+            .store
+            .get_failed_messages(&mut client.store.conn()?)?)
+    }
+
+    /// Send a failed message again, with the same number of attempts as a new message
+    pub async fn retry_failed_message(
+        client: &Client<A>,
+        message_id: i32,
+    ) -> Result<(), ConversationError> {
+        let was_failed = client
+            .store
+            .reset_failed_message(&mut client.store.conn()?, message_id)?;
+        if !was_failed {
+            return Err(ConversationError::Generic(format!(
+                "no failed message with id {}",
+                message_id
+            )));
+        }
+
+        Conversations::process_outbound_messages(client).await
+    }
+
+    pub fn delete_failed_message(
+        client: &Client<A>,
+        message_id: i32,
+    ) -> Result<(), ConversationError> {
+        let was_failed = client
+            .store
+            .delete_failed_message(&mut client.store.conn()?, message_id)?;
+        if !was_failed {
+            return Err(ConversationError::Generic(format!(
+                "no failed message with id {}",
+                message_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Publish every pending payload in batches, until the queue is empty or a publish fails.
+    ///
+    /// Delivery is at least once: a payload is only acknowledged after the network accepts it,
+    /// and a payload whose lease expires before then is published again. Republishing sends an
+    /// identical envelope, which recipients store under the same id and so process only once.
+    pub async fn publish_outbound_payloads(client: &Client<A>) -> Result<(), ConversationError> {
+        loop {
+            let unsent_payloads = client.store.fetch_and_lock_outbound_payloads(
+                OutboundPayloadState::Pending,
+                OUTBOUND_PAYLOAD_LEASE_NS,
+                OUTBOUND_PAYLOAD_BATCH_SIZE,
+            )?;
+            if unsent_payloads.is_empty() {
+                return Ok(());
+            }
+
+            let envelopes = unsent_payloads
+                .iter()
+                .map(|payload| Envelope {
+                    content_topic: payload.content_topic.clone(),
+                    timestamp_ns: payload.created_at_ns as u64,
+                    message: payload.payload.clone(),
+                })
+                .collect();
+            let payload_ids: Vec<String> = unsent_payloads
+                .iter()
+                .map(|payload| payload.payload_id.clone())
+                .collect();
+
+            // TODO: API tokens
+            let published = client
+                .api_client
+                .publish("".to_string(), PublishRequest { envelopes })
+                .await;
+            if let Err(err) = published {
+                let attempts = unsent_payloads
+                    .iter()
+                    .map(|payload| payload.publish_attempts)
+                    .max()
+                    .unwrap_or(1);
+                client.store.release_outbound_payloads(
+                    payload_ids,
+                    now() + Conversations::<A>::retry_delay_ns(attempts),
+                )?;
+                return Err(err.into());
+            }
+
+            client.store.update_and_unlock_outbound_payloads(
+                payload_ids,
+                OutboundPayloadState::ServerAcknowledged,
+            )?;
+        }
+    }
+
+    /// Publish pending payloads every `interval`, yielding the result of each run. Payloads left
+    /// behind by a previous process are picked up once their lease expires. Publishing stops
+    /// when the stream is dropped.
+    pub fn stream_outbound_publishing(
+        client: &Client<A>,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<(), ConversationError>> + '_ {
+        stream::unfold(false, move |has_run| async move {
+            if has_run {
+                tokio::time::sleep(interval).await;
+            }
+            Some((Conversations::publish_outbound_payloads(client).await, true))
+        })
+    }
+}
+
+fn is_delivery_receipt(message: &StoredMessage) -> bool {
+    message.content_type == content_type_name(&DeliveryReceiptCodec::content_type())
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use xmtp_cryptography::utils::generate_local_wallet;
+    use xmtp_proto::xmtp::message_api::v1::QueryRequest;
+
+    use xmtp_proto::xmtp::v3::message_contents::{
+        PadlockMessageEnvelope, PadlockMessageHeader, PadlockMessageSealedMetadata,
+    };
 
     use crate::{
         codecs::{text::TextCodec, ContentCodec},
-        conversation::{convo_id, SecretConversation},
-        conversations::Conversations,
+        conversation::{convo_id, ListMessagesOptions, SecretConversation},
+        conversations::{Conversations, OUTBOUND_PAYLOAD_LEASE_NS},
         invitation::Invitation,
+        mock_xmtp_api_client::MockXmtpApiClient,
         storage::{
-            now, InboundInvite, InboundInviteStatus, MessageState, StoredConversation,
-            StoredMessage, StoredUser,
+            compute_message_id, content_type_name, now, ConsentState, InboundInvite,
+            InboundInviteStatus, InboundMessageStatus, InvalidMessageReason, MessageState,
+            NewStoredMessage, OutboundPayloadState, StoredConversation, StoredMessage,
+            StoredOutboundPayload, StoredUser,
+        },
+        test_utils::test_utils::{
+            gen_test_client, gen_test_client_on_network, gen_test_conversation,
+            gen_two_test_clients,
         },
-        test_utils::test_utils::{gen_test_client, gen_test_conversation, gen_two_test_clients},
         types::networking::XmtpApiClient,
         utils::{build_envelope, build_installation_message_topic, build_user_invite_topic},
-        ClientBuilder, Fetch,
+        Client, ClientBuilder, Fetch, InboxOwner, Store,
     };
 
     fn init() {
@@ -646,15 +1480,14 @@ mod tests {
         let bob_client = gen_test_client().await;
 
         let mut session = alice_client
-            .get_session(
-                &mut alice_client.store.conn().unwrap(),
-                &bob_client.account.contact(),
-            )
+            .get_session(&bob_client.account.contact())
+            .await
             .unwrap();
 
         let _payload = Conversations::create_outbound_payload(
             &alice_client,
             &mut session,
+            &bob_client.account.contact(),
             &StoredMessage {
                 id: 0,
                 created_at: 0,
@@ -664,7 +1497,13 @@ mod tests {
                 content: TextCodec::encode("Hello world".to_string())
                     .unwrap()
                     .encode_to_vec(),
-                state: MessageState::Unprocessed as i32,
+                state: MessageState::Unprocessed,
+                send_attempts: 0,
+                max_send_attempts: 0,
+                next_attempt_ns: 0,
+                error_reason: None,
+                message_id: "message_id".to_string(),
+                content_type: content_type_name(&TextCodec::content_type()),
             },
         )
         .unwrap();
@@ -697,6 +1536,103 @@ mod tests {
         // TODO verify using receive logic
     }
 
+    #[tokio::test]
+    async fn abandoned_payloads_are_republished() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let conversation = gen_test_conversation(&alice_client, &bob_client.wallet_address()).await;
+        let bob_messages = || async {
+            bob_client
+                .api_client
+                .query(QueryRequest {
+                    content_topics: vec![build_installation_message_topic(
+                        &bob_client.installation_id(),
+                    )],
+                    start_time_ns: 0,
+                    end_time_ns: now() as u64,
+                    paging_info: None,
+                })
+                .await
+                .unwrap()
+                .envelopes
+        };
+
+        // Commit the payloads for a message without publishing them
+        NewStoredMessage::new(
+            conversation.convo_id(),
+            alice_client.account.addr(),
+            TextCodec::encode("Hello".to_string())
+                .unwrap()
+                .encode_to_vec(),
+            MessageState::Unprocessed,
+            now(),
+        )
+        .store(&mut alice_client.store.conn().unwrap())
+        .unwrap();
+        let message = alice_client
+            .store
+            .get_unprocessed_messages()
+            .unwrap()
+            .remove(0);
+        Conversations::process_outbound_message(&alice_client, &message)
+            .await
+            .unwrap();
+
+        // Payloads leased by another worker are left alone
+        let leased = alice_client
+            .store
+            .fetch_and_lock_outbound_payloads(
+                OutboundPayloadState::Pending,
+                OUTBOUND_PAYLOAD_LEASE_NS,
+                10,
+            )
+            .unwrap();
+        assert_eq!(leased.len(), 1);
+        Conversations::publish_outbound_payloads(&alice_client)
+            .await
+            .unwrap();
+        assert!(bob_messages().await.is_empty());
+
+        // The worker dies and its lease expires
+        let payload_ids: Vec<String> = leased.into_iter().map(|p| p.payload_id).collect();
+        alice_client
+            .store
+            .release_outbound_payloads(payload_ids.clone(), 0)
+            .unwrap();
+        Conversations::publish_outbound_payloads(&alice_client)
+            .await
+            .unwrap();
+        assert_eq!(bob_messages().await.len(), 1);
+        let payloads: Vec<StoredOutboundPayload> =
+            alice_client.store.conn().unwrap().fetch_all().unwrap();
+        let payload = payloads
+            .iter()
+            .find(|p| p.payload_id == payload_ids[0])
+            .unwrap();
+        assert_eq!(payload.publish_attempts, 2);
+        assert_eq!(
+            payload.outbound_payload_state,
+            OutboundPayloadState::ServerAcknowledged
+        );
+
+        // Published again after a crash before the acknowledgement was recorded
+        alice_client
+            .store
+            .update_and_unlock_outbound_payloads(payload_ids, OutboundPayloadState::Pending)
+            .unwrap();
+        Conversations::publish_outbound_payloads(&alice_client)
+            .await
+            .unwrap();
+        assert_eq!(bob_messages().await.len(), 2);
+
+        // Bob only sees the message once
+        Conversations::receive(&bob_client).unwrap();
+        let stored: Vec<StoredMessage> = bob_client.store.conn().unwrap().fetch_all().unwrap();
+        let received = stored
+            .iter()
+            .filter(|message| message.state == MessageState::Received);
+        assert_eq!(received.count(), 1);
+    }
+
     #[tokio::test]
     async fn process_invites_happy_path() {
         let alice_client = gen_test_client().await;
@@ -705,10 +1641,8 @@ mod tests {
         let bob_address = bob_client.account.contact().wallet_address;
         let alice_to_bob_inner_invite = Invitation::build_inner_invite_bytes(bob_address).unwrap();
         let mut alice_to_bob_session = alice_client
-            .get_session(
-                &mut alice_client.store.conn().unwrap(),
-                &bob_client.account.contact(),
-            )
+            .get_session(&bob_client.account.contact())
+            .await
             .unwrap();
         let alice_to_bob_invite = Invitation::build(
             alice_client.account.contact(),
@@ -738,7 +1672,7 @@ mod tests {
 
         let inbound_invites: Vec<InboundInvite> = conn.fetch_all().unwrap();
         assert_eq!(inbound_invites.len(), 1);
-        assert!(inbound_invites[0].status == InboundInviteStatus::Processed as i16);
+        assert!(inbound_invites[0].status == InboundInviteStatus::Processed);
 
         let users: Vec<StoredUser> = conn.fetch_all().unwrap();
         // Expect 2 users because Bob is always in his own DB already
@@ -758,10 +1692,8 @@ mod tests {
         let bob_address = bob_client.account.contact().wallet_address;
         let alice_to_bob_inner_invite = Invitation::build_inner_invite_bytes(bob_address).unwrap();
         let mut bad_session = alice_client
-            .get_session(
-                &mut alice_client.store.conn().unwrap(),
-                &gen_test_client().await.account.contact(),
-            )
+            .get_session(&gen_test_client().await.account.contact())
+            .await
             .unwrap();
         let alice_to_bob_invite = Invitation::build(
             alice_client.account.contact(),
@@ -791,7 +1723,7 @@ mod tests {
 
         let inbound_invites: Vec<InboundInvite> = conn.fetch_all().unwrap();
         assert_eq!(inbound_invites.len(), 1);
-        assert!(inbound_invites[0].status == InboundInviteStatus::DecryptionFailure as i16);
+        assert!(inbound_invites[0].status == InboundInviteStatus::DecryptionFailure);
 
         let users: Vec<StoredUser> = conn.fetch_all().unwrap();
         // Expect 1 user because Bob is always in his own DB already
@@ -819,9 +1751,7 @@ mod tests {
                 &mut bob_client.store.conn().unwrap(),
                 None,
                 None,
-                None,
-                None,
-                None,
+                &ListMessagesOptions::default(),
             )
             .unwrap();
 
@@ -834,9 +1764,7 @@ mod tests {
                     &mut alice_client.store.conn().unwrap(),
                     None,
                     None,
-                    None,
-                    None,
-                    None,
+                    &ListMessagesOptions::default(),
                 )
                 .unwrap();
             assert_eq!(alice_messages.len(), 1);
@@ -853,9 +1781,7 @@ mod tests {
                 &mut alice_client.store.conn().unwrap(),
                 None,
                 None,
-                None,
-                None,
-                None,
+                &ListMessagesOptions::default(),
             )
             .unwrap();
 
@@ -863,6 +1789,219 @@ mod tests {
         // assert_eq!(alice_messages.len(), 2);
     }
 
+    // Seals a message from Alice to Bob using the given metadata rather than the session's
+    async fn seal_for_bob(
+        alice_client: &Client<MockXmtpApiClient>,
+        bob_client: &Client<MockXmtpApiClient>,
+        metadata: PadlockMessageSealedMetadata,
+        convo_id: String,
+    ) -> StoredOutboundPayload {
+        let mut session = alice_client
+            .get_session(&bob_client.account.contact())
+            .await
+            .unwrap();
+        Conversations::seal_outbound_payload(
+            alice_client,
+            &mut session,
+            &bob_client.account.contact(),
+            &metadata,
+            &test_message(alice_client, convo_id),
+        )
+        .unwrap()
+    }
+
+    fn test_message(alice_client: &Client<MockXmtpApiClient>, convo_id: String) -> StoredMessage {
+        StoredMessage {
+            id: 0,
+            created_at: now(),
+            convo_id,
+            addr_from: alice_client.wallet_address(),
+            sent_at_ns: 0,
+            content: TextCodec::encode("Hello world".to_string())
+                .unwrap()
+                .encode_to_vec(),
+            state: MessageState::Unprocessed,
+            send_attempts: 0,
+            max_send_attempts: 0,
+            next_attempt_ns: 0,
+            error_reason: None,
+            message_id: "message_id".to_string(),
+            content_type: content_type_name(&TextCodec::content_type()),
+        }
+    }
+
+    fn save_for_bob(bob_client: &Client<MockXmtpApiClient>, topic: String, payload: Vec<u8>) {
+        bob_client
+            .store
+            .save_inbound_message(
+                &mut bob_client.store.conn().unwrap(),
+                build_envelope(topic, payload).into(),
+            )
+            .unwrap();
+    }
+
+    fn valid_metadata(
+        alice_client: &Client<MockXmtpApiClient>,
+        bob_client: &Client<MockXmtpApiClient>,
+    ) -> PadlockMessageSealedMetadata {
+        PadlockMessageSealedMetadata {
+            sender_user_address: alice_client.wallet_address(),
+            sender_installation_id: alice_client.installation_id(),
+            recipient_user_address: bob_client.wallet_address(),
+            recipient_installation_id: bob_client.installation_id(),
+            is_prekey_message: true,
+        }
+    }
+
+    fn assert_rejected(bob_client: &Client<MockXmtpApiClient>, reason: InvalidMessageReason) {
+        Conversations::process_inbound_messages(bob_client).unwrap();
+        let invalid = bob_client
+            .store
+            .get_inbound_messages(
+                &mut bob_client.store.conn().unwrap(),
+                InboundMessageStatus::Invalid,
+            )
+            .unwrap();
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].status_reason, Some(reason));
+
+        let messages: Vec<StoredMessage> = bob_client.store.conn().unwrap().fetch_all().unwrap();
+        assert!(messages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn accept_valid_message() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let metadata = valid_metadata(&alice_client, &bob_client);
+        let convo_id = convo_id(alice_client.wallet_address(), bob_client.wallet_address());
+        let payload = seal_for_bob(&alice_client, &bob_client, metadata, convo_id).await;
+        save_for_bob(&bob_client, payload.content_topic, payload.payload);
+
+        Conversations::process_inbound_messages(&bob_client).unwrap();
+        let processed = bob_client
+            .store
+            .get_inbound_messages(
+                &mut bob_client.store.conn().unwrap(),
+                InboundMessageStatus::Processed,
+            )
+            .unwrap();
+        assert_eq!(processed.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn duplicate_messages_are_stored_once() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let metadata = valid_metadata(&alice_client, &bob_client);
+        let convo_id = convo_id(alice_client.wallet_address(), bob_client.wallet_address());
+        let message = test_message(&alice_client, convo_id);
+
+        // The same message encrypted twice, as if it were resent after a crash
+        let mut session = alice_client
+            .get_session(&bob_client.account.contact())
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            let payload = Conversations::seal_outbound_payload(
+                &alice_client,
+                &mut session,
+                &bob_client.account.contact(),
+                &metadata,
+                &message,
+            )
+            .unwrap();
+            save_for_bob(&bob_client, payload.content_topic, payload.payload);
+        }
+
+        Conversations::allow(&bob_client, &alice_client.wallet_address()).unwrap();
+        Conversations::process_inbound_messages(&bob_client).unwrap();
+        let processed = bob_client
+            .store
+            .get_inbound_messages(
+                &mut bob_client.store.conn().unwrap(),
+                InboundMessageStatus::Processed,
+            )
+            .unwrap();
+        assert_eq!(processed.len(), 2);
+        // Only acknowledged once
+        assert_eq!(
+            bob_client.store.get_unprocessed_messages().unwrap().len(),
+            1
+        );
+
+        let stored: Vec<StoredMessage> = bob_client.store.conn().unwrap().fetch_all().unwrap();
+        let messages: Vec<StoredMessage> = stored
+            .into_iter()
+            .filter(|message| message.state == MessageState::Received)
+            .collect();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            messages[0].message_id,
+            compute_message_id(
+                &message.convo_id,
+                &message.addr_from,
+                message.sent_at_ns,
+                &message.content
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn reject_wrong_recipient() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let mut metadata = valid_metadata(&alice_client, &bob_client);
+        metadata.recipient_installation_id = "another_installation".to_string();
+        let convo_id = convo_id(alice_client.wallet_address(), bob_client.wallet_address());
+        let payload = seal_for_bob(&alice_client, &bob_client, metadata, convo_id).await;
+        save_for_bob(&bob_client, payload.content_topic, payload.payload);
+
+        assert_rejected(&bob_client, InvalidMessageReason::RecipientMismatch);
+    }
+
+    #[tokio::test]
+    async fn reject_spoofed_sender() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let mut metadata = valid_metadata(&alice_client, &bob_client);
+        // Alice's installation is not associated with this address
+        metadata.sender_user_address = gen_test_client().await.wallet_address();
+        let convo_id = convo_id(
+            metadata.sender_user_address.clone(),
+            bob_client.wallet_address(),
+        );
+        let payload = seal_for_bob(&alice_client, &bob_client, metadata, convo_id).await;
+        save_for_bob(&bob_client, payload.content_topic, payload.payload);
+
+        assert_rejected(&bob_client, InvalidMessageReason::UnknownSender);
+    }
+
+    #[tokio::test]
+    async fn reject_spoofed_convo_id() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let metadata = valid_metadata(&alice_client, &bob_client);
+        let carol_address = gen_test_client().await.wallet_address();
+        let spoofed_convo_id = convo_id(carol_address, bob_client.wallet_address());
+        let payload = seal_for_bob(&alice_client, &bob_client, metadata, spoofed_convo_id).await;
+        save_for_bob(&bob_client, payload.content_topic, payload.payload);
+
+        assert_rejected(&bob_client, InvalidMessageReason::ConversationMismatch);
+    }
+
+    #[tokio::test]
+    async fn reject_tampered_header() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let metadata = valid_metadata(&alice_client, &bob_client);
+        let convo_id = convo_id(alice_client.wallet_address(), bob_client.wallet_address());
+        let payload = seal_for_bob(&alice_client, &bob_client, metadata, convo_id).await;
+
+        // Alter the header after it has been signed
+        let mut envelope = PadlockMessageEnvelope::decode(payload.payload.as_slice()).unwrap();
+        let mut header = PadlockMessageHeader::decode(envelope.header_bytes.as_slice()).unwrap();
+        header.sent_ns += 1;
+        envelope.header_bytes = header.encode_to_vec();
+        save_for_bob(&bob_client, payload.content_topic, envelope.encode_to_vec());
+
+        assert_rejected(&bob_client, InvalidMessageReason::InvalidSignature);
+    }
+
     #[tokio::test]
     async fn list() {
         let (alice_client, bob_client) = gen_two_test_clients().await;
@@ -873,4 +2012,206 @@ mod tests {
         assert_eq!(list.len(), 1);
         assert_eq!(list[0].peer_address(), conversation.peer_address());
     }
+
+    #[tokio::test]
+    async fn blocked_peers_are_hidden() {
+        let (alice_client, bob_client) = gen_two_test_clients().await;
+        let conversation = gen_test_conversation(&alice_client, &bob_client.wallet_address()).await;
+        assert_eq!(conversation.consent_state().unwrap(), ConsentState::Allowed);
+        conversation.send_text("Hello").await.unwrap();
+
+        // Bob hasn't decided about Alice yet
+        let list = Conversations::list(&bob_client, true).await.unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].consent_state().unwrap(), ConsentState::Unknown);
+        let allowed =
+            Conversations::list_with_consent(&bob_client, false, &[ConsentState::Allowed])
+                .await
+                .unwrap();
+        assert!(allowed.is_empty());
+        Conversations::receive(&bob_client).unwrap();
+
+        Conversations::block(&bob_client, &alice_client.wallet_address()).unwrap();
+        conversation.send_text("Are you there?").await.unwrap();
+        Conversations::receive(&bob_client).unwrap();
+
+        assert!(Conversations::list(&bob_client, true)
+            .await
+            .unwrap()
+            .is_empty());
+        let bob_conversation =
+            SecretConversation::new(&bob_client, alice_client.wallet_address()).unwrap();
+        let texts: Vec<String> = bob_conversation
+            .list_messages(&ListMessagesOptions::default())
+            .await
+            .unwrap()
+            .iter()
+            .map(|message| message.get_text().unwrap())
+            .collect();
+        assert_eq!(texts, vec!["Hello"]);
+        let ignored = bob_client
+            .store
+            .get_stored_messages(
+                &mut bob_client.store.conn().unwrap(),
+                Some(vec![MessageState::Ignored]),
+                None,
+                &ListMessagesOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(ignored.len(), 1);
+        assert_eq!(ignored[0].get_text().unwrap(), "Are you there?");
+
+        Conversations::allow(&bob_client, &alice_client.wallet_address()).unwrap();
+        let list = Conversations::list(&bob_client, false).await.unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].consent_state().unwrap(), ConsentState::Allowed);
+    }
+
+    #[tokio::test]
+    async fn new_installation_is_invited_and_backfilled() {
+        let wallet = generate_local_wallet();
+        let api_client = MockXmtpApiClient::new();
+        let mut bob_phone: Client<MockXmtpApiClient> = ClientBuilder::new(wallet.clone().into())
+            .api_client(api_client.clone())
+            .build()
+            .unwrap();
+        bob_phone.init().await.unwrap();
+        let alice_client = gen_test_client_on_network(api_client.clone()).await;
+        let conversation = gen_test_conversation(&alice_client, &bob_phone.wallet_address()).await;
+        conversation.send_text("Before the laptop").await.unwrap();
+
+        let mut bob_laptop: Client<MockXmtpApiClient> = ClientBuilder::new(wallet.into())
+            .api_client(api_client)
+            .build()
+            .unwrap();
+        bob_laptop.init().await.unwrap();
+        // Alice discovers the laptop the next time she sends
+        conversation.send_text("After the laptop").await.unwrap();
+
+        // The invitation went out through the outbound queue
+        let payloads: Vec<StoredOutboundPayload> =
+            alice_client.store.conn().unwrap().fetch_all().unwrap();
+        let invitation = payloads
+            .iter()
+            .find(|payload| {
+                payload.content_topic == build_user_invite_topic(bob_laptop.installation_id())
+            })
+            .unwrap();
+        assert_eq!(
+            invitation.outbound_payload_state,
+            OutboundPayloadState::ServerAcknowledged
+        );
+
+        let list = Conversations::list(&bob_laptop, true).await.unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].peer_address(), alice_client.wallet_address());
+
+        Conversations::receive(&bob_laptop).unwrap();
+        let texts: Vec<String> = list[0]
+            .list_messages(&ListMessagesOptions::default())
+            .await
+            .unwrap()
+            .iter()
+            .map(|message| message.get_text().unwrap())
+            .collect();
+        assert_eq!(texts, vec!["Before the laptop", "After the laptop"]);
+    }
+
+    fn get_message(client: &Client<MockXmtpApiClient>, message_id: i32) -> StoredMessage {
+        client
+            .store
+            .get_stored_messages(
+                &mut client.store.conn().unwrap(),
+                None,
+                None,
+                &ListMessagesOptions::default(),
+            )
+            .unwrap()
+            .into_iter()
+            .find(|message| message.id == message_id)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn retryable_send_errors_back_off() {
+        let alice_client = gen_test_client().await;
+        // Nobody has registered this address, so there are no sessions to send to yet
+        let conversation =
+            SecretConversation::new(&alice_client, generate_local_wallet().get_address()).unwrap();
+        conversation.send_text("Hello").await.unwrap();
+
+        let message_id = alice_client.store.get_unprocessed_messages().unwrap()[0].id;
+        let message = get_message(&alice_client, message_id);
+        assert_eq!(message.state, MessageState::Unprocessed);
+        assert_eq!(message.send_attempts, 1);
+        assert!(message.next_attempt_ns > now());
+        assert!(message.error_reason.unwrap().contains("No sessions"));
+
+        // Not attempted again until the backoff has passed
+        Conversations::process_outbound_messages(&alice_client)
+            .await
+            .unwrap();
+        assert_eq!(get_message(&alice_client, message_id).send_attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn failed_messages_can_be_retried_or_deleted() {
+        let alice_client = gen_test_client().await;
+        let peer_address = generate_local_wallet().get_address();
+        NewStoredMessage::new(
+            convo_id(alice_client.wallet_address(), peer_address),
+            alice_client.wallet_address(),
+            TextCodec::encode("Hello".to_string())
+                .unwrap()
+                .encode_to_vec(),
+            MessageState::Unprocessed,
+            now(),
+        )
+        .with_max_send_attempts(1)
+        .store(&mut alice_client.store.conn().unwrap())
+        .unwrap();
+        // Permanent errors fail the message on the first attempt
+        NewStoredMessage::new(
+            "not a convo id".to_string(),
+            alice_client.wallet_address(),
+            vec![],
+            MessageState::Unprocessed,
+            now(),
+        )
+        .store(&mut alice_client.store.conn().unwrap())
+        .unwrap();
+
+        Conversations::process_outbound_messages(&alice_client)
+            .await
+            .unwrap();
+        let failed = Conversations::list_failed_messages(&alice_client).unwrap();
+        assert_eq!(failed.len(), 2);
+        assert_eq!(failed[0].send_attempts, 1);
+        assert_eq!(failed[1].send_attempts, 1);
+        assert!(failed.iter().all(|message| message.error_reason.is_some()));
+
+        // Retrying starts over with a fresh set of attempts, which fail again here
+        Conversations::retry_failed_message(&alice_client, failed[0].id)
+            .await
+            .unwrap();
+        assert_eq!(
+            get_message(&alice_client, failed[0].id).state,
+            MessageState::Failed
+        );
+        assert_eq!(get_message(&alice_client, failed[0].id).send_attempts, 1);
+
+        Conversations::delete_failed_message(&alice_client, failed[1].id).unwrap();
+        assert!(Conversations::delete_failed_message(&alice_client, failed[1].id).is_err());
+        assert!(
+            Conversations::retry_failed_message(&alice_client, failed[1].id)
+                .await
+                .is_err()
+        );
+        assert_eq!(
+            Conversations::list_failed_messages(&alice_client)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
 }
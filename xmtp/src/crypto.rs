@@ -0,0 +1,115 @@
+//! Symmetric encryption shared by sealed sender and backups: AES-256-CBC with PKCS#7 padding,
+//! authenticated by an HMAC-SHA256 over the ciphertext, under keys derived by HKDF-SHA256. This
+//! mirrors the cipher Olm uses for message bodies.
+
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use thiserror::Error;
+
+type Aes256CbcEnc = cbc::Encryptor<aes::Aes256>;
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+type HmacSha256 = Hmac<Sha256>;
+
+pub(crate) const IV_LENGTH: usize = 16;
+pub(crate) const MAC_LENGTH: usize = 32;
+
+#[derive(Debug, Error)]
+pub(crate) enum CryptoError {
+    #[error("mac verification failed")]
+    InvalidMac,
+    #[error("decryption failed")]
+    Decryption,
+}
+
+/// Fill `output` with key material expanded from `ikm` by HKDF-SHA256
+pub(crate) fn hkdf_sha256(ikm: &[u8], salt: &[u8], info: &[u8], output: &mut [u8]) {
+    Hkdf::<Sha256>::new(Some(salt), ikm)
+        .expand(info, output)
+        .expect("HKDF-SHA256 output is at most 8160 bytes");
+}
+
+pub(crate) struct CbcHmacKeys {
+    aes_key: [u8; 32],
+    mac_key: [u8; 32],
+    /// Only for keys used once, as the same IV is derived every time
+    pub(crate) iv: [u8; IV_LENGTH],
+}
+
+impl CbcHmacKeys {
+    pub(crate) fn derive(ikm: &[u8], salt: &[u8], info: &[u8]) -> Self {
+        let mut expanded = [0u8; 80];
+        hkdf_sha256(ikm, salt, info, &mut expanded);
+
+        let mut keys = Self {
+            aes_key: [0u8; 32],
+            mac_key: [0u8; 32],
+            iv: [0u8; IV_LENGTH],
+        };
+        keys.aes_key.copy_from_slice(&expanded[..32]);
+        keys.mac_key.copy_from_slice(&expanded[32..64]);
+        keys.iv.copy_from_slice(&expanded[64..]);
+
+        keys
+    }
+
+    pub(crate) fn encrypt(&self, iv: &[u8; IV_LENGTH], plaintext: &[u8]) -> Vec<u8> {
+        Aes256CbcEnc::new(&self.aes_key.into(), iv.into())
+            .encrypt_padded_vec_mut::<Pkcs7>(plaintext)
+    }
+
+    /// Only call once the MAC has been verified
+    pub(crate) fn decrypt(
+        &self,
+        iv: &[u8; IV_LENGTH],
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, CryptoError> {
+        Aes256CbcDec::new(&self.aes_key.into(), iv.into())
+            .decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+            .map_err(|_| CryptoError::Decryption)
+    }
+
+    /// The MAC of `authenticated`, which should include the ciphertext and anything else the
+    /// recipient relies on, such as headers
+    pub(crate) fn mac(&self, authenticated: &[u8]) -> [u8; MAC_LENGTH] {
+        self.hmac(authenticated).finalize().into_bytes().into()
+    }
+
+    pub(crate) fn verify_mac(&self, authenticated: &[u8], mac: &[u8]) -> Result<(), CryptoError> {
+        self.hmac(authenticated)
+            .verify_slice(mac)
+            .map_err(|_| CryptoError::InvalidMac)
+    }
+
+    fn hmac(&self, authenticated: &[u8]) -> HmacSha256 {
+        let mut mac = <HmacSha256 as Mac>::new_from_slice(&self.mac_key)
+            .expect("HMAC accepts keys of any length");
+        mac.update(authenticated);
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CbcHmacKeys, CryptoError};
+
+    #[test]
+    fn encrypt_then_mac_round_trip() {
+        let keys = CbcHmacKeys::derive(b"secret", b"salt", b"info");
+        let ciphertext = keys.encrypt(&keys.iv, b"Hello, world!");
+        let mac = keys.mac(&ciphertext);
+
+        keys.verify_mac(&ciphertext, &mac).unwrap();
+        assert_eq!(
+            keys.decrypt(&keys.iv, &ciphertext).unwrap(),
+            b"Hello, world!"
+        );
+
+        let other = CbcHmacKeys::derive(b"secret", b"salt", b"other info");
+        assert!(matches!(
+            other.verify_mac(&ciphertext, &mac),
+            Err(CryptoError::InvalidMac)
+        ));
+    }
+}
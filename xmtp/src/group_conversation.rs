@@ -0,0 +1,562 @@
+use prost::Message;
+
+use crate::{
+    codecs::{
+        membership::{GroupMembershipChange, GroupMembershipCodec},
+        text::TextCodec,
+        ContentCodec,
+    },
+    conversation::{
+        group_creator_from_convo_id, is_group_convo_id, new_group_convo_id, ConversationError,
+        ListMessagesOptions,
+    },
+    conversations::Conversations,
+    storage::{
+        now, ConsentState, ConversationKind, ConversationState, MessageState, NewStoredMessage,
+        StoredConversation, StoredMessage, StoredUser,
+    },
+    types::networking::XmtpApiClient,
+    types::Address,
+    Client, Store,
+};
+
+/// A conversation with any number of members. Every message is encrypted separately to each
+/// installation of each member, using the same per-installation sessions as `SecretConversation`.
+pub struct GroupConversation<'c, A>
+where
+    A: XmtpApiClient,
+{
+    convo_id: String,
+    client: &'c Client<A>,
+}
+
+impl<'c, A> GroupConversation<'c, A>
+where
+    A: XmtpApiClient,
+{
+    /// Create a new group with the given members and invite every one of their installations
+    pub async fn create(
+        client: &'c Client<A>,
+        members: Vec<Address>,
+    ) -> Result<GroupConversation<'c, A>, ConversationError> {
+        let self_address = client.wallet_address();
+        let group = Self {
+            convo_id: new_group_convo_id(&self_address),
+            client,
+        };
+        let mut all_members = vec![self_address.clone()];
+        for member in members.iter() {
+            if !all_members.contains(member) {
+                all_members.push(member.clone());
+            }
+        }
+
+        {
+            let conn = &mut client.store.conn()?;
+            client.store.insert_or_ignore_user_with_conn(
+                conn,
+                StoredUser {
+                    user_address: self_address.clone(),
+                    created_at: now(),
+                    last_refreshed: 0,
+                },
+            )?;
+            client.store.insert_or_ignore_conversation_with_conn(
+                conn,
+                StoredConversation {
+                    convo_id: group.convo_id(),
+                    peer_address: self_address.clone(),
+                    created_at: now(),
+                    convo_state: ConversationState::Uninitialized,
+                    convo_kind: ConversationKind::Group,
+                    consent_state: ConsentState::Allowed,
+                    retention_ns: None,
+                },
+            )?;
+            client
+                .store
+                .set_conversation_members(conn, &group.convo_id, &all_members)?;
+        }
+
+        // The first membership change doubles as the invitation to the group
+        let added = all_members
+            .iter()
+            .filter(|member| **member != self_address)
+            .cloned()
+            .collect();
+        group
+            .send_membership_change(GroupMembershipChange {
+                members: all_members,
+                added,
+                removed: vec![],
+            })
+            .await?;
+        client.store.set_conversation_state(
+            &mut client.store.conn()?,
+            &group.convo_id,
+            ConversationState::Invited,
+        )?;
+
+        Ok(group)
+    }
+
+    /// Load a group which has already been created or joined
+    pub fn from_convo_id(
+        client: &'c Client<A>,
+        convo_id: String,
+    ) -> Result<GroupConversation<'c, A>, ConversationError> {
+        let is_known_group = is_group_convo_id(&convo_id)
+            && client
+                .store
+                .get_conversation(&convo_id)?
+                .map_or(false, |c| c.convo_kind == ConversationKind::Group);
+        if !is_known_group {
+            return Err(ConversationError::Generic(format!(
+                "no group conversation with id {}",
+                convo_id
+            )));
+        }
+
+        Ok(Self { convo_id, client })
+    }
+
+    pub fn convo_id(&self) -> String {
+        self.convo_id.clone()
+    }
+
+    pub fn members(&self) -> Result<Vec<Address>, ConversationError> {
+        let conn = &mut self.client.store.conn()?;
+        Ok(self
+            .client
+            .store
+            .get_conversation_members(conn, &self.convo_id)?)
+    }
+
+    pub async fn add_members(&self, new_members: Vec<Address>) -> Result<(), ConversationError> {
+        let mut members = self.members()?;
+        let mut added = vec![];
+        for member in new_members {
+            if !members.contains(&member) {
+                members.push(member.clone());
+                added.push(member);
+            }
+        }
+        if added.is_empty() {
+            return Ok(());
+        }
+
+        self.update_members(GroupMembershipChange {
+            members,
+            added,
+            removed: vec![],
+        })
+        .await
+    }
+
+    /// Remove members from the group. Removed members are not sent the change, and stop receiving
+    /// messages from the remaining members. Only the creator of the group can remove members.
+    pub async fn remove_members(
+        &self,
+        removed_members: Vec<Address>,
+    ) -> Result<(), ConversationError> {
+        let self_address = self.client.wallet_address();
+        if group_creator_from_convo_id(&self.convo_id) != Some(self_address.as_str()) {
+            return Err(ConversationError::Generic(
+                "only the group creator can remove members".to_string(),
+            ));
+        }
+        let (removed, members): (Vec<Address>, Vec<Address>) = self
+            .members()?
+            .into_iter()
+            .partition(|member| removed_members.contains(member));
+        if removed.is_empty() {
+            return Ok(());
+        }
+
+        self.update_members(GroupMembershipChange {
+            members,
+            added: vec![],
+            removed,
+        })
+        .await
+    }
+
+    pub async fn send(&self, content_bytes: Vec<u8>) -> Result<(), ConversationError> {
+        NewStoredMessage::new(
+            self.convo_id(),
+            self.client.account.addr(),
+            content_bytes,
+            MessageState::Unprocessed,
+            now(),
+        )
+        .store(&mut self.client.store.conn()?)?;
+
+        if let Err(err) = Conversations::process_outbound_messages(self.client).await {
+            log::error!("Could not process outbound messages: {:?}", err)
+        }
+
+        Ok(())
+    }
+
+    pub async fn send_text(&self, text: &str) -> Result<(), ConversationError> {
+        let encoded_content = TextCodec::encode(text.to_string())?;
+
+        self.send(encoded_content.encode_to_vec()).await
+    }
+
+    pub async fn list_messages(
+        &self,
+        opts: &ListMessagesOptions,
+    ) -> Result<Vec<StoredMessage>, ConversationError> {
+        let conn = &mut self.client.store.conn()?;
+        let messages = self.client.store.get_stored_messages(
+            conn,
+            Some(vec![MessageState::Received, MessageState::LocallyCommitted]),
+            Some(self.convo_id.as_str()),
+            opts,
+        )?;
+
+        Ok(messages)
+    }
+
+    async fn update_members(&self, change: GroupMembershipChange) -> Result<(), ConversationError> {
+        self.client.store.set_conversation_members(
+            &mut self.client.store.conn()?,
+            &self.convo_id,
+            &change.members,
+        )?;
+
+        self.send_membership_change(change).await
+    }
+
+    // Sent to the members in the store, so the member list must be updated beforehand
+    async fn send_membership_change(
+        &self,
+        change: GroupMembershipChange,
+    ) -> Result<(), ConversationError> {
+        let encoded_content = GroupMembershipCodec::encode(change)?;
+
+        self.send(encoded_content.encode_to_vec()).await
+    }
+}
+
+/// Whether `sender` may make `change` to a group whose current members are `members`, which is
+/// empty if the group is unknown. Any member may add members, but only the creator may remove them
+/// and the creator itself is never removed. `added` and `removed` must match the difference between
+/// the member lists, so the change that is shown is the change that is applied.
+pub(crate) fn is_authorized_membership_change(
+    convo_id: &str,
+    members: &[Address],
+    sender: &str,
+    recipient: &str,
+    change: &GroupMembershipChange,
+) -> bool {
+    let creator = group_creator_from_convo_id(convo_id);
+    let is_member = |address: &str| change.members.iter().any(|member| member == address);
+    if !is_member(sender) || !creator.map_or(true, is_member) {
+        return false;
+    }
+
+    if members.is_empty() {
+        // An unknown group can only be joined by being added to it, or created by this wallet on
+        // another installation
+        return change.removed.is_empty()
+            && change.added.iter().all(|added| is_member(added))
+            && (sender == recipient || change.added.iter().any(|added| added == recipient));
+    }
+
+    let added: Vec<Address> = change
+        .members
+        .iter()
+        .filter(|member| !members.contains(member))
+        .cloned()
+        .collect();
+    let removed: Vec<Address> = members
+        .iter()
+        .filter(|member| !change.members.contains(member))
+        .cloned()
+        .collect();
+
+    same_addresses(&added, &change.added)
+        && same_addresses(&removed, &change.removed)
+        && (removed.is_empty() || creator == Some(sender))
+}
+
+fn same_addresses(a: &[Address], b: &[Address]) -> bool {
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    a.sort();
+    a.dedup();
+    b.sort();
+    b.dedup();
+
+    a == b
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use xmtp_proto::xmtp::message_contents::EncodedContent;
+
+    use super::{is_authorized_membership_change, GroupConversation};
+    use crate::{
+        codecs::{
+            membership::{GroupMembershipChange, GroupMembershipCodec},
+            ContentCodec,
+        },
+        conversation::{new_group_convo_id, ListMessagesOptions},
+        conversations::Conversations,
+        mock_xmtp_api_client::MockXmtpApiClient,
+        storage::{InboundMessageStatus, InvalidMessageReason},
+        test_utils::test_utils::gen_test_client_on_network,
+        Client,
+    };
+
+    async fn gen_three_test_clients() -> (
+        Client<MockXmtpApiClient>,
+        Client<MockXmtpApiClient>,
+        Client<MockXmtpApiClient>,
+    ) {
+        let api_client = MockXmtpApiClient::new();
+        (
+            gen_test_client_on_network(api_client.clone()).await,
+            gen_test_client_on_network(api_client.clone()).await,
+            gen_test_client_on_network(api_client).await,
+        )
+    }
+
+    async fn received_texts(client: &Client<MockXmtpApiClient>, convo_id: &str) -> Vec<String> {
+        Conversations::receive(client).unwrap();
+        GroupConversation::from_convo_id(client, convo_id.to_string())
+            .unwrap()
+            .list_messages(&ListMessagesOptions::default())
+            .await
+            .unwrap()
+            .iter()
+            .filter(|m| {
+                let content = EncodedContent::decode(m.content.as_slice()).unwrap();
+                !GroupMembershipCodec::is_membership_change(&content)
+            })
+            .map(|m| m.get_text().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn create_group_and_send() {
+        let (alice, bob, carol) = gen_three_test_clients().await;
+        let group =
+            GroupConversation::create(&alice, vec![bob.wallet_address(), carol.wallet_address()])
+                .await
+                .unwrap();
+        let convo_id = group.convo_id();
+        group.send_text("Hello group").await.unwrap();
+
+        for member in [&bob, &carol] {
+            assert_eq!(received_texts(member, &convo_id).await, vec!["Hello group"]);
+            let mut members = GroupConversation::from_convo_id(member, convo_id.clone())
+                .unwrap()
+                .members()
+                .unwrap();
+            members.sort();
+            let mut expected = vec![
+                alice.wallet_address(),
+                bob.wallet_address(),
+                carol.wallet_address(),
+            ];
+            expected.sort();
+            assert_eq!(members, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn added_and_removed_members() {
+        let (alice, bob, carol) = gen_three_test_clients().await;
+        let group = GroupConversation::create(&alice, vec![bob.wallet_address()])
+            .await
+            .unwrap();
+        let convo_id = group.convo_id();
+        Conversations::receive(&bob).unwrap();
+
+        group
+            .add_members(vec![carol.wallet_address()])
+            .await
+            .unwrap();
+        // Carol joins through the membership change, and Bob learns about her
+        Conversations::receive(&carol).unwrap();
+        Conversations::receive(&bob).unwrap();
+        assert!(GroupConversation::from_convo_id(&bob, convo_id.clone())
+            .unwrap()
+            .members()
+            .unwrap()
+            .contains(&carol.wallet_address()));
+
+        // Bob can message the group, including Carol
+        GroupConversation::from_convo_id(&bob, convo_id.clone())
+            .unwrap()
+            .send_text("Hi Carol")
+            .await
+            .unwrap();
+        assert_eq!(received_texts(&carol, &convo_id).await, vec!["Hi Carol"]);
+
+        group
+            .remove_members(vec![carol.wallet_address()])
+            .await
+            .unwrap();
+        assert!(!group.members().unwrap().contains(&carol.wallet_address()));
+        group.send_text("Carol is gone").await.unwrap();
+        assert_eq!(
+            received_texts(&bob, &convo_id).await,
+            vec!["Hi Carol", "Carol is gone"]
+        );
+        assert_eq!(received_texts(&carol, &convo_id).await, vec!["Hi Carol"]);
+    }
+
+    #[tokio::test]
+    async fn receipts_only_go_to_the_sender() {
+        let (alice, bob, carol) = gen_three_test_clients().await;
+        let group =
+            GroupConversation::create(&alice, vec![bob.wallet_address(), carol.wallet_address()])
+                .await
+                .unwrap();
+        let convo_id = group.convo_id();
+        Conversations::receive(&bob).unwrap();
+        GroupConversation::from_convo_id(&bob, convo_id.clone())
+            .unwrap()
+            .send_text("Hi all")
+            .await
+            .unwrap();
+
+        // Alice created the group, so she acknowledges Bob's message, to Bob alone
+        Conversations::receive(&alice).unwrap();
+        let receipts = alice.store.get_unprocessed_messages().unwrap();
+        assert_eq!(receipts.len(), 1);
+        Conversations::process_outbound_messages(&alice)
+            .await
+            .unwrap();
+        let payloads = alice
+            .store
+            .get_message_payloads(&mut alice.store.conn().unwrap(), &receipts[0].message_id)
+            .unwrap();
+        assert!(!payloads.is_empty());
+        assert!(payloads
+            .iter()
+            .all(|payload| payload.recipient_address == bob.wallet_address()));
+
+        // Carol was added rather than allowing the group, so she acknowledges nothing
+        Conversations::receive(&carol).unwrap();
+        assert!(carol.store.get_unprocessed_messages().unwrap().is_empty());
+    }
+
+    fn assert_rejected_change(client: &Client<MockXmtpApiClient>) {
+        let invalid = client
+            .store
+            .get_inbound_messages(
+                &mut client.store.conn().unwrap(),
+                InboundMessageStatus::Invalid,
+            )
+            .unwrap();
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(
+            invalid[0].status_reason,
+            Some(InvalidMessageReason::UnauthorizedMembershipChange)
+        );
+    }
+
+    #[tokio::test]
+    async fn only_creator_can_remove_members() {
+        let (alice, bob, carol) = gen_three_test_clients().await;
+        let group =
+            GroupConversation::create(&alice, vec![bob.wallet_address(), carol.wallet_address()])
+                .await
+                .unwrap();
+        let convo_id = group.convo_id();
+        Conversations::receive(&bob).unwrap();
+        let bob_group = GroupConversation::from_convo_id(&bob, convo_id.clone()).unwrap();
+
+        assert!(bob_group
+            .remove_members(vec![carol.wallet_address()])
+            .await
+            .is_err());
+
+        // Sent anyway, bypassing the check above
+        bob_group
+            .send_membership_change(GroupMembershipChange {
+                members: vec![alice.wallet_address(), bob.wallet_address()],
+                added: vec![],
+                removed: vec![carol.wallet_address()],
+            })
+            .await
+            .unwrap();
+        Conversations::receive(&alice).unwrap();
+        assert_rejected_change(&alice);
+        assert!(group.members().unwrap().contains(&carol.wallet_address()));
+    }
+
+    #[tokio::test]
+    async fn spoofed_change_does_not_create_group() {
+        let (alice, bob, carol) = gen_three_test_clients().await;
+        let group = GroupConversation::create(&alice, vec![bob.wallet_address()])
+            .await
+            .unwrap();
+        let convo_id = group.convo_id();
+        Conversations::receive(&bob).unwrap();
+
+        // Bob claims Carol was already a member, instead of adding her
+        let members = vec![
+            alice.wallet_address(),
+            bob.wallet_address(),
+            carol.wallet_address(),
+        ];
+        bob.store
+            .set_conversation_members(&mut bob.store.conn().unwrap(), &convo_id, &members)
+            .unwrap();
+        GroupConversation::from_convo_id(&bob, convo_id.clone())
+            .unwrap()
+            .send_membership_change(GroupMembershipChange {
+                members,
+                added: vec![],
+                removed: vec![],
+            })
+            .await
+            .unwrap();
+
+        Conversations::receive(&carol).unwrap();
+        assert_rejected_change(&carol);
+        assert!(GroupConversation::from_convo_id(&carol, convo_id).is_err());
+    }
+
+    #[test]
+    fn membership_change_authorization() {
+        let (alice, bob, carol) = ("0xa", "0xb", "0xc");
+        let convo_id = new_group_convo_id(alice);
+        let members = vec![alice.to_string(), bob.to_string()];
+        let change = |members: &[&str], added: &[&str], removed: &[&str]| GroupMembershipChange {
+            members: members.iter().map(|a| a.to_string()).collect(),
+            added: added.iter().map(|a| a.to_string()).collect(),
+            removed: removed.iter().map(|a| a.to_string()).collect(),
+        };
+        let known = |sender, recipient, change: &GroupMembershipChange| {
+            is_authorized_membership_change(&convo_id, &members, sender, recipient, change)
+        };
+        let unknown = |sender, recipient, change: &GroupMembershipChange| {
+            is_authorized_membership_change(&convo_id, &[], sender, recipient, change)
+        };
+
+        // Members may add, but only the creator may remove, and never the creator itself
+        let add_carol = change(&[alice, bob, carol], &[carol], &[]);
+        assert!(known(bob, alice, &add_carol));
+        assert!(known(alice, bob, &change(&[alice], &[], &[bob])));
+        let remove_alice = change(&[bob], &[], &[alice]);
+        assert!(!known(bob, alice, &remove_alice));
+        assert!(!known(alice, bob, &remove_alice));
+
+        // The stated changes must match the member list
+        assert!(!known(alice, bob, &change(&[alice, carol], &[carol], &[])));
+        let hidden_addition = change(&[alice, bob, carol], &[], &[]);
+        assert!(!known(bob, alice, &hidden_addition));
+
+        // Unknown groups are only joined by being added, to a group that includes its creator
+        assert!(unknown(bob, carol, &add_carol));
+        assert!(!unknown(alice, carol, &hidden_addition));
+        assert!(!unknown(bob, carol, &change(&[bob, carol], &[carol], &[])));
+    }
+}
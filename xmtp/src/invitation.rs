@@ -168,13 +168,11 @@ mod tests {
 
     use super::Invitation;
 
-    #[test]
-    fn serialize_round_trip() {
+    #[tokio::test]
+    async fn serialize_round_trip() {
         let client = ClientBuilder::new_test().build().unwrap();
         let other_account = Account::generate(test_wallet_signer).unwrap();
-        let mut session = client
-            .get_session(&mut client.store.conn().unwrap(), &other_account.contact())
-            .unwrap();
+        let mut session = client.get_session(&other_account.contact()).await.unwrap();
 
         let invitation = Invitation::build(
             client.account.contact(),
@@ -199,19 +197,18 @@ mod tests {
         assert_eq!(invitation.ciphertext, invitation2.ciphertext);
     }
 
-    #[test]
-    fn fail_on_bad_invite() {
+    #[tokio::test]
+    async fn fail_on_bad_invite() {
         let client = ClientBuilder::new_test().build().unwrap();
         let other_account = Account::generate(test_wallet_signer).unwrap();
-        let mut session = client
-            .get_session(&mut client.store.conn().unwrap(), &other_account.contact())
-            .unwrap();
+        let mut session = client.get_session(&other_account.contact()).await.unwrap();
 
         let bad_bundle = InstallationContactBundle {
             version: Some(ContactBundleVersionProto::V1(
                 VmacInstallationPublicKeyBundleV1 {
                     identity_key: None,
                     fallback_key: None,
+                    sealed_sender_key: None,
                 },
             )),
         };
@@ -2,15 +2,18 @@ pub mod account;
 pub mod association;
 pub mod builder;
 pub mod client;
-mod codecs;
+pub mod codecs;
 pub mod contact;
 pub mod conversation;
 pub mod conversations;
+mod crypto;
+pub mod group_conversation;
 pub mod invitation;
 pub mod message;
 pub mod mock_xmtp_api_client;
 pub mod owner;
 pub mod persistence;
+pub mod sealed_sender;
 pub mod session;
 pub mod storage;
 mod test_utils;
@@ -24,7 +27,11 @@ pub use client::{Client, Network};
 use storage::StorageError;
 use xmtp_cryptography::signature::{RecoverableSignature, SignatureError};
 
-pub use codecs::{text::TextCodec, ContentCodec};
+pub use codecs::{
+    membership::{GroupMembershipChange, GroupMembershipCodec},
+    text::TextCodec,
+    CodecRegistry, ContentCodec, CustomContent, DynContentCodec, MessageContent,
+};
 
 pub trait Signable {
     fn bytes_to_sign(&self) -> Vec<u8>;
@@ -1,4 +1,6 @@
 use crate::{
+    account::Account,
+    sealed_sender::{unseal, SealedSenderError},
     storage::InboundMessage,
     types::{Address, InstallationId},
 };
@@ -15,6 +17,8 @@ pub enum PayloadError {
     ProstDecode(#[from] ProstDecodeError),
     #[error("vmacdecode:{0}")]
     VmacDecode(#[from] VmacDecodeError),
+    #[error("sealedsender:{0}")]
+    SealedSender(#[from] SealedSenderError),
     #[error("error:{0}")]
     Generic(String),
 }
@@ -24,32 +28,39 @@ pub fn decode_bytes<T: prost::Message + Default>(bytes: &[u8]) -> Result<T, Payl
 }
 
 pub struct DecodedInboundMessage {
+    /// Id of the `InboundMessage` this was decoded from
+    pub inbound_message_id: String,
     pub sender_address: Address,
     pub sender_installation_id: InstallationId,
     pub recipient_address: Address,
     pub recipient_installation_id: InstallationId,
     pub is_prekey_message: bool,
+    pub header_bytes: Vec<u8>,
     pub ciphertext: Vec<u8>,
     pub sent_at_ns: i64,
 }
 
-impl TryFrom<InboundMessage> for DecodedInboundMessage {
+// The recipient's account is needed to unseal the sender metadata
+impl TryFrom<(InboundMessage, &Account)> for DecodedInboundMessage {
     type Error = PayloadError;
 
-    fn try_from(value: InboundMessage) -> Result<Self, Self::Error> {
+    fn try_from((value, account): (InboundMessage, &Account)) -> Result<Self, Self::Error> {
         let message_envelope: PadlockMessageEnvelope = decode_bytes(&value.payload)?;
         let message_header: PadlockMessageHeader = decode_bytes(&message_envelope.header_bytes)?;
         let unsealed_header: PadlockMessageSealedMetadata =
-            decode_bytes(&message_header.sealed_metadata)?;
+            unseal(&message_header.sealed_metadata, account.sealed_sender_key())?;
 
         Ok(Self {
+            inbound_message_id: value.id,
             sender_address: unsealed_header.sender_user_address,
             sender_installation_id: unsealed_header.sender_installation_id,
             recipient_address: unsealed_header.recipient_user_address,
             recipient_installation_id: unsealed_header.recipient_installation_id,
             is_prekey_message: unsealed_header.is_prekey_message,
+            header_bytes: message_envelope.header_bytes,
             ciphertext: message_envelope.ciphertext,
-            sent_at_ns: value.sent_at_ns,
+            // The header is signed by the sender, unlike the envelope timestamp
+            sent_at_ns: message_header.sent_ns as i64,
         })
     }
 }
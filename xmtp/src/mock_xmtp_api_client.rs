@@ -1,26 +1,112 @@
 use crate::types::networking::*;
 use async_trait::async_trait;
+use prost::Message;
 use std::{
     collections::HashMap,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, Weak,
+    },
 };
+use xmtp_cryptography::hash::sha256_bytes;
+use xmtp_proto::xmtp::v3::message_contents::VmacOneTimeKeyTopupBundle;
 
-pub struct MockXmtpApiSubscription {}
+use crate::utils::build_envelope;
+
+// Mirrors the default (and maximum) page size of the network nodes
+const DEFAULT_PAGE_SIZE: u32 = 100;
+
+// Envelopes are ordered by timestamp, with the digest breaking ties, the same way the nodes do
+fn index_cursor(envelope: &Envelope) -> IndexCursor {
+    IndexCursor {
+        digest: sha256_bytes(
+            &[
+                envelope.content_topic.as_bytes(),
+                envelope.message.as_slice(),
+            ]
+            .concat(),
+        ),
+        sender_time_ns: envelope.timestamp_ns,
+    }
+}
+
+fn sort_key(cursor: IndexCursor) -> (u64, Vec<u8>) {
+    (cursor.sender_time_ns, cursor.digest)
+}
+
+pub struct MockXmtpApiSubscription {
+    topics: Vec<String>,
+    pending: Arc<Mutex<Vec<Envelope>>>,
+    closed: Arc<AtomicBool>,
+    // Only set on the subscription returned to the caller, which removes its handle from the
+    // client as soon as it is closed or dropped
+    client: Option<Weak<Mutex<InnerMockXmtpApiClient>>>,
+}
+
+impl MockXmtpApiSubscription {
+    fn new(topics: Vec<String>, client: &Arc<Mutex<InnerMockXmtpApiClient>>) -> Self {
+        Self {
+            topics,
+            pending: Arc::new(Mutex::new(Vec::new())),
+            closed: Arc::new(AtomicBool::new(false)),
+            client: Some(Arc::downgrade(client)),
+        }
+    }
+
+    // Returns a handle that shares the pending queue and closed flag with this subscription
+    fn handle(&self) -> Self {
+        Self {
+            topics: self.topics.clone(),
+            pending: self.pending.clone(),
+            closed: self.closed.clone(),
+            client: None,
+        }
+    }
+
+    fn unregister(&self) {
+        let client = match self.client.as_ref().and_then(Weak::upgrade) {
+            Some(client) => client,
+            None => return,
+        };
+        if let Ok(mut inner) = client.lock() {
+            inner
+                .subscriptions
+                .retain(|handle| !Arc::ptr_eq(&handle.closed, &self.closed));
+        };
+    }
+
+    fn deliver(&self, envelope: &Envelope) {
+        if self.topics.contains(&envelope.content_topic) {
+            self.pending.lock().unwrap().push(envelope.clone());
+        }
+    }
+}
 
 impl XmtpApiSubscription for MockXmtpApiSubscription {
     fn is_closed(&self) -> bool {
-        false
+        self.closed.load(Ordering::SeqCst)
     }
 
     fn get_messages(&self) -> Vec<Envelope> {
-        vec![]
+        let mut pending = self.pending.lock().unwrap();
+        pending.drain(..).collect()
+    }
+
+    fn close_stream(&mut self) {
+        self.closed.store(true, Ordering::SeqCst);
+        self.unregister();
     }
+}
 
-    fn close_stream(&mut self) {}
+impl Drop for MockXmtpApiSubscription {
+    fn drop(&mut self) {
+        self.unregister();
+    }
 }
 
 struct InnerMockXmtpApiClient {
     pub messages: HashMap<String, Vec<Envelope>>,
+    pub subscriptions: Vec<MockXmtpApiSubscription>,
     pub app_version: String,
 }
 
@@ -33,6 +119,7 @@ impl MockXmtpApiClient {
         Self {
             inner_client: Arc::new(Mutex::new(InnerMockXmtpApiClient {
                 messages: HashMap::new(),
+                subscriptions: Vec::new(),
                 app_version: String::from("0.0.0"),
             })),
         }
@@ -69,6 +156,9 @@ impl XmtpApiClient for MockXmtpApiClient {
     ) -> Result<PublishResponse, Error> {
         let mut inner = self.inner_client.lock().unwrap();
         for envelope in request.envelopes {
+            for subscription in inner.subscriptions.iter() {
+                subscription.deliver(&envelope);
+            }
             let topic = envelope.content_topic.clone();
             let mut existing: Vec<Envelope> = match inner.messages.get(&topic) {
                 Some(existing_envelopes) => existing_envelopes.clone(),
@@ -82,18 +172,295 @@ impl XmtpApiClient for MockXmtpApiClient {
 
     async fn query(&self, request: QueryRequest) -> Result<QueryResponse, Error> {
         let inner = self.inner_client.lock().unwrap();
-        let envelopes: Vec<Envelope> = match inner.messages.get(&request.content_topics[0]) {
-            Some(envelopes) => envelopes.clone(),
-            None => vec![],
+        let paging_info = request.paging_info.unwrap_or_default();
+        let descending = paging_info.direction == SortDirection::Descending as i32;
+        let limit = match paging_info.limit {
+            0 => DEFAULT_PAGE_SIZE,
+            limit => limit.min(DEFAULT_PAGE_SIZE),
+        };
+        let start_after = match paging_info.cursor.and_then(|c| c.cursor) {
+            Some(cursor::Cursor::Index(index)) => Some(sort_key(index)),
+            None => None,
+        };
+
+        let mut matching: Vec<((u64, Vec<u8>), Envelope)> = request
+            .content_topics
+            .iter()
+            .filter_map(|topic| inner.messages.get(topic))
+            .flatten()
+            .filter(|e| request.start_time_ns == 0 || e.timestamp_ns >= request.start_time_ns)
+            .filter(|e| request.end_time_ns == 0 || e.timestamp_ns <= request.end_time_ns)
+            .map(|e| (sort_key(index_cursor(e)), e.clone()))
+            .collect();
+        matching.sort_by(|a, b| a.0.cmp(&b.0));
+        if descending {
+            matching.reverse();
+        }
+        if let Some(start_after) = start_after {
+            matching.retain(|(key, _)| {
+                if descending {
+                    *key < start_after
+                } else {
+                    *key > start_after
+                }
+            });
+        }
+
+        let envelopes: Vec<Envelope> = matching
+            .into_iter()
+            .take(limit as usize)
+            .map(|(_, envelope)| envelope)
+            .collect();
+        // Only a full page indicates that there may be more results to fetch
+        let next_cursor = if envelopes.len() == limit as usize {
+            envelopes.last().map(|e| Cursor {
+                cursor: Some(cursor::Cursor::Index(index_cursor(e))),
+            })
+        } else {
+            None
         };
 
         Ok(QueryResponse {
             envelopes,
-            paging_info: None,
+            paging_info: Some(PagingInfo {
+                limit,
+                cursor: next_cursor,
+                direction: paging_info.direction,
+            }),
         })
     }
 
-    async fn subscribe(&self, _request: SubscribeRequest) -> Result<Self::Subscription, Error> {
-        Err(Error::new(ErrorKind::SubscribeError))
+    async fn subscribe(&self, request: SubscribeRequest) -> Result<Self::Subscription, Error> {
+        let subscription = MockXmtpApiSubscription::new(request.content_topics, &self.inner_client);
+        let mut inner = self.inner_client.lock().unwrap();
+        inner.subscriptions.push(subscription.handle());
+
+        Ok(subscription)
+    }
+
+    // Stands in for the node's key server: hands out one key from the oldest topup bundle on the
+    // topic and deletes it, so that the same key is never claimed twice
+    async fn claim_one_time_key(&self, topic: String) -> Result<Option<Envelope>, Error> {
+        let mut inner = self.inner_client.lock().unwrap();
+        let envelopes = match inner.messages.get_mut(&topic) {
+            Some(envelopes) => envelopes,
+            None => return Ok(None),
+        };
+
+        while !envelopes.is_empty() {
+            let mut bundle = VmacOneTimeKeyTopupBundle::decode(envelopes[0].message.as_slice())
+                .map_err(|e| Error::new(ErrorKind::QueryError).with(e))?;
+            let claimed_key = bundle.one_time_keys.pop();
+            if bundle.one_time_keys.is_empty() {
+                envelopes.remove(0);
+            } else {
+                envelopes[0].message = bundle.encode_to_vec();
+            }
+
+            if let Some(key) = claimed_key {
+                let claimed = VmacOneTimeKeyTopupBundle {
+                    identity_key: bundle.identity_key,
+                    one_time_keys: vec![key],
+                };
+                return Ok(Some(build_envelope(topic, claimed.encode_to_vec())));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use xmtp_proto::xmtp::v3::message_contents::VmacOneTimeKeyTopupBundle;
+
+    use super::MockXmtpApiClient;
+    use crate::{
+        account::{tests::test_wallet_signer, Account},
+        types::networking::*,
+        utils::build_envelope,
+    };
+
+    async fn publish_numbered(client: &MockXmtpApiClient, topic: &str, count: u64) {
+        let envelopes = (1..=count)
+            .map(|i| Envelope {
+                content_topic: topic.to_string(),
+                timestamp_ns: i * 10,
+                message: vec![i as u8],
+            })
+            .collect();
+        client
+            .publish("".to_string(), PublishRequest { envelopes })
+            .await
+            .unwrap();
+    }
+
+    fn paged_query(topic: &str, limit: u32, direction: SortDirection) -> QueryRequest {
+        QueryRequest {
+            content_topics: vec![topic.to_string()],
+            start_time_ns: 0,
+            end_time_ns: 0,
+            paging_info: Some(PagingInfo {
+                limit,
+                cursor: None,
+                direction: direction as i32,
+            }),
+        }
+    }
+
+    fn message_ids(envelopes: &[Envelope]) -> Vec<u8> {
+        envelopes.iter().map(|e| e.message[0]).collect()
+    }
+
+    #[tokio::test]
+    async fn query_honors_limit_and_cursor() {
+        let client = MockXmtpApiClient::new();
+        publish_numbered(&client, "topic", 5).await;
+
+        let mut pages = QueryPages::new(&client, paged_query("topic", 2, SortDirection::Ascending));
+        assert_eq!(
+            message_ids(&pages.next_page().await.unwrap().unwrap()),
+            vec![1, 2]
+        );
+        assert_eq!(
+            message_ids(&pages.next_page().await.unwrap().unwrap()),
+            vec![3, 4]
+        );
+        assert_eq!(
+            message_ids(&pages.next_page().await.unwrap().unwrap()),
+            vec![5]
+        );
+        assert!(pages.next_page().await.unwrap().is_none());
+
+        let all = QueryPages::new(&client, paged_query("topic", 2, SortDirection::Ascending))
+            .collect_all()
+            .await
+            .unwrap();
+        assert_eq!(message_ids(&all), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn query_honors_direction() {
+        let client = MockXmtpApiClient::new();
+        publish_numbered(&client, "topic", 5).await;
+
+        let all = QueryPages::new(&client, paged_query("topic", 2, SortDirection::Descending))
+            .collect_all()
+            .await
+            .unwrap();
+        assert_eq!(message_ids(&all), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn query_honors_time_range() {
+        let client = MockXmtpApiClient::new();
+        publish_numbered(&client, "topic", 5).await;
+
+        let mut request = paged_query("topic", 0, SortDirection::Ascending);
+        request.start_time_ns = 20;
+        request.end_time_ns = 40;
+        let response = client.query(request).await.unwrap();
+        assert_eq!(message_ids(&response.envelopes), vec![2, 3, 4]);
+        assert!(response.paging_info.unwrap().cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn claim_one_time_key_serves_each_key_once() {
+        let client = MockXmtpApiClient::new();
+        let account = Account::generate(test_wallet_signer).unwrap();
+        let bundle = account.generate_one_time_keys(2);
+        client
+            .publish(
+                "".to_string(),
+                PublishRequest {
+                    envelopes: vec![build_envelope("otk".to_string(), bundle.encode_to_vec())],
+                },
+            )
+            .await
+            .unwrap();
+
+        let mut claimed = vec![];
+        for _ in 0..2 {
+            let envelope = client
+                .claim_one_time_key("otk".to_string())
+                .await
+                .unwrap()
+                .unwrap();
+            let claimed_bundle =
+                VmacOneTimeKeyTopupBundle::decode(envelope.message.as_slice()).unwrap();
+            assert_eq!(claimed_bundle.identity_key, bundle.identity_key);
+            assert_eq!(claimed_bundle.one_time_keys.len(), 1);
+            claimed.extend(claimed_bundle.one_time_keys);
+        }
+        assert_ne!(claimed[0], claimed[1]);
+        assert!(bundle.one_time_keys.contains(&claimed[0]));
+        assert!(bundle.one_time_keys.contains(&claimed[1]));
+
+        // Every key has been handed out
+        assert!(client
+            .claim_one_time_key("otk".to_string())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn subscribe_receives_published_envelopes() {
+        let client = MockXmtpApiClient::new();
+        let mut subscription = client
+            .subscribe(SubscribeRequest {
+                content_topics: vec!["topic-a".to_string()],
+            })
+            .await
+            .unwrap();
+
+        client
+            .publish(
+                "".to_string(),
+                PublishRequest {
+                    envelopes: vec![
+                        build_envelope("topic-a".to_string(), vec![1]),
+                        build_envelope("topic-b".to_string(), vec![2]),
+                    ],
+                },
+            )
+            .await
+            .unwrap();
+
+        let messages = subscription.get_messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].message, vec![1]);
+        // Messages are drained once read
+        assert!(subscription.get_messages().is_empty());
+
+        subscription.close_stream();
+        assert!(subscription.is_closed());
+        // Closed subscriptions are forgotten straight away, not on the next publish
+        assert!(client.inner_client.lock().unwrap().subscriptions.is_empty());
+        client
+            .publish(
+                "".to_string(),
+                PublishRequest {
+                    envelopes: vec![build_envelope("topic-a".to_string(), vec![3])],
+                },
+            )
+            .await
+            .unwrap();
+        assert!(subscription.get_messages().is_empty());
+    }
+
+    #[tokio::test]
+    async fn dropped_subscription_is_forgotten() {
+        let client = MockXmtpApiClient::new();
+        let request = || SubscribeRequest {
+            content_topics: vec!["topic".to_string()],
+        };
+        let subscription = client.subscribe(request()).await.unwrap();
+        let _other = client.subscribe(request()).await.unwrap();
+        assert_eq!(client.inner_client.lock().unwrap().subscriptions.len(), 2);
+
+        drop(subscription);
+        assert_eq!(client.inner_client.lock().unwrap().subscriptions.len(), 1);
     }
 }
@@ -0,0 +1,164 @@
+//! Sealed sender hides who sent a message, and to whom, from anyone other than the recipient.
+//!
+//! `PadlockMessageSealedMetadata` is encrypted to the sealed sender key published in the recipient
+//! installation's contact bundle, using a fresh ephemeral key for every message. The X25519 shared
+//! secret is expanded into the keys and IV for the cipher in `crypto`.
+//!
+//! Wire format: `ephemeral_public_key (32) || ciphertext || mac (32)`
+
+use prost::{DecodeError, Message};
+use thiserror::Error;
+use vodozemac::{Curve25519PublicKey, Curve25519SecretKey};
+use xmtp_proto::xmtp::v3::message_contents::PadlockMessageSealedMetadata;
+
+use crate::crypto::{CbcHmacKeys, MAC_LENGTH};
+
+const SEALED_SENDER_INFO: &[u8] = b"XMTP_SEALED_SENDER_V1";
+const PUBLIC_KEY_LENGTH: usize = 32;
+
+#[derive(Debug, Error)]
+pub enum SealedSenderError {
+    #[error("sealed metadata is truncated")]
+    Truncated,
+    #[error("mac verification failed")]
+    InvalidMac,
+    #[error("decryption failed")]
+    Decryption,
+    #[error("decode error: {0}")]
+    Decode(#[from] DecodeError),
+}
+
+fn derive_keys(
+    shared_secret: &[u8],
+    ephemeral_key: &Curve25519PublicKey,
+    recipient_key: &Curve25519PublicKey,
+) -> CbcHmacKeys {
+    // Binding both public keys into the salt ties the derived keys to this exact exchange
+    let salt = [ephemeral_key.to_bytes(), recipient_key.to_bytes()].concat();
+    CbcHmacKeys::derive(shared_secret, &salt, SEALED_SENDER_INFO)
+}
+
+/// Encrypt the metadata so that only the holder of `recipient_key` can read it
+pub fn seal(
+    metadata: &PadlockMessageSealedMetadata,
+    recipient_key: &Curve25519PublicKey,
+) -> Vec<u8> {
+    let ephemeral_secret = Curve25519SecretKey::new();
+    let ephemeral_key = Curve25519PublicKey::from(&ephemeral_secret);
+    let shared_secret = ephemeral_secret.diffie_hellman(recipient_key);
+    let keys = derive_keys(shared_secret.as_bytes(), &ephemeral_key, recipient_key);
+
+    let ciphertext = keys.encrypt(&keys.iv, &metadata.encode_to_vec());
+    let mut sealed = [ephemeral_key.to_bytes().as_slice(), ciphertext.as_slice()].concat();
+    sealed.extend(keys.mac(&sealed));
+
+    sealed
+}
+
+/// Decrypt metadata sealed to the public key belonging to `secret_key`
+pub fn unseal(
+    sealed: &[u8],
+    secret_key: &Curve25519SecretKey,
+) -> Result<PadlockMessageSealedMetadata, SealedSenderError> {
+    if sealed.len() < PUBLIC_KEY_LENGTH + MAC_LENGTH {
+        return Err(SealedSenderError::Truncated);
+    }
+    let (authenticated, expected_mac) = sealed.split_at(sealed.len() - MAC_LENGTH);
+    let (ephemeral_key_bytes, ciphertext) = authenticated.split_at(PUBLIC_KEY_LENGTH);
+
+    let mut ephemeral_key = [0u8; PUBLIC_KEY_LENGTH];
+    ephemeral_key.copy_from_slice(ephemeral_key_bytes);
+    let ephemeral_key = Curve25519PublicKey::from_bytes(ephemeral_key);
+    let recipient_key = Curve25519PublicKey::from(secret_key);
+    let shared_secret = secret_key.diffie_hellman(&ephemeral_key);
+    let keys = derive_keys(shared_secret.as_bytes(), &ephemeral_key, &recipient_key);
+
+    keys.verify_mac(authenticated, expected_mac)
+        .map_err(|_| SealedSenderError::InvalidMac)?;
+    let plaintext = keys
+        .decrypt(&keys.iv, ciphertext)
+        .map_err(|_| SealedSenderError::Decryption)?;
+
+    Ok(PadlockMessageSealedMetadata::decode(plaintext.as_slice())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use vodozemac::{Curve25519PublicKey, Curve25519SecretKey};
+    use xmtp_proto::xmtp::v3::message_contents::PadlockMessageSealedMetadata;
+
+    use super::{seal, unseal, SealedSenderError};
+    use crate::account::{tests::test_wallet_signer, Account};
+
+    fn test_metadata() -> PadlockMessageSealedMetadata {
+        PadlockMessageSealedMetadata {
+            sender_user_address: "0xSenderAddress".to_string(),
+            sender_installation_id: "sender_installation".to_string(),
+            recipient_user_address: "0xRecipientAddress".to_string(),
+            recipient_installation_id: "recipient_installation".to_string(),
+            is_prekey_message: true,
+        }
+    }
+
+    #[test]
+    fn seal_round_trip() {
+        let recipient_secret = Curve25519SecretKey::new();
+        let recipient_key = Curve25519PublicKey::from(&recipient_secret);
+
+        let sealed = seal(&test_metadata(), &recipient_key);
+        let unsealed = unseal(&sealed, &recipient_secret).unwrap();
+
+        assert_eq!(unsealed, test_metadata());
+    }
+
+    #[test]
+    fn sealed_metadata_is_not_readable() {
+        let recipient_key = Curve25519PublicKey::from(&Curve25519SecretKey::new());
+        let sealed = seal(&test_metadata(), &recipient_key);
+
+        for field in [
+            "0xSenderAddress",
+            "0xRecipientAddress",
+            "sender_installation",
+        ] {
+            assert!(!sealed
+                .windows(field.len())
+                .any(|window| window == field.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn third_party_cannot_unseal() {
+        let recipient = Account::generate(test_wallet_signer).unwrap();
+        let third_party = Account::generate(test_wallet_signer).unwrap();
+
+        let sealed = seal(
+            &test_metadata(),
+            &recipient.contact().vmac_sealed_sender_key(),
+        );
+
+        let result = unseal(&sealed, third_party.sealed_sender_key());
+        assert!(matches!(result, Err(SealedSenderError::InvalidMac)));
+
+        let unsealed = unseal(&sealed, recipient.sealed_sender_key()).unwrap();
+        assert_eq!(unsealed, test_metadata());
+    }
+
+    #[test]
+    fn tampered_metadata_is_rejected() {
+        let recipient_secret = Curve25519SecretKey::new();
+        let recipient_key = Curve25519PublicKey::from(&recipient_secret);
+
+        let mut sealed = seal(&test_metadata(), &recipient_key);
+        sealed[40] ^= 1;
+        assert!(matches!(
+            unseal(&sealed, &recipient_secret),
+            Err(SealedSenderError::InvalidMac)
+        ));
+
+        assert!(matches!(
+            unseal(&sealed[..40], &recipient_secret),
+            Err(SealedSenderError::Truncated)
+        ));
+    }
+}
@@ -141,7 +141,7 @@ mod tests {
         let account_a_contact = account_a.contact();
         let account_b_contact = account_b.contact();
 
-        let a_to_b_olm_session = account_a.create_outbound_session(&account_b_contact);
+        let a_to_b_olm_session = account_a.create_outbound_session(&account_b_contact, None);
         let mut a_to_b_session =
             super::SessionManager::from_olm_session(a_to_b_olm_session, &account_b_contact)
                 .unwrap();
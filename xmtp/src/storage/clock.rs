@@ -0,0 +1,52 @@
+//! Sources of the current time, so that code which depends on it can be tested at any time
+
+use std::{
+    sync::atomic::{AtomicI64, Ordering},
+    time::Duration,
+};
+
+use super::now;
+
+pub trait Clock: Send + Sync {
+    /// Nanoseconds since the unix epoch
+    fn now_ns(&self) -> i64;
+}
+
+/// The system time, as returned by `now()`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock {}
+
+impl Clock for SystemClock {
+    fn now_ns(&self) -> i64 {
+        now()
+    }
+}
+
+/// A clock which only moves when it is told to
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    now_ns: AtomicI64,
+}
+
+impl ManualClock {
+    pub fn new(now_ns: i64) -> Self {
+        Self {
+            now_ns: AtomicI64::new(now_ns),
+        }
+    }
+
+    pub fn set(&self, now_ns: i64) {
+        self.now_ns.store(now_ns, Ordering::SeqCst);
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.now_ns
+            .fetch_add(by.as_nanos() as i64, Ordering::SeqCst);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_ns(&self) -> i64 {
+        self.now_ns.load(Ordering::SeqCst)
+    }
+}
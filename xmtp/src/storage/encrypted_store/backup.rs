@@ -0,0 +1,616 @@
+//! Encrypted backups of everything needed to restore a client on another device: accounts,
+//! users, installations, sessions, conversations and, unless excluded, messages. Payloads
+//! waiting to be processed or published are specific to the device and are not backed up.
+//!
+//! Archive format, with integers in big endian:
+//! `magic (8) || format version (1) || kdf (1) || kdf iterations (4) || salt (32) || iv (16) ||
+//! ciphertext || mac (32)`
+//!
+//! The contents are serialized as JSON, compressed with deflate and encrypted with the cipher in
+//! `crypto`. The encryption and MAC keys are derived from the backup key, or from a password
+//! stretched with PBKDF2-HMAC-SHA256. The IV is random, and the MAC covers everything before it.
+//!
+//! `BACKUP_SCHEMA_VERSION` is bumped whenever the backed up models change. Fields added to them
+//! need `#[serde(default)]` so that older backups can still be imported.
+
+use diesel::prelude::*;
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use thiserror::Error;
+use xmtp_proto::xmtp::message_contents::Compression;
+
+use super::{
+    index_message,
+    models::{
+        stored_content_type, StoredConversation, StoredConversationMember, StoredInstallation,
+        StoredMessage, StoredSession, StoredUser,
+    },
+    schema::{conversation_members, conversations, installations, messages, sessions, users},
+    EncryptedMessageStore, EncryptionKey,
+};
+use crate::{
+    account::Account,
+    codecs::compression::{compress, decompress},
+    crypto::{CbcHmacKeys, IV_LENGTH, MAC_LENGTH},
+    storage::{now, StorageError},
+    Fetch, Store,
+};
+
+/// Version of the contents of a backup, see the module docs
+pub const BACKUP_SCHEMA_VERSION: u32 = 1;
+
+const MAGIC: &[u8] = b"XMTPBKUP";
+const FORMAT_VERSION: u8 = 1;
+const BACKUP_INFO: &[u8] = b"XMTP_BACKUP_V1";
+// Tests would take minutes in debug builds with the real work factor
+const PASSWORD_ITERATIONS: u32 = if cfg!(test) { 1_000 } else { 600_000 };
+// Leaves room to raise the work factor, without letting an archive make opening it take
+// arbitrarily long
+const MAX_PASSWORD_ITERATIONS: u32 = 4 * PASSWORD_ITERATIONS;
+const SALT_LENGTH: usize = 32;
+const HEADER_LENGTH: usize = MAGIC.len() + 1 + 1 + 4 + SALT_LENGTH + IV_LENGTH;
+// Archives which decompress to more than this are rejected rather than held in memory
+const MAX_BACKUP_SIZE: usize = 1024 * 1024 * 1024;
+
+#[derive(Debug, Error)]
+pub enum BackupError {
+    #[error("not a backup archive")]
+    NotABackup,
+    #[error("unsupported backup version {0}")]
+    UnsupportedVersion(u32),
+    #[error("unsupported password iterations {0}")]
+    UnsupportedIterations(u32),
+    #[error("backup has been modified or the secret is wrong")]
+    IntegrityCheckFailed,
+    #[error("invalid backup contents: {0}")]
+    InvalidContents(String),
+    #[error("backups can only be imported into a store without an account")]
+    StoreNotEmpty,
+}
+
+/// What a backup is encrypted with
+pub enum BackupSecret {
+    Key(EncryptionKey),
+    Password(String),
+}
+
+impl BackupSecret {
+    fn kdf(&self) -> u8 {
+        match self {
+            BackupSecret::Key(_) => 0,
+            BackupSecret::Password(_) => 1,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BackupOptions {
+    /// Whether messages are backed up, or only what is needed to keep using the identity
+    pub include_history: bool,
+}
+
+impl Default for BackupOptions {
+    fn default() -> Self {
+        Self {
+            include_history: true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct BackupContents {
+    schema_version: u32,
+    created_at_ns: i64,
+    accounts: Vec<Account>,
+    users: Vec<StoredUser>,
+    installations: Vec<StoredInstallation>,
+    sessions: Vec<StoredSession>,
+    conversations: Vec<StoredConversation>,
+    conversation_members: Vec<StoredConversationMember>,
+    messages: Vec<StoredMessage>,
+}
+
+fn derive_keys(secret: &BackupSecret, iterations: u32, salt: &[u8]) -> CbcHmacKeys {
+    let mut stretched = [0u8; 32];
+    let ikm: &[u8] = match secret {
+        BackupSecret::Key(key) => key,
+        BackupSecret::Password(password) => {
+            pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, iterations, &mut stretched);
+            &stretched
+        }
+    };
+
+    CbcHmacKeys::derive(ikm, salt, BACKUP_INFO)
+}
+
+fn seal(plaintext: &[u8], secret: &BackupSecret) -> Vec<u8> {
+    let mut rng = rand::thread_rng();
+    let mut salt = [0u8; SALT_LENGTH];
+    let mut iv = [0u8; IV_LENGTH];
+    rng.fill_bytes(&mut salt);
+    rng.fill_bytes(&mut iv);
+    let iterations = match secret {
+        BackupSecret::Key(_) => 0,
+        BackupSecret::Password(_) => PASSWORD_ITERATIONS,
+    };
+    let keys = derive_keys(secret, iterations, &salt);
+
+    let mut archive = Vec::with_capacity(HEADER_LENGTH + plaintext.len() + 2 * MAC_LENGTH);
+    archive.extend(MAGIC);
+    archive.push(FORMAT_VERSION);
+    archive.push(secret.kdf());
+    archive.extend(iterations.to_be_bytes());
+    archive.extend(salt);
+    archive.extend(iv);
+    archive.extend(keys.encrypt(&iv, plaintext));
+    let mac = keys.mac(&archive);
+    archive.extend(mac);
+
+    archive
+}
+
+fn open(archive: &[u8], secret: &BackupSecret) -> Result<Vec<u8>, BackupError> {
+    if archive.len() < HEADER_LENGTH + MAC_LENGTH || !archive.starts_with(MAGIC) {
+        return Err(BackupError::NotABackup);
+    }
+    let (header, rest) = archive.split_at(HEADER_LENGTH);
+    let format_version = header[MAGIC.len()];
+    if format_version != FORMAT_VERSION {
+        return Err(BackupError::UnsupportedVersion(format_version as u32));
+    }
+    if header[MAGIC.len() + 1] != secret.kdf() {
+        return Err(BackupError::IntegrityCheckFailed);
+    }
+    let iterations = u32::from_be_bytes(
+        header[MAGIC.len() + 2..MAGIC.len() + 6]
+            .try_into()
+            .expect("slice has a fixed length"),
+    );
+    let supported_iterations = match secret {
+        BackupSecret::Key(_) => iterations == 0,
+        BackupSecret::Password(_) => {
+            (PASSWORD_ITERATIONS..=MAX_PASSWORD_ITERATIONS).contains(&iterations)
+        }
+    };
+    if !supported_iterations {
+        return Err(BackupError::UnsupportedIterations(iterations));
+    }
+    let salt = &header[MAGIC.len() + 6..MAGIC.len() + 6 + SALT_LENGTH];
+    let iv: [u8; IV_LENGTH] = header[HEADER_LENGTH - IV_LENGTH..]
+        .try_into()
+        .expect("slice has a fixed length");
+    let (ciphertext, expected_mac) = rest.split_at(rest.len() - MAC_LENGTH);
+
+    let keys = derive_keys(secret, iterations, salt);
+    keys.verify_mac(&archive[..archive.len() - MAC_LENGTH], expected_mac)
+        .map_err(|_| BackupError::IntegrityCheckFailed)?;
+    keys.decrypt(&iv, ciphertext)
+        .map_err(|_| BackupError::IntegrityCheckFailed)
+}
+
+impl EncryptedMessageStore {
+    /// Serialize the client state into an archive encrypted with `secret`, which
+    /// [`EncryptedMessageStore::import_backup`] restores
+    pub fn export_backup(
+        &self,
+        secret: &BackupSecret,
+        opts: &BackupOptions,
+    ) -> Result<Vec<u8>, StorageError> {
+        let conn = &mut self.conn()?;
+        // A single transaction gives a consistent snapshot
+        let contents = conn.transaction::<_, StorageError, _>(|conn| {
+            let messages = if opts.include_history {
+                messages::table.load::<StoredMessage>(conn)?
+            } else {
+                vec![]
+            };
+            Ok(BackupContents {
+                schema_version: BACKUP_SCHEMA_VERSION,
+                created_at_ns: now(),
+                accounts: conn.fetch_all()?,
+                users: users::table.load(conn)?,
+                installations: installations::table.load(conn)?,
+                sessions: sessions::table.load(conn)?,
+                conversations: conversations::table.load(conn)?,
+                conversation_members: conversation_members::table.load(conn)?,
+                messages,
+            })
+        })?;
+
+        let plaintext =
+            serde_json::to_vec(&contents).map_err(|_| StorageError::SerializationError)?;
+        let compressed = compress(&plaintext, Compression::Deflate)
+            .map_err(|e| StorageError::Store(e.to_string()))?;
+
+        Ok(seal(&compressed, secret))
+    }
+
+    /// Restore a backup made by [`EncryptedMessageStore::export_backup`]. The store must not
+    /// have an account yet, and nothing is imported unless everything is.
+    pub fn import_backup(&self, archive: &[u8], secret: &BackupSecret) -> Result<(), StorageError> {
+        let compressed = open(archive, secret)?;
+        let plaintext = decompress(&compressed, Compression::Deflate, MAX_BACKUP_SIZE)
+            .map_err(|e| BackupError::InvalidContents(e.to_string()))?;
+        let mut contents = parse_contents(&plaintext)?;
+        // Derived from the content, and missing from backups made before it was stored
+        for message in contents.messages.iter_mut() {
+            message.content_type = stored_content_type(&message.content);
+        }
+
+        let conn = &mut self.conn()?;
+        conn.transaction::<_, StorageError, _>(|conn| {
+            let existing: Vec<Account> = conn.fetch_all()?;
+            if !existing.is_empty() {
+                return Err(BackupError::StoreNotEmpty.into());
+            }
+
+            for account in contents.accounts.iter() {
+                account.store(conn)?;
+            }
+            diesel::insert_into(users::table)
+                .values(&contents.users)
+                .execute(conn)?;
+            diesel::insert_into(installations::table)
+                .values(&contents.installations)
+                .execute(conn)?;
+            diesel::insert_into(sessions::table)
+                .values(&contents.sessions)
+                .execute(conn)?;
+            diesel::insert_into(conversations::table)
+                .values(&contents.conversations)
+                .execute(conn)?;
+            diesel::insert_into(conversation_members::table)
+                .values(&contents.conversation_members)
+                .execute(conn)?;
+            diesel::insert_into(messages::table)
+                .values(&contents.messages)
+                .execute(conn)?;
+            for message in contents.messages.iter() {
+                index_message(conn, message.id, &message.content)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+fn parse_contents(plaintext: &[u8]) -> Result<BackupContents, BackupError> {
+    #[derive(Deserialize)]
+    struct Versioned {
+        schema_version: u32,
+    }
+
+    let version: Versioned = serde_json::from_slice(plaintext)
+        .map_err(|e| BackupError::InvalidContents(e.to_string()))?;
+    // Older versions are read with the defaults of the fields added since
+    if version.schema_version > BACKUP_SCHEMA_VERSION {
+        return Err(BackupError::UnsupportedVersion(version.schema_version));
+    }
+
+    serde_json::from_slice(plaintext).map_err(|e| BackupError::InvalidContents(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        open, seal, BackupContents, BackupError, BackupOptions, BackupSecret,
+        BACKUP_SCHEMA_VERSION, MAGIC, PASSWORD_ITERATIONS,
+    };
+    use crate::{
+        account::{tests::test_wallet_signer, Account},
+        codecs::compression::{compress, decompress},
+        storage::{
+            ConsentState, ConversationKind, ConversationState, EncryptedMessageStore, MessageState,
+            NewStoredMessage, StorageError, StorageOption, StoredConversation, StoredMessage,
+            StoredUser,
+        },
+        ContentCodec, Fetch, Store, TextCodec,
+    };
+    use prost::Message;
+    use serde_json::json;
+    use xmtp_proto::xmtp::message_contents::Compression;
+
+    fn new_store() -> EncryptedMessageStore {
+        EncryptedMessageStore::new(
+            StorageOption::Ephemeral,
+            EncryptedMessageStore::generate_enc_key(),
+        )
+        .unwrap()
+    }
+
+    fn populated_store() -> EncryptedMessageStore {
+        let store = new_store();
+        let conn = &mut store.conn().unwrap();
+        Account::generate(test_wallet_signer)
+            .unwrap()
+            .store(conn)
+            .unwrap();
+        StoredUser {
+            user_address: "0x01".into(),
+            created_at: 10,
+            last_refreshed: 0,
+        }
+        .store(conn)
+        .unwrap();
+        StoredConversation {
+            convo_id: "convo".into(),
+            peer_address: "0x01".into(),
+            created_at: 10,
+            convo_state: ConversationState::Invited,
+            convo_kind: ConversationKind::Direct,
+            consent_state: ConsentState::Allowed,
+            retention_ns: None,
+        }
+        .store(conn)
+        .unwrap();
+        NewStoredMessage::new(
+            "convo".into(),
+            "0x01".into(),
+            "Hello".into(),
+            MessageState::Received,
+            10,
+        )
+        .store(conn)
+        .unwrap();
+
+        store
+    }
+
+    fn messages(store: &EncryptedMessageStore) -> Vec<StoredMessage> {
+        store.conn().unwrap().fetch_all().unwrap()
+    }
+
+    #[test]
+    fn backup_roundtrip() {
+        let store = populated_store();
+        let key = EncryptedMessageStore::generate_enc_key();
+        for secret in [
+            BackupSecret::Key(key),
+            BackupSecret::Password("correct horse battery staple".into()),
+        ] {
+            let archive = store
+                .export_backup(&secret, &BackupOptions::default())
+                .unwrap();
+            let restored = new_store();
+            restored.import_backup(&archive, &secret).unwrap();
+
+            let account: Account = restored.conn().unwrap().fetch_one(1).unwrap().unwrap();
+            let original: Account = store.conn().unwrap().fetch_one(1).unwrap().unwrap();
+            assert_eq!(json!(account), json!(original));
+            assert_eq!(
+                restored.get_conversation("convo").unwrap(),
+                store.get_conversation("convo").unwrap()
+            );
+            let restored_messages = messages(&restored);
+            assert_eq!(restored_messages.len(), 1);
+            assert_eq!(
+                restored_messages[0].message_id,
+                messages(&store)[0].message_id
+            );
+
+            // A store with an account is left alone
+            assert!(matches!(
+                restored.import_backup(&archive, &secret),
+                Err(StorageError::Backup(BackupError::StoreNotEmpty))
+            ));
+        }
+    }
+
+    #[test]
+    fn backup_without_history() {
+        let store = populated_store();
+        let secret = BackupSecret::Password("hunter2".into());
+        let archive = store
+            .export_backup(
+                &secret,
+                &BackupOptions {
+                    include_history: false,
+                },
+            )
+            .unwrap();
+        let restored = new_store();
+        restored.import_backup(&archive, &secret).unwrap();
+
+        assert!(restored.get_conversation("convo").unwrap().is_some());
+        assert!(messages(&restored).is_empty());
+    }
+
+    #[test]
+    fn rejects_tampered_backups() {
+        let store = populated_store();
+        let secret = BackupSecret::Key(EncryptedMessageStore::generate_enc_key());
+        let archive = store
+            .export_backup(&secret, &BackupOptions::default())
+            .unwrap();
+
+        let wrong_secrets = [
+            BackupSecret::Key(EncryptedMessageStore::generate_enc_key()),
+            BackupSecret::Password("hunter2".into()),
+        ];
+        for wrong_secret in wrong_secrets {
+            assert!(matches!(
+                open(&archive, &wrong_secret),
+                Err(BackupError::IntegrityCheckFailed)
+            ));
+        }
+        for index in [archive.len() / 2, archive.len() - 1, 12] {
+            let mut tampered = archive.clone();
+            tampered[index] ^= 1;
+            assert!(open(&tampered, &secret).is_err());
+        }
+        assert!(matches!(
+            open(&archive[..20], &secret),
+            Err(BackupError::NotABackup)
+        ));
+        assert!(new_store().import_backup(b"not a backup", &secret).is_err());
+    }
+
+    #[test]
+    fn rejects_unsupported_iterations() {
+        let store = populated_store();
+        let secret = BackupSecret::Password("hunter2".into());
+        let archive = store
+            .export_backup(&secret, &BackupOptions::default())
+            .unwrap();
+        let iterations_offset = MAGIC.len() + 2;
+
+        for iterations in [0, PASSWORD_ITERATIONS - 1, u32::MAX] {
+            let mut modified = archive.clone();
+            modified[iterations_offset..iterations_offset + 4]
+                .copy_from_slice(&iterations.to_be_bytes());
+            assert!(matches!(
+                open(&modified, &secret),
+                Err(BackupError::UnsupportedIterations(rejected)) if rejected == iterations
+            ));
+        }
+
+        // Key backups are never stretched
+        let key = BackupSecret::Key(EncryptedMessageStore::generate_enc_key());
+        let mut archive = store
+            .export_backup(&key, &BackupOptions::default())
+            .unwrap();
+        archive[iterations_offset..iterations_offset + 4]
+            .copy_from_slice(&PASSWORD_ITERATIONS.to_be_bytes());
+        assert!(matches!(
+            open(&archive, &key),
+            Err(BackupError::UnsupportedIterations(_))
+        ));
+    }
+
+    #[test]
+    fn recomputes_missing_content_types() {
+        let store = populated_store();
+        let secret = BackupSecret::Key(EncryptedMessageStore::generate_enc_key());
+        let archive = store
+            .export_backup(&secret, &BackupOptions::default())
+            .unwrap();
+
+        // As exported before messages stored their content type
+        let plaintext = decompress(
+            &open(&archive, &secret).unwrap(),
+            Compression::Deflate,
+            1 << 20,
+        )
+        .unwrap();
+        let mut contents: serde_json::Value = serde_json::from_slice(&plaintext).unwrap();
+        for message in contents["messages"].as_array_mut().unwrap() {
+            message.as_object_mut().unwrap().remove("content_type");
+        }
+        let older = seal(
+            &compress(
+                &serde_json::to_vec(&contents).unwrap(),
+                Compression::Deflate,
+            )
+            .unwrap(),
+            &secret,
+        );
+
+        let restored = new_store();
+        restored.import_backup(&older, &secret).unwrap();
+        let restored_messages = messages(&restored);
+        assert_eq!(restored_messages.len(), 1);
+        assert!(!restored_messages[0].content_type.is_empty());
+        assert_eq!(
+            restored_messages[0].content_type,
+            messages(&store)[0].content_type
+        );
+    }
+
+    #[test]
+    fn imports_v1_backups() {
+        let content = TextCodec::encode("Hello".to_string())
+            .unwrap()
+            .encode_to_vec();
+        // Contents as written by the first version of backups, with enums as their discriminants
+        let contents = json!({
+            "schema_version": 1,
+            "created_at_ns": 100,
+            "accounts": [],
+            "users": [{ "user_address": "0x01", "created_at": 10, "last_refreshed": 0 }],
+            "installations": [],
+            "sessions": [],
+            "conversations": [{
+                "convo_id": "convo",
+                "peer_address": "0x01",
+                "created_at": 10,
+                "convo_state": 10,
+                "convo_kind": 0,
+                "consent_state": 10,
+                "retention_ns": null
+            }],
+            "conversation_members": [],
+            "messages": [{
+                "id": 1,
+                "created_at": 20,
+                "sent_at_ns": 20,
+                "convo_id": "convo",
+                "addr_from": "0x01",
+                "content": content,
+                "state": 20,
+                "send_attempts": 0,
+                "max_send_attempts": 5,
+                "next_attempt_ns": 0,
+                "error_reason": null,
+                "message_id": "message"
+            }]
+        });
+        let secret = BackupSecret::Key(EncryptedMessageStore::generate_enc_key());
+        let archive = seal(
+            &compress(
+                &serde_json::to_vec(&contents).unwrap(),
+                Compression::Deflate,
+            )
+            .unwrap(),
+            &secret,
+        );
+
+        let restored = new_store();
+        restored.import_backup(&archive, &secret).unwrap();
+        let convo = restored.get_conversation("convo").unwrap().unwrap();
+        assert_eq!(convo.convo_state, ConversationState::Invited);
+        assert_eq!(convo.convo_kind, ConversationKind::Direct);
+        assert_eq!(convo.consent_state, ConsentState::Allowed);
+        let restored_messages = messages(&restored);
+        assert_eq!(restored_messages.len(), 1);
+        assert_eq!(restored_messages[0].state, MessageState::Received);
+        assert_eq!(restored_messages[0].content_type, "xmtp.org/text");
+
+        // Exports keep the same representation
+        let exported: serde_json::Value = serde_json::to_value(&convo).unwrap();
+        assert_eq!(exported["convo_state"], json!(10));
+    }
+
+    #[test]
+    fn rejects_newer_schema_versions() {
+        let store = populated_store();
+        let secret = BackupSecret::Key(EncryptedMessageStore::generate_enc_key());
+        let archive = store
+            .export_backup(&secret, &BackupOptions::default())
+            .unwrap();
+
+        let plaintext = decompress(
+            &open(&archive, &secret).unwrap(),
+            Compression::Deflate,
+            1 << 20,
+        )
+        .unwrap();
+        let mut contents: BackupContents = serde_json::from_slice(&plaintext).unwrap();
+        contents.schema_version = BACKUP_SCHEMA_VERSION + 1;
+        let newer = seal(
+            &compress(
+                &serde_json::to_vec(&contents).unwrap(),
+                Compression::Deflate,
+            )
+            .unwrap(),
+            &secret,
+        );
+
+        assert!(matches!(
+            new_store().import_backup(&newer, &secret),
+            Err(StorageError::Backup(BackupError::UnsupportedVersion(version)))
+                if version == BACKUP_SCHEMA_VERSION + 1
+        ));
+    }
+}
@@ -11,35 +11,131 @@
 //! `diesel print-schema` or use `cargo run update-schema` which will update the files for you.      
 //!
 
+pub mod backup;
 pub mod models;
 pub mod schema;
 
 use self::{
     models::*,
     schema::{
-        accounts, conversations, inbound_invites, installations, messages, refresh_jobs, users,
+        accounts, conversation_members, conversations, inbound_invites, inbound_messages,
+        installations, messages, refresh_jobs, users,
     },
 };
-use super::{now, StorageError};
-use crate::{account::Account, utils::is_wallet_address, Errorer, Fetch, Store};
+use super::{clock::Clock, now, StorageError};
+use crate::{
+    account::Account,
+    conversation::{ListMessagesOptions, SearchMessagesOptions, SortDirection},
+    utils::is_wallet_address,
+    Errorer, Fetch, Store,
+};
 use diesel::{
     connection::SimpleConnection,
     prelude::*,
-    r2d2::{ConnectionManager, Pool, PooledConnection},
+    r2d2::{ConnectionManager, CustomizeConnection, Pool, PooledConnection},
     sql_query,
-    sql_types::Text,
+    sql_types::{BigInt, Double, Integer, Text},
 };
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use futures::{stream, Stream};
 use log::warn;
 use rand::RngCore;
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    path::Path,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 use xmtp_cryptography::utils as crypto_utils;
 
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations/");
 
 pub type DbConnection = PooledConnection<ConnectionManager<SqliteConnection>>;
 
+// The migration which created `messages_fts`
+const SEARCH_INDEX_MIGRATION: &str = "20230824092233";
+// The migration which added `messages.content_type`
+const CONTENT_TYPE_MIGRATION: &str = "20230825081532";
+
 pub type EncryptionKey = [u8; 32];
 
+/// Sets the SQLCipher key of each connection the pool opens, as keys are per connection
+struct SqlCipherKey(EncryptionKey);
+
+impl fmt::Debug for SqlCipherKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SqlCipherKey(..)")
+    }
+}
+
+impl CustomizeConnection<SqliteConnection, diesel::r2d2::Error> for SqlCipherKey {
+    fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<(), diesel::r2d2::Error> {
+        conn.batch_execute(&format!("PRAGMA key = \"x'{}'\";", hex::encode(self.0)))
+            .map_err(diesel::r2d2::Error::QueryError)
+    }
+}
+
+#[derive(QueryableByName)]
+struct SearchHitRow {
+    #[diesel(sql_type = Integer)]
+    id: i32,
+    #[diesel(sql_type = Double)]
+    rank: f64,
+}
+
+/// Add the text of message `id` to the search index, if it has any
+fn index_message(conn: &mut DbConnection, id: i32, content: &[u8]) -> Result<(), StorageError> {
+    if let Some(text) = searchable_text(content) {
+        sql_query("INSERT INTO messages_fts (rowid, body) VALUES (?, ?)")
+            .bind::<Integer, _>(id)
+            .bind::<Text, _>(text)
+            .execute(conn)?;
+    }
+    Ok(())
+}
+
+/// Quote every word of `query` so that FTS5 syntax in it is matched literally, and match words
+/// which start with it. Words without any letters or digits are dropped, as the tokenizer would
+/// leave an empty phrase.
+fn fts_query(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .filter(|term| term.chars().any(char::is_alphanumeric))
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect();
+    if terms.is_empty() {
+        return None;
+    }
+    Some(terms.join(" "))
+}
+
+fn pool_conn(
+    pool: &Pool<ConnectionManager<SqliteConnection>>,
+) -> Result<DbConnection, StorageError> {
+    pool.get()
+        .map_err(|e| StorageError::PoolError(e.to_string()))
+}
+
+fn poisoned_pool() -> StorageError {
+    StorageError::PoolError("the connection pool lock is poisoned".to_string())
+}
+
+/// Make a rename within the directory of `path` durable
+fn sync_parent_dir(path: &str) -> Result<(), StorageError> {
+    let dir = match Path::new(path).parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    // Directories can't be opened as files on Windows, where renames are durable once they return
+    if cfg!(unix) {
+        fs::File::open(dir)
+            .and_then(|dir| dir.sync_all())
+            .map_err(|e| StorageError::Store(e.to_string()))?;
+    }
+    Ok(())
+}
+
 #[derive(Default, Clone, Debug)]
 pub enum StorageOption {
     #[default]
@@ -52,7 +148,8 @@ pub enum StorageOption {
 /// Manages a Sqlite db for persisting messages and other objects.
 pub struct EncryptedMessageStore {
     connect_opt: StorageOption,
-    pool: Pool<ConnectionManager<SqliteConnection>>,
+    // Shared with every clone, so that they all use the new key once the database is rekeyed
+    pool: Arc<RwLock<Pool<ConnectionManager<SqliteConnection>>>>,
 }
 
 impl Errorer for EncryptedMessageStore {
@@ -80,44 +177,125 @@ impl EncryptedMessageStore {
         opts: StorageOption,
         enc_key: Option<EncryptionKey>,
     ) -> Result<Self, StorageError> {
-        let pool = match opts {
-            StorageOption::Ephemeral => Pool::builder()
-                .max_size(1)
-                .build(ConnectionManager::<SqliteConnection>::new(":memory:"))
-                .map_err(|e| StorageError::DbInitError(e.to_string()))?,
-            StorageOption::Persistent(ref path) => Pool::builder()
-                .max_size(10)
-                .build(ConnectionManager::<SqliteConnection>::new(path))
-                .map_err(|e| StorageError::DbInitError(e.to_string()))?,
-        };
-
-        // // Setup SqlCipherKey
-        if let Some(key) = enc_key {
-            Self::set_sqlcipher_key(pool.clone(), &key)?;
-        }
-
-        // TODO: Validate that sqlite is correctly configured. Bad EncKey is not detected until the migrations run which returns an
-        // unhelpful error.
+        let pool = Self::build_pool(&opts, enc_key)?;
+        Self::validate_key(&mut pool_conn(&pool)?, enc_key.is_some())?;
 
         let mut obj = Self {
             connect_opt: opts,
-            pool,
+            pool: Arc::new(RwLock::new(pool)),
         };
 
         obj.init_db()?;
         Ok(obj)
     }
 
+    fn build_pool(
+        opts: &StorageOption,
+        enc_key: Option<EncryptionKey>,
+    ) -> Result<Pool<ConnectionManager<SqliteConnection>>, StorageError> {
+        let (builder, path) = match opts {
+            StorageOption::Ephemeral => (Pool::builder().max_size(1), ":memory:"),
+            StorageOption::Persistent(path) => (Pool::builder().max_size(10), path.as_str()),
+        };
+        // Every connection has to be keyed, not just the first one
+        let builder = match enc_key {
+            Some(key) => builder.connection_customizer(Box::new(SqlCipherKey(key))),
+            None => builder,
+        };
+
+        builder
+            .build(ConnectionManager::<SqliteConnection>::new(path))
+            .map_err(|e| StorageError::DbInitError(e.to_string()))
+    }
+
+    /// A wrong key is only noticed once the database is read, which would otherwise be inside
+    /// the migrations with an unhelpful error
+    fn validate_key(conn: &mut DbConnection, encrypted: bool) -> Result<(), StorageError> {
+        conn.batch_execute("SELECT count(*) FROM sqlite_master;")
+            .map_err(|e| {
+                if encrypted {
+                    StorageError::WrongEncryptionKey
+                } else {
+                    StorageError::DbInitError(e.to_string())
+                }
+            })
+    }
+
+    /// Re-encrypt the database with `new_key`. Connections are opened again with the new key,
+    /// so this fails if any connection of this store or its clones is in use. No connection can
+    /// be taken until the database has been rekeyed. An unencrypted database is encrypted.
+    ///
+    /// The database is copied into a new file encrypted with `new_key`, which then atomically
+    /// replaces the original. If the process stops part way through, the original database is
+    /// left as it was. Callers should keep the old key until this returns, and fall back to it if
+    /// opening with the new key fails with `StorageError::WrongEncryptionKey`.
+    pub fn rekey(&self, new_key: EncryptionKey) -> Result<(), StorageError> {
+        let path = match &self.connect_opt {
+            StorageOption::Persistent(path) => path.clone(),
+            // In-memory databases are never written to disk, so there is nothing to re-encrypt
+            StorageOption::Ephemeral => return Ok(()),
+        };
+        let mut pool = self.pool.write().map_err(|_| poisoned_pool())?;
+        let state = pool.state();
+        if state.connections != state.idle_connections {
+            return Err(StorageError::Store(
+                "cannot rekey while connections are in use".to_string(),
+            ));
+        }
+
+        let rekeyed_path = format!("{}.rekey", path);
+        // Left behind by an attempt which was interrupted
+        for stale in [rekeyed_path.clone(), format!("{}-journal", rekeyed_path)] {
+            if Path::new(&stale).exists() {
+                fs::remove_file(&stale).map_err(|e| StorageError::Store(e.to_string()))?;
+            }
+        }
+
+        {
+            let conn = &mut pool_conn(&pool)?;
+            conn.batch_execute(&format!(
+                "ATTACH DATABASE '{}' AS rekeyed KEY \"x'{}'\";",
+                rekeyed_path.replace('\'', "''"),
+                hex::encode(new_key)
+            ))?;
+            let exported = conn.batch_execute("SELECT sqlcipher_export('rekeyed');");
+            conn.batch_execute("DETACH DATABASE rekeyed;")?;
+            exported?;
+        }
+
+        fs::rename(&rekeyed_path, &path).map_err(|e| StorageError::Store(e.to_string()))?;
+        sync_parent_dir(&path)?;
+        // The old connections still refer to the replaced file
+        let rekeyed_pool = Self::build_pool(&self.connect_opt, Some(new_key))?;
+        Self::validate_key(&mut pool_conn(&rekeyed_pool)?, true)?;
+        *pool = rekeyed_pool;
+
+        Ok(())
+    }
+
     fn init_db(&mut self) -> Result<(), StorageError> {
         let conn = &mut self.conn()?;
 
-        conn.run_pending_migrations(MIGRATIONS)
+        let applied = conn
+            .run_pending_migrations(MIGRATIONS)
             .map_err(|e| StorageError::DbInitError(e.to_string()))?;
+        let was_applied = |migration: &str| {
+            applied
+                .iter()
+                .any(|version| version.to_string() == migration)
+        };
+        // Messages stored before the search index existed are indexed from their content
+        if was_applied(SEARCH_INDEX_MIGRATION) {
+            Self::reindex_messages(conn)?;
+        }
+        if was_applied(CONTENT_TYPE_MIGRATION) {
+            Self::backfill_content_types(conn)?;
+        }
 
         Ok(())
     }
 
-    pub fn create_fake_msg(&self, content: &str, state: i32) {
+    pub fn create_fake_msg(&self, content: &str, state: MessageState) {
         NewStoredMessage::new("convo".into(), "addr".into(), content.into(), state, 10)
             .store(&mut self.conn().unwrap())
             .unwrap();
@@ -126,27 +304,9 @@ impl EncryptedMessageStore {
     pub fn conn(
         &self,
     ) -> Result<PooledConnection<ConnectionManager<SqliteConnection>>, StorageError> {
-        let conn = self
-            .pool
-            .get()
-            .map_err(|e| StorageError::PoolError(e.to_string()))?;
-
-        Ok(conn)
-    }
-
-    fn set_sqlcipher_key(
-        pool: Pool<ConnectionManager<SqliteConnection>>,
-        encryption_key: &[u8; 32],
-    ) -> Result<(), StorageError> {
-        let conn = &mut pool
-            .get()
-            .map_err(|e| StorageError::PoolError(e.to_string()))?;
-
-        conn.batch_execute(&format!(
-            "PRAGMA key = \"x'{}'\";",
-            hex::encode(encryption_key)
-        ))?;
-        Ok(())
+        // Held while the connection is taken, so that it can't come from a pool being replaced
+        let pool = self.pool.read().map_err(|_| poisoned_pool())?;
+        pool_conn(&pool)
     }
 
     pub fn generate_enc_key() -> EncryptionKey {
@@ -164,6 +324,46 @@ impl EncryptedMessageStore {
         Ok(account_list.pop())
     }
 
+    /// Overwrite the persisted account with the current state of the in-memory account, which
+    /// changes whenever keys are generated or consumed
+    pub fn update_account(
+        &self,
+        conn: &mut DbConnection,
+        account: &Account,
+    ) -> Result<(), StorageError> {
+        use self::schema::accounts::dsl::*;
+
+        let updated = NewStoredAccount::try_from(account)?;
+        // A store only ever holds the account of the installation it belongs to
+        diesel::update(accounts)
+            .set(serialized_key.eq(updated.serialized_key))
+            .execute(conn)?;
+
+        Ok(())
+    }
+
+    /// Insert a new inbound session together with the account that created it. Creating the
+    /// session can consume a one-time key, so both are written in one transaction to ensure the
+    /// persisted account and sessions never diverge.
+    /// Returns false if a session with the same id already exists, in which case it is kept as is.
+    pub fn insert_session_with_account(
+        &self,
+        conn: &mut DbConnection,
+        session: StoredSession,
+        account: &Account,
+    ) -> Result<bool, StorageError> {
+        conn.transaction(|transaction_manager| {
+            let inserted = diesel::insert_into(schema::sessions::table)
+                .values(&session)
+                .on_conflict(schema::sessions::session_id)
+                .do_nothing()
+                .execute(transaction_manager)?;
+            self.update_account(transaction_manager, account)?;
+
+            Ok(inserted == 1)
+        })
+    }
+
     pub fn get_latest_session_for_installation(
         &self,
         installation_id: &str,
@@ -217,12 +417,21 @@ impl EncryptedMessageStore {
           FROM 
             sessions 
           WHERE 
-            user_address = ?
+            user_address = ? 
+            AND peer_installation_id NOT IN (
+              SELECT 
+                installation_id 
+              FROM 
+                installations 
+              WHERE 
+                expires_at_ns <= ?
+            )
         ) AS ids 
         LEFT JOIN sessions ON ids.session_id = sessions.session_id
       ",
         )
         .bind::<Text, _>(user_address)
+        .bind::<BigInt, _>(now())
         .load::<StoredSession>(conn)
         .map_err(|e| StorageError::Unknown(e.to_string()))?;
 
@@ -300,7 +509,14 @@ impl EncryptedMessageStore {
         convo_id: &str,
     ) -> Result<Option<StoredConversation>, StorageError> {
         let conn = &mut self.conn()?;
+        self.get_conversation_with_conn(conn, convo_id)
+    }
 
+    pub fn get_conversation_with_conn(
+        &self,
+        conn: &mut DbConnection,
+        convo_id: &str,
+    ) -> Result<Option<StoredConversation>, StorageError> {
         let mut convo_list = conversations::table
             .find(convo_id)
             .load::<StoredConversation>(conn)?;
@@ -328,6 +544,51 @@ impl EncryptedMessageStore {
         Ok(())
     }
 
+    /// Replace the member list of a conversation, keeping the time existing members were added
+    pub fn set_conversation_members(
+        &self,
+        conn: &mut DbConnection,
+        convo_id: &str,
+        members: &[String],
+    ) -> Result<(), StorageError> {
+        use self::schema::conversation_members::dsl;
+
+        diesel::delete(dsl::conversation_members)
+            .filter(dsl::convo_id.eq(convo_id))
+            .filter(dsl::user_address.ne_all(members))
+            .execute(conn)?;
+        let added_at_ns = now();
+        let new_members: Vec<StoredConversationMember> = members
+            .iter()
+            .map(|member| StoredConversationMember {
+                convo_id: convo_id.to_string(),
+                user_address: member.clone(),
+                added_at_ns,
+            })
+            .collect();
+        if !new_members.is_empty() {
+            diesel::insert_or_ignore_into(conversation_members::table)
+                .values(new_members)
+                .execute(conn)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_conversation_members(
+        &self,
+        conn: &mut DbConnection,
+        convo_id: &str,
+    ) -> Result<Vec<String>, StorageError> {
+        use self::schema::conversation_members::dsl;
+
+        Ok(dsl::conversation_members
+            .filter(dsl::convo_id.eq(convo_id))
+            .order(dsl::added_at_ns.asc())
+            .select(dsl::user_address)
+            .load::<String>(conn)?)
+    }
+
     pub fn get_contacts(
         &self,
         user_address: &str,
@@ -348,7 +609,132 @@ impl EncryptedMessageStore {
         let conn = &mut self.conn()?;
 
         let msg_list = messages::table
-            .filter(messages::state.eq(MessageState::Unprocessed as i32))
+            .filter(messages::state.eq(MessageState::Unprocessed))
+            .load::<StoredMessage>(conn)?;
+
+        Ok(msg_list)
+    }
+
+    /// Schedule another attempt at sending a message which failed with a retryable error
+    pub fn set_message_retry(
+        &self,
+        conn: &mut DbConnection,
+        message_id: i32,
+        send_attempts: i32,
+        next_attempt_ns: i64,
+        error_reason: &str,
+    ) -> Result<(), StorageError> {
+        diesel::update(messages::table.find(message_id))
+            .set((
+                messages::send_attempts.eq(send_attempts),
+                messages::next_attempt_ns.eq(next_attempt_ns),
+                messages::error_reason.eq(error_reason),
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    }
+
+    /// Stop attempting to send a message
+    pub fn set_message_failed(
+        &self,
+        conn: &mut DbConnection,
+        message_id: i32,
+        send_attempts: i32,
+        error_reason: &str,
+    ) -> Result<(), StorageError> {
+        diesel::update(messages::table.find(message_id))
+            .set((
+                messages::state.eq(MessageState::Failed),
+                messages::send_attempts.eq(send_attempts),
+                messages::error_reason.eq(error_reason),
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    }
+
+    pub fn get_failed_messages(
+        &self,
+        conn: &mut DbConnection,
+    ) -> Result<Vec<StoredMessage>, StorageError> {
+        Ok(messages::table
+            .filter(messages::state.eq(MessageState::Failed))
+            .order(messages::created_at.asc())
+            .load::<StoredMessage>(conn)?)
+    }
+
+    /// Return a failed message to the outbound queue with a fresh set of attempts. Returns false
+    /// if there is no failed message with the id.
+    pub fn reset_failed_message(
+        &self,
+        conn: &mut DbConnection,
+        message_id: i32,
+    ) -> Result<bool, StorageError> {
+        let updated = diesel::update(
+            messages::table
+                .find(message_id)
+                .filter(messages::state.eq(MessageState::Failed)),
+        )
+        .set((
+            messages::state.eq(MessageState::Unprocessed),
+            messages::send_attempts.eq(0),
+            messages::next_attempt_ns.eq(0),
+            messages::error_reason.eq(None::<String>),
+        ))
+        .execute(conn)?;
+
+        Ok(updated > 0)
+    }
+
+    /// Returns false if there is no failed message with the id
+    pub fn delete_failed_message(
+        &self,
+        conn: &mut DbConnection,
+        message_id: i32,
+    ) -> Result<bool, StorageError> {
+        let deleted = diesel::delete(
+            messages::table
+                .find(message_id)
+                .filter(messages::state.eq(MessageState::Failed)),
+        )
+        .execute(conn)?;
+
+        Ok(deleted > 0)
+    }
+
+    /// Returns the id of the most recently inserted message, or 0 if the table is empty
+    pub fn get_latest_message_id(&self, conn: &mut DbConnection) -> Result<i32, StorageError> {
+        use self::schema::messages::dsl as schema;
+
+        let latest_id: Option<i32> = schema::messages
+            .select(diesel::dsl::max(schema::id))
+            .first(conn)?;
+
+        Ok(latest_id.unwrap_or(0))
+    }
+
+    /// Received messages decrypted from any of the given inbound messages, skipping receipts and
+    /// those with an id of `after_id` or lower
+    pub fn get_received_messages_from_inbound(
+        &self,
+        conn: &mut DbConnection,
+        inbound_message_ids: &[String],
+        after_id: i32,
+    ) -> Result<Vec<StoredMessage>, StorageError> {
+        use self::schema::inbound_messages::dsl as inbound_schema;
+        use self::schema::messages::dsl as schema;
+
+        let message_ids: Vec<String> = inbound_schema::inbound_messages
+            .filter(inbound_schema::id.eq_any(inbound_message_ids))
+            .select(inbound_schema::message_id)
+            .load(conn)?;
+        let msg_list = schema::messages
+            .filter(schema::message_id.eq_any(message_ids))
+            .filter(schema::id.gt(after_id))
+            .filter(schema::state.eq(MessageState::Received))
+            .filter(schema::content_type.ne_all(receipt_content_types()))
+            .order(schema::id.asc())
             .load::<StoredMessage>(conn)?;
 
         Ok(msg_list)
@@ -392,7 +778,7 @@ impl EncryptedMessageStore {
         use self::schema::inbound_invites::dsl;
 
         let invites = dsl::inbound_invites
-            .filter(dsl::status.eq(status as i16))
+            .filter(dsl::status.eq(status))
             .order(dsl::sent_at_ns.asc())
             .load::<InboundInvite>(conn)?;
 
@@ -431,7 +817,7 @@ impl EncryptedMessageStore {
 
         diesel::update(dsl::inbound_invites)
             .filter(dsl::id.eq(id))
-            .set(dsl::status.eq(status as i16))
+            .set(dsl::status.eq(status))
             .get_result::<InboundInvite>(conn)?;
 
         Ok(())
@@ -445,7 +831,7 @@ impl EncryptedMessageStore {
         use self::schema::inbound_messages::dsl as schema;
 
         let msgs = schema::inbound_messages
-            .filter(schema::status.eq(status as i16))
+            .filter(schema::status.eq(status))
             .order(schema::sent_at_ns.asc())
             .load::<InboundMessage>(conn)?;
 
@@ -455,7 +841,7 @@ impl EncryptedMessageStore {
         &self,
         conn: &mut PooledConnection<ConnectionManager<SqliteConnection>>,
         message: InboundMessage,
-    ) -> Result<(), StorageError> {
+    ) -> Result<bool, StorageError> {
         use self::schema::inbound_messages::dsl as schema;
         let mesg_id = message.id.clone();
         let result = diesel::insert_into(schema::inbound_messages)
@@ -466,13 +852,14 @@ impl EncryptedMessageStore {
             use diesel::result as dr;
             match &e {
                 dr::Error::DatabaseError(dr::DatabaseErrorKind::UniqueViolation, _) => {
-                    warn!("This message has already been stored: {}", mesg_id)
+                    warn!("This message has already been stored: {}", mesg_id);
+                    return Ok(false);
                 }
                 _ => return Err(StorageError::from(e)),
             }
         }
 
-        Ok(())
+        Ok(true)
     }
 
     pub fn set_msg_status(
@@ -485,7 +872,43 @@ impl EncryptedMessageStore {
 
         diesel::update(schema::inbound_messages)
             .filter(schema::id.eq(id))
-            .set(schema::status.eq(status as i16))
+            .set(schema::status.eq(status))
+            .execute(conn)?;
+
+        Ok(())
+    }
+
+    pub fn set_msg_invalid(
+        &self,
+        conn: &mut DbConnection,
+        id: String,
+        reason: InvalidMessageReason,
+    ) -> Result<(), StorageError> {
+        use self::schema::inbound_messages::dsl as schema;
+
+        diesel::update(schema::inbound_messages)
+            .filter(schema::id.eq(id))
+            .set((
+                schema::status.eq(InboundMessageStatus::Invalid),
+                schema::status_reason.eq(reason),
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    }
+
+    /// Record the message an inbound payload decrypted to
+    pub fn link_inbound_message(
+        &self,
+        conn: &mut DbConnection,
+        id: &str,
+        message_id: &str,
+    ) -> Result<(), StorageError> {
+        use self::schema::inbound_messages::dsl as schema;
+
+        diesel::update(schema::inbound_messages)
+            .filter(schema::id.eq(id))
+            .set(schema::message_id.eq(message_id))
             .execute(conn)?;
 
         Ok(())
@@ -502,6 +925,46 @@ impl EncryptedMessageStore {
         Ok(())
     }
 
+    /// Replace the stored contact bundle of an installation, e.g. after a fallback key rotation
+    pub fn update_installation_contact(
+        &self,
+        conn: &mut DbConnection,
+        installation_id: &str,
+        contact: Vec<u8>,
+    ) -> Result<(), StorageError> {
+        use self::schema::installations::dsl;
+
+        diesel::update(dsl::installations.filter(dsl::installation_id.eq(installation_id)))
+            .set(dsl::contact.eq(contact))
+            .execute(conn)?;
+
+        Ok(())
+    }
+
+    /// Expire an installation at `expires_at_ns`, unless it is already set to expire earlier
+    pub fn set_installation_expiry(
+        &self,
+        conn: &mut DbConnection,
+        installation_id: &str,
+        expires_at_ns: i64,
+    ) -> Result<(), StorageError> {
+        use self::schema::installations::dsl;
+
+        diesel::update(
+            dsl::installations
+                .filter(dsl::installation_id.eq(installation_id))
+                .filter(
+                    dsl::expires_at_ns
+                        .is_null()
+                        .or(dsl::expires_at_ns.gt(expires_at_ns)),
+                ),
+        )
+        .set(dsl::expires_at_ns.eq(expires_at_ns))
+        .execute(conn)?;
+
+        Ok(())
+    }
+
     pub fn insert_or_ignore_session(
         &self,
         session: StoredSession,
@@ -513,15 +976,117 @@ impl EncryptedMessageStore {
         Ok(())
     }
 
+    /// Returns false if a message with the same `message_id` is already stored
     pub fn insert_or_ignore_message(
         &self,
         conn: &mut PooledConnection<ConnectionManager<SqliteConnection>>,
         msg: NewStoredMessage,
-    ) -> Result<(), StorageError> {
-        diesel::insert_or_ignore_into(schema::messages::table)
-            .values(msg)
-            .execute(conn)?;
-        Ok(())
+    ) -> Result<bool, StorageError> {
+        // Nothing is returned when the message is ignored
+        let inserted: Vec<i32> = diesel::insert_or_ignore_into(schema::messages::table)
+            .values(&msg)
+            .returning(schema::messages::id)
+            .get_results(conn)?;
+        match inserted.first() {
+            Some(id) => {
+                index_message(conn, *id, &msg.content)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Rebuild the search index from the content of every message
+    fn reindex_messages(conn: &mut DbConnection) -> Result<(), StorageError> {
+        conn.transaction::<_, StorageError, _>(|conn| {
+            sql_query("DELETE FROM messages_fts").execute(conn)?;
+            let messages: Vec<(i32, Vec<u8>)> = messages::table
+                .select((messages::id, messages::content))
+                .load(conn)?;
+            for (id, content) in messages {
+                index_message(conn, id, &content)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Fill in the content type of every message from its content
+    fn backfill_content_types(conn: &mut DbConnection) -> Result<(), StorageError> {
+        conn.transaction::<_, StorageError, _>(|conn| {
+            let messages: Vec<(i32, Vec<u8>)> = messages::table
+                .select((messages::id, messages::content))
+                .load(conn)?;
+            for (id, content) in messages {
+                diesel::update(messages::table.find(id))
+                    .set(messages::content_type.eq(stored_content_type(&content)))
+                    .execute(conn)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Received and sent messages whose text contains words starting with every word of `query`,
+    /// best matches first, along with their bm25 rank where lower is better
+    pub fn search_messages(
+        &self,
+        conn: &mut DbConnection,
+        query: &str,
+        opts: &SearchMessagesOptions,
+    ) -> Result<Vec<(StoredMessage, f64)>, StorageError> {
+        let query = match fts_query(query) {
+            Some(query) => query,
+            None => return Ok(vec![]),
+        };
+        let mut search = sql_query(
+            "SELECT messages_fts.rowid AS id, messages_fts.rank AS rank FROM messages_fts \
+             JOIN messages ON messages.id = messages_fts.rowid \
+             WHERE messages_fts MATCH ? AND messages.state IN (?, ?) \
+             AND messages.sent_at_ns >= ? AND messages.sent_at_ns <= ?",
+        )
+        .into_boxed()
+        .bind::<Text, _>(query)
+        .bind::<Integer, _>(MessageState::Received)
+        .bind::<Integer, _>(MessageState::LocallyCommitted)
+        .bind::<BigInt, _>(opts.start_time_ns.unwrap_or(i64::MIN))
+        .bind::<BigInt, _>(opts.end_time_ns.unwrap_or(i64::MAX));
+        if let Some(sender_addresses) = &opts.sender_addresses {
+            // Matches nothing, like the same filter in `get_stored_messages`
+            if sender_addresses.is_empty() {
+                return Ok(vec![]);
+            }
+            let placeholders = vec!["?"; sender_addresses.len()].join(", ");
+            search = search.sql(format!(" AND messages.addr_from IN ({placeholders})"));
+            for sender_address in sender_addresses {
+                search = search.bind::<Text, _>(sender_address.clone());
+            }
+        }
+        let hits: Vec<SearchHitRow> = search
+            .sql(" ORDER BY rank LIMIT ?")
+            // A negative limit is no limit in sqlite
+            .bind::<BigInt, _>(opts.limit.unwrap_or(-1))
+            .load(conn)?;
+
+        let mut messages: HashMap<i32, StoredMessage> = messages::table
+            .filter(messages::id.eq_any(hits.iter().map(|hit| hit.id)))
+            .load::<StoredMessage>(conn)?
+            .into_iter()
+            .map(|message| (message.id, message))
+            .collect();
+        Ok(hits
+            .into_iter()
+            .filter_map(|hit| Some((messages.remove(&hit.id)?, hit.rank)))
+            .collect())
+    }
+
+    pub fn get_message_by_message_id(
+        &self,
+        conn: &mut DbConnection,
+        message_id: &str,
+    ) -> Result<Option<StoredMessage>, StorageError> {
+        Ok(schema::messages::table
+            .filter(schema::messages::message_id.eq(message_id))
+            .first(conn)
+            .optional()?)
     }
 
     pub fn commit_outbound_payloads_for_message(
@@ -531,6 +1096,21 @@ impl EncryptedMessageStore {
         new_outbound_payloads: Vec<StoredOutboundPayload>,
         updated_sessions: Vec<StoredSession>,
         conn: &mut PooledConnection<ConnectionManager<SqliteConnection>>,
+    ) -> Result<(), StorageError> {
+        self.commit_outbound_payloads(new_outbound_payloads, updated_sessions, conn)?;
+        diesel::update(messages::table.find(message_id))
+            .set(messages::state.eq(updated_message_state))
+            .get_result::<StoredMessage>(conn)?;
+        Ok(())
+    }
+
+    /// Store payloads along with the sessions that encrypted them, without changing the state of
+    /// the messages they were built from
+    pub fn commit_outbound_payloads(
+        &self,
+        new_outbound_payloads: Vec<StoredOutboundPayload>,
+        updated_sessions: Vec<StoredSession>,
+        conn: &mut DbConnection,
     ) -> Result<(), StorageError> {
         for session in updated_sessions {
             diesel::update(schema::sessions::table.find(session.session_id))
@@ -540,42 +1120,138 @@ impl EncryptedMessageStore {
         diesel::insert_into(schema::outbound_payloads::table)
             .values(new_outbound_payloads)
             .execute(conn)?;
-        diesel::update(messages::table.find(message_id))
-            .set(messages::state.eq(updated_message_state as i32))
-            .get_result::<StoredMessage>(conn)?;
         Ok(())
     }
 
+    /// Lease up to `limit` of the oldest payloads in `payload_state` for `lock_duration_ns`,
+    /// counting an attempt for each. Payloads whose lease has expired, for example because the
+    /// process died while publishing them, are leased again.
     pub fn fetch_and_lock_outbound_payloads(
         &self,
         payload_state: OutboundPayloadState,
         lock_duration_ns: i64,
+        limit: i64,
     ) -> Result<Vec<StoredOutboundPayload>, StorageError> {
         let conn = &mut self.conn()?;
         use self::schema::outbound_payloads::dsl as schema;
         let now = now();
-        // Must happen atomically
-        let payloads = diesel::update(schema::outbound_payloads)
-            .filter(schema::outbound_payload_state.eq(payload_state as i32))
-            .filter(schema::locked_until_ns.lt(now))
-            .set(schema::locked_until_ns.eq(now + lock_duration_ns))
-            .get_results::<StoredOutboundPayload>(conn)?;
-        Ok(payloads)
+        // Must happen atomically, so that no two workers lease the same payload
+        conn.immediate_transaction(|conn| {
+            let payload_ids: Vec<String> = schema::outbound_payloads
+                .filter(schema::outbound_payload_state.eq(payload_state))
+                .filter(schema::locked_until_ns.lt(now))
+                .order(schema::created_at_ns.asc())
+                .limit(limit)
+                .select(schema::payload_id)
+                .load(conn)?;
+
+            let mut payloads = diesel::update(schema::outbound_payloads)
+                .filter(schema::payload_id.eq_any(payload_ids))
+                .set((
+                    schema::locked_until_ns.eq(now + lock_duration_ns),
+                    schema::publish_attempts.eq(schema::publish_attempts + 1),
+                ))
+                .get_results::<StoredOutboundPayload>(conn)?;
+            payloads.sort_by_key(|payload| payload.created_at_ns);
+
+            Ok(payloads)
+        })
     }
 
     pub fn update_and_unlock_outbound_payloads(
         &self,
-        payload_ids: Vec<i64>,
+        payload_ids: Vec<String>,
         new_payload_state: OutboundPayloadState,
     ) -> Result<(), StorageError> {
         let conn = &mut self.conn()?;
         use self::schema::outbound_payloads::dsl::*;
-        diesel::update(outbound_payloads)
-            .filter(created_at_ns.eq_any(payload_ids))
-            .set((
-                outbound_payload_state.eq(new_payload_state as i32),
-                locked_until_ns.eq(0),
-            ))
+        let published = matches!(new_payload_state, OutboundPayloadState::ServerAcknowledged);
+        conn.transaction(|conn| {
+            diesel::update(outbound_payloads)
+                .filter(payload_id.eq_any(&payload_ids))
+                .set((
+                    outbound_payload_state.eq(new_payload_state),
+                    locked_until_ns.eq(0),
+                ))
+                .execute(conn)?;
+            if published {
+                diesel::update(outbound_payloads)
+                    .filter(payload_id.eq_any(&payload_ids))
+                    .filter(delivery_status.lt(DeliveryStatus::Published))
+                    .set(delivery_status.eq(DeliveryStatus::Published))
+                    .execute(conn)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Move the payloads of `message_ids` sent to `recipient_installation_id` forward to `status`.
+    /// Payloads which are already further along are left alone.
+    pub fn advance_delivery_status(
+        &self,
+        conn: &mut DbConnection,
+        recipient_installation_id: &str,
+        message_ids: Vec<String>,
+        status: DeliveryStatus,
+    ) -> Result<(), StorageError> {
+        use self::schema::outbound_payloads::dsl;
+        diesel::update(dsl::outbound_payloads)
+            .filter(dsl::recipient_installation_id.eq(recipient_installation_id))
+            .filter(dsl::message_id.eq_any(message_ids))
+            .filter(dsl::delivery_status.lt(status))
+            .set(dsl::delivery_status.eq(status))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    /// Mark every message in the conversation sent up to `sent_at_ns` as read by
+    /// `recipient_installation_id`
+    pub fn mark_read_up_to(
+        &self,
+        conn: &mut DbConnection,
+        recipient_installation_id: &str,
+        convo_id: &str,
+        sent_at_ns: i64,
+    ) -> Result<(), StorageError> {
+        let message_ids = messages::table
+            .filter(messages::convo_id.eq(convo_id))
+            .filter(messages::sent_at_ns.le(sent_at_ns))
+            .select(messages::message_id);
+        use self::schema::outbound_payloads::dsl;
+        diesel::update(dsl::outbound_payloads)
+            .filter(dsl::recipient_installation_id.eq(recipient_installation_id))
+            .filter(dsl::message_id.eq_any(message_ids))
+            .filter(dsl::delivery_status.lt(DeliveryStatus::Read))
+            .set(dsl::delivery_status.eq(DeliveryStatus::Read))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    /// The payloads which carried `message_id`, one for each recipient installation
+    pub fn get_message_payloads(
+        &self,
+        conn: &mut DbConnection,
+        message_id: &str,
+    ) -> Result<Vec<StoredOutboundPayload>, StorageError> {
+        use self::schema::outbound_payloads::dsl;
+        Ok(dsl::outbound_payloads
+            .filter(dsl::message_id.eq(message_id))
+            .order(dsl::created_at_ns.asc())
+            .load(conn)?)
+    }
+
+    /// Shorten the lease on payloads which could not be published, so that they are retried
+    /// from `retry_at_ns`
+    pub fn release_outbound_payloads(
+        &self,
+        payload_ids: Vec<String>,
+        retry_at_ns: i64,
+    ) -> Result<(), StorageError> {
+        let conn = &mut self.conn()?;
+        use self::schema::outbound_payloads::dsl as schema;
+        diesel::update(schema::outbound_payloads)
+            .filter(schema::payload_id.eq_any(payload_ids))
+            .set(schema::locked_until_ns.eq(retry_at_ns))
             .execute(conn)?;
         Ok(())
     }
@@ -589,56 +1265,190 @@ impl EncryptedMessageStore {
         use self::schema::conversations::dsl;
         diesel::update(dsl::conversations)
             .filter(dsl::convo_id.eq(convo_id))
-            .set(dsl::convo_state.eq(state as i32))
+            .set(dsl::convo_state.eq(state))
             .get_result::<StoredConversation>(conn)?;
         Ok(())
     }
 
+    pub fn set_consent_state(
+        &self,
+        conn: &mut DbConnection,
+        convo_id: &str,
+        state: ConsentState,
+    ) -> Result<(), StorageError> {
+        use self::schema::conversations::dsl;
+        diesel::update(dsl::conversations)
+            .filter(dsl::convo_id.eq(convo_id))
+            .set(dsl::consent_state.eq(state))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub fn set_conversation_retention(
+        &self,
+        conn: &mut DbConnection,
+        convo_id: &str,
+        retention_ns: Option<i64>,
+    ) -> Result<(), StorageError> {
+        use self::schema::conversations::dsl;
+        diesel::update(dsl::conversations)
+            .filter(dsl::convo_id.eq(convo_id))
+            .set(dsl::retention_ns.eq(retention_ns))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    /// Delete the messages which have outlived their conversation's retention at `now_ns`,
+    /// along with the inbound payloads they were decrypted from. Messages still waiting to be
+    /// sent are kept. Returns the number of messages deleted.
+    pub fn delete_expired_messages(
+        &self,
+        conn: &mut DbConnection,
+        now_ns: i64,
+    ) -> Result<usize, StorageError> {
+        conn.transaction::<_, StorageError, _>(|conn| {
+            let retentions: Vec<(String, Option<i64>)> = conversations::table
+                .filter(conversations::retention_ns.is_not_null())
+                .select((conversations::convo_id, conversations::retention_ns))
+                .load(conn)?;
+
+            let mut deleted = 0;
+            for (convo_id, retention_ns) in retentions
+                .into_iter()
+                .filter_map(|(convo_id, retention_ns)| Some((convo_id, retention_ns?)))
+            {
+                let message_ids: Vec<String> = messages::table
+                    .filter(messages::convo_id.eq(&convo_id))
+                    .filter(messages::sent_at_ns.le(now_ns.saturating_sub(retention_ns)))
+                    .filter(messages::state.ne(MessageState::Unprocessed))
+                    .select(messages::message_id)
+                    .load(conn)?;
+
+                diesel::delete(
+                    inbound_messages::table
+                        .filter(inbound_messages::message_id.eq_any(&message_ids)),
+                )
+                .execute(conn)?;
+                deleted += diesel::delete(
+                    messages::table.filter(messages::message_id.eq_any(&message_ids)),
+                )
+                .execute(conn)?;
+            }
+            Ok(deleted)
+        })
+    }
+
+    pub fn sweep_expired_messages(&self, clock: &dyn Clock) -> Result<usize, StorageError> {
+        self.delete_expired_messages(&mut self.conn()?, clock.now_ns())
+    }
+
+    /// Delete expired messages every `interval`, yielding the number deleted by each sweep.
+    /// Sweeping stops when the stream is dropped.
+    pub fn stream_retention_sweeps<'a>(
+        &'a self,
+        clock: &'a dyn Clock,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<usize, StorageError>> + 'a {
+        stream::unfold(false, move |has_run| async move {
+            if has_run {
+                tokio::time::sleep(interval).await;
+            }
+            Some((self.sweep_expired_messages(clock), true))
+        })
+    }
+
+    /// Whether the user has blocked any of the conversations in `convo_ids`
+    pub fn any_conversation_denied(
+        &self,
+        conn: &mut DbConnection,
+        convo_ids: Vec<String>,
+    ) -> Result<bool, StorageError> {
+        let denied: i64 = conversations::table
+            .filter(conversations::convo_id.eq_any(convo_ids))
+            .filter(conversations::consent_state.eq(ConsentState::Denied))
+            .count()
+            .get_result(conn)?;
+        Ok(denied > 0)
+    }
+
     pub fn get_conversations(
         &self,
         conn: &mut DbConnection,
         allowed_states: Vec<ConversationState>,
     ) -> Result<Vec<StoredConversation>, StorageError> {
         let convos = conversations::table
-            .filter(conversations::convo_state.eq_any(allowed_states.into_iter().map(|s| s as i32)))
+            .filter(conversations::convo_state.eq_any(allowed_states))
             .load::<StoredConversation>(conn)?;
 
         Ok(convos)
     }
 
+    /// Messages in order of when they were sent, with messages sent at the same time in the order
+    /// they were stored. See `ListMessagesOptions` for paging through them.
     pub fn get_stored_messages(
         &self,
         conn: &mut DbConnection,
         allowed_states: Option<Vec<MessageState>>,
         conversation_id: Option<&str>,
-        start_time_ns: Option<i64>,
-        end_time_ns: Option<i64>,
-        limit: Option<i64>,
+        opts: &ListMessagesOptions,
     ) -> Result<Vec<StoredMessage>, StorageError> {
         use self::schema::messages::dsl as schema;
 
-        let mut query = schema::messages
-            .order(schema::sent_at_ns.asc())
-            .into_boxed();
+        let mut query = match opts.direction {
+            SortDirection::Ascending => schema::messages
+                .order((schema::sent_at_ns.asc(), schema::id.asc()))
+                .into_boxed(),
+            SortDirection::Descending => schema::messages
+                .order((schema::sent_at_ns.desc(), schema::id.desc()))
+                .into_boxed(),
+        };
 
         if let Some(allowed_states) = allowed_states {
-            query =
-                query.filter(schema::state.eq_any(allowed_states.into_iter().map(|s| s as i32)));
+            query = query.filter(schema::state.eq_any(allowed_states));
         }
 
         if let Some(conversation_id) = conversation_id {
             query = query.filter(schema::convo_id.eq(conversation_id));
         }
 
-        if let Some(start_time_ns) = start_time_ns {
+        if let Some(start_time_ns) = opts.start_time_ns {
             query = query.filter(schema::sent_at_ns.ge(start_time_ns));
         }
 
-        if let Some(end_time_ns) = end_time_ns {
+        if let Some(end_time_ns) = opts.end_time_ns {
             query = query.filter(schema::sent_at_ns.le(end_time_ns));
         }
 
-        if let Some(limit) = limit {
+        if let Some(cursor) = opts.cursor {
+            let same_time = schema::sent_at_ns.eq(cursor.sent_at_ns);
+            query = match opts.direction {
+                SortDirection::Ascending => query.filter(
+                    schema::sent_at_ns
+                        .gt(cursor.sent_at_ns)
+                        .or(same_time.and(schema::id.gt(cursor.id))),
+                ),
+                SortDirection::Descending => query.filter(
+                    schema::sent_at_ns
+                        .lt(cursor.sent_at_ns)
+                        .or(same_time.and(schema::id.lt(cursor.id))),
+                ),
+            };
+        }
+
+        if let Some(sender_addresses) = &opts.sender_addresses {
+            query = query.filter(schema::addr_from.eq_any(sender_addresses));
+        }
+
+        if let Some(content_types) = &opts.content_types {
+            query = query
+                .filter(schema::content_type.eq_any(content_types.iter().map(content_type_name)));
+        }
+
+        if !opts.include_receipts {
+            query = query.filter(schema::content_type.ne_all(receipt_content_types()));
+        }
+
+        if let Some(limit) = opts.limit {
             query = query.limit(limit);
         }
 
@@ -650,9 +1460,11 @@ impl EncryptedMessageStore {
 
 impl Store<DbConnection> for NewStoredMessage {
     fn store(&self, into: &mut DbConnection) -> Result<(), StorageError> {
-        diesel::insert_into(messages::table)
+        let id = diesel::insert_into(messages::table)
             .values(self)
-            .execute(into)?;
+            .returning(messages::id)
+            .get_result(into)?;
+        index_message(into, id, &self.content)?;
 
         Ok(())
     }
@@ -695,7 +1507,7 @@ impl Fetch<StoredMessage> for DbConnection {
 
         messages
             .load::<StoredMessage>(self)
-            .map_err(StorageError::DieselResultError)
+            .map_err(StorageError::from)
     }
 
     fn fetch_one(&mut self, key: i32) -> Result<Option<StoredMessage>, StorageError> where {
@@ -711,7 +1523,7 @@ impl Fetch<StoredSession> for DbConnection {
 
         sessions
             .load::<StoredSession>(self)
-            .map_err(StorageError::DieselResultError)
+            .map_err(StorageError::from)
     }
 
     fn fetch_one(&mut self, key: &str) -> Result<Option<StoredSession>, StorageError> {
@@ -727,7 +1539,7 @@ impl Fetch<InboundInvite> for DbConnection {
 
         inbound_invites
             .load::<InboundInvite>(self)
-            .map_err(StorageError::DieselResultError)
+            .map_err(StorageError::from)
     }
 
     fn fetch_one(&mut self, key: &str) -> Result<Option<InboundInvite>, StorageError> {
@@ -743,7 +1555,7 @@ impl Fetch<StoredUser> for DbConnection {
 
         dsl::users
             .load::<StoredUser>(self)
-            .map_err(StorageError::DieselResultError)
+            .map_err(StorageError::from)
     }
     fn fetch_one(&mut self, key: &str) -> Result<Option<StoredUser>, StorageError> {
         use self::schema::users::dsl::*;
@@ -758,7 +1570,7 @@ impl Fetch<StoredConversation> for DbConnection {
 
         dsl::conversations
             .load::<StoredConversation>(self)
-            .map_err(StorageError::DieselResultError)
+            .map_err(StorageError::from)
     }
     fn fetch_one(&mut self, key: &str) -> Result<Option<StoredConversation>, StorageError> {
         use self::schema::conversations::dsl::*;
@@ -766,6 +1578,21 @@ impl Fetch<StoredConversation> for DbConnection {
     }
 }
 
+impl Fetch<StoredOutboundPayload> for DbConnection {
+    type Key<'a> = &'a str;
+    fn fetch_all(&mut self) -> Result<Vec<StoredOutboundPayload>, StorageError> {
+        use self::schema::outbound_payloads::dsl;
+
+        dsl::outbound_payloads
+            .load::<StoredOutboundPayload>(self)
+            .map_err(StorageError::from)
+    }
+    fn fetch_one(&mut self, key: &str) -> Result<Option<StoredOutboundPayload>, StorageError> {
+        use self::schema::outbound_payloads::dsl::*;
+        Ok(outbound_payloads.find(key).first(self).optional()?)
+    }
+}
+
 impl Store<DbConnection> for Account {
     fn store(&self, into: &mut DbConnection) -> Result<(), StorageError> {
         diesel::insert_into(accounts::table)
@@ -822,7 +1649,7 @@ impl Fetch<StoredInstallation> for DbConnection {
 
         dsl::installations
             .load::<StoredInstallation>(self)
-            .map_err(StorageError::DieselResultError)
+            .map_err(StorageError::from)
     }
     fn fetch_one(&mut self, key: &str) -> Result<Option<StoredInstallation>, StorageError> {
         use self::schema::installations::dsl::*;
@@ -844,15 +1671,27 @@ fn warn_length<T>(list: &Vec<T>, str_id: &str, max_length: usize) {
 #[cfg(test)]
 mod tests {
 
-    use super::{models::*, EncryptedMessageStore, StorageError, StorageOption};
-    use crate::{Fetch, Store};
-    use diesel::Connection;
+    use super::{models::*, schema, EncryptedMessageStore, StorageError, StorageOption};
+    use crate::{
+        account::{tests::test_wallet_signer, Account},
+        codecs::{
+            reaction::{Reaction, ReactionAction, ReactionCodec, ReactionSchema},
+            text::TextCodec,
+            ContentCodec,
+        },
+        conversation::{ListMessagesOptions, MessageCursor, SearchMessagesOptions, SortDirection},
+        storage::{clock::ManualClock, UnknownEnumValue},
+        Fetch, Store,
+    };
+    use diesel::{sql_query, Connection, RunQueryDsl};
+    use prost::Message;
     use rand::{
         distributions::{Alphanumeric, DistString},
         Rng,
     };
+    use serde_json::json;
     use std::fs;
-    use std::{thread::sleep, time::Duration};
+    use std::{path::Path, thread::sleep, time::Duration};
 
     fn rand_string() -> String {
         Alphanumeric.sample_string(&mut rand::thread_rng(), 16)
@@ -874,7 +1713,7 @@ mod tests {
             "Bola".into(),
             "0x000A".into(),
             "Hello Bola".into(),
-            MessageState::Unprocessed as i32,
+            MessageState::Unprocessed,
             10,
         )
         .store(conn)
@@ -884,7 +1723,7 @@ mod tests {
             "Mark".into(),
             "0x000A".into(),
             "Sup Mark".into(),
-            MessageState::Unprocessed as i32,
+            MessageState::Unprocessed,
             10,
         )
         .store(conn)
@@ -894,7 +1733,7 @@ mod tests {
             "Bola".into(),
             "0x000B".into(),
             "Hey Amal".into(),
-            MessageState::Unprocessed as i32,
+            MessageState::Unprocessed,
             10,
         )
         .store(conn)
@@ -904,7 +1743,7 @@ mod tests {
             "Bola".into(),
             "0x000A".into(),
             "bye".into(),
-            MessageState::Unprocessed as i32,
+            MessageState::Unprocessed,
             10,
         )
         .store(conn)
@@ -914,6 +1753,54 @@ mod tests {
         assert_eq!(4, v.len());
     }
 
+    #[test]
+    fn unknown_enum_values_are_rejected() {
+        assert_eq!(MessageState::try_from(20).unwrap(), MessageState::Received);
+        assert_eq!(
+            InboundMessageStatus::try_from(3).unwrap(),
+            InboundMessageStatus::Invalid
+        );
+        assert!(ConversationState::try_from(5).is_err());
+        assert_eq!(ConsentState::try_from(20).unwrap(), ConsentState::Denied);
+        assert!(DeliveryStatus::try_from(15).is_err());
+        assert!(InvalidMessageReason::try_from(0).is_err());
+        assert!(serde_json::from_value::<MessageState>(json!(25)).is_err());
+
+        let store = EncryptedMessageStore::new(
+            StorageOption::Ephemeral,
+            EncryptedMessageStore::generate_enc_key(),
+        )
+        .unwrap();
+        let conn = &mut store.conn().unwrap();
+        NewStoredMessage::new(
+            "convo".into(),
+            "0x01".into(),
+            rand_vec(),
+            MessageState::Received,
+            10,
+        )
+        .store(conn)
+        .unwrap();
+        let messages = store
+            .get_stored_messages(conn, None, None, &ListMessagesOptions::default())
+            .unwrap();
+        assert_eq!(messages[0].state, MessageState::Received);
+
+        sql_query("UPDATE messages SET state = 25")
+            .execute(conn)
+            .unwrap();
+        let err = store
+            .get_stored_messages(conn, None, None, &ListMessagesOptions::default())
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            StorageError::UnknownEnumValue(UnknownEnumValue {
+                name: "MessageState",
+                value: 25
+            })
+        ));
+    }
+
     #[test]
     fn store_persistent() {
         let db_path = format!("{}.db3", rand_string());
@@ -929,7 +1816,7 @@ mod tests {
                 "Bola".into(),
                 "0x000A".into(),
                 "Hello Bola".into(),
-                MessageState::Unprocessed as i32,
+                MessageState::Unprocessed,
                 10,
             )
             .store(conn)
@@ -954,7 +1841,7 @@ mod tests {
             rand_string(),
             rand_string(),
             rand_vec(),
-            MessageState::Unprocessed as i32,
+            MessageState::Unprocessed,
             10,
         );
         sleep(Duration::from_millis(10));
@@ -962,7 +1849,7 @@ mod tests {
             rand_string(),
             rand_string(),
             rand_vec(),
-            MessageState::Unprocessed as i32,
+            MessageState::Unprocessed,
             10,
         );
 
@@ -997,7 +1884,7 @@ mod tests {
                 rand_string(),
                 rand_string(),
                 rand_vec(),
-                MessageState::Unprocessed as i32,
+                MessageState::Unprocessed,
                 10,
             );
             msg0.store(&mut store.conn().unwrap()).unwrap();
@@ -1007,12 +1894,85 @@ mod tests {
         let res = EncryptedMessageStore::new(StorageOption::Persistent(db_path.clone()), enc_key);
         // Ensure it fails
         match res.err() {
-            Some(StorageError::DbInitError(_)) => (),
-            _ => panic!("Expected a DbInitError"),
+            Some(StorageError::WrongEncryptionKey) => (),
+            _ => panic!("Expected a WrongEncryptionKey"),
+        }
+        fs::remove_file(db_path).unwrap();
+    }
+
+    #[test]
+    fn rekey() {
+        let old_key = EncryptedMessageStore::generate_enc_key();
+        let new_key = EncryptedMessageStore::generate_enc_key();
+        let db_path = format!("{}.db3", rand_string());
+        {
+            let store =
+                EncryptedMessageStore::new(StorageOption::Persistent(db_path.clone()), old_key)
+                    .unwrap();
+            store.create_fake_msg("Hello", MessageState::Received);
+
+            // Connections taken by a clone count as in use
+            let clone = store.clone();
+            let conn = clone.conn().unwrap();
+            assert!(store.rekey(new_key).is_err());
+            drop(conn);
+
+            store.rekey(new_key).unwrap();
+            let messages: Vec<StoredMessage> = store.conn().unwrap().fetch_all().unwrap();
+            assert_eq!(messages.len(), 1);
+            // Clones use the new key too
+            let messages: Vec<StoredMessage> = clone.conn().unwrap().fetch_all().unwrap();
+            assert_eq!(messages.len(), 1);
         }
+
+        let res = EncryptedMessageStore::new(StorageOption::Persistent(db_path.clone()), old_key);
+        assert!(matches!(res.err(), Some(StorageError::WrongEncryptionKey)));
+        let store = EncryptedMessageStore::new(StorageOption::Persistent(db_path.clone()), new_key)
+            .unwrap();
+        let messages: Vec<StoredMessage> = store.conn().unwrap().fetch_all().unwrap();
+        assert_eq!(messages.len(), 1);
+        assert!(!Path::new(&format!("{}.rekey", db_path)).exists());
         fs::remove_file(db_path).unwrap();
     }
 
+    #[test]
+    fn session_and_account_are_stored_together() {
+        let store = EncryptedMessageStore::new(
+            StorageOption::Ephemeral,
+            EncryptedMessageStore::generate_enc_key(),
+        )
+        .unwrap();
+        let account = Account::generate(test_wallet_signer).unwrap();
+        account.store(&mut store.conn().unwrap()).unwrap();
+        let session = StoredSession::new("A".into(), rand_string(), rand_vec(), rand_string());
+
+        account.generate_one_time_keys(1);
+        assert!(store
+            .insert_session_with_account(&mut store.conn().unwrap(), session.clone(), &account)
+            .unwrap());
+        let stored_account: Account = store.conn().unwrap().fetch_one(1).unwrap().unwrap();
+        assert_eq!(json!(stored_account), json!(account));
+
+        // A duplicate session keeps the existing one, but the account is still saved
+        let duplicate = StoredSession {
+            vmac_session_data: rand_vec(),
+            ..session.clone()
+        };
+        account.generate_one_time_keys(1);
+        assert!(!store
+            .insert_session_with_account(&mut store.conn().unwrap(), duplicate, &account)
+            .unwrap());
+        let stored_session: StoredSession = store
+            .conn()
+            .unwrap()
+            .fetch_one(session.session_id.as_str())
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored_session.vmac_session_data, session.vmac_session_data);
+        let stored_account: Account = store.conn().unwrap().fetch_one(1).unwrap().unwrap();
+        assert_eq!(json!(stored_account), json!(account));
+    }
+
     #[test]
     fn store_session() {
         let store = EncryptedMessageStore::new(
@@ -1109,14 +2069,14 @@ mod tests {
             id: "id_1".into(),
             payload: vec![1, 2, 3],
             topic: "topic".into(),
-            status: InboundInviteStatus::Pending as i16,
+            status: InboundInviteStatus::Pending,
         };
         let invite_2 = InboundInvite {
             sent_at_ns: 30,
             id: "id_2".into(),
             payload: vec![1, 2, 3, 4],
             topic: "topic".into(),
-            status: InboundInviteStatus::Pending as i16,
+            status: InboundInviteStatus::Pending,
         };
         store
             .save_inbound_invite(&mut store.conn().unwrap(), invite_1.clone())
@@ -1150,7 +2110,7 @@ mod tests {
             id: "id".into(),
             payload: vec![1, 2, 3],
             topic: "topic".into(),
-            status: InboundInviteStatus::Pending as i16,
+            status: InboundInviteStatus::Pending,
         };
 
         let conn = &mut store.conn().unwrap();
@@ -1188,13 +2148,19 @@ mod tests {
             convo_id: "convo_1".into(),
             peer_address: address.clone(),
             created_at: 10,
-            convo_state: ConversationState::Invited as i32,
+            convo_state: ConversationState::Invited,
+            convo_kind: ConversationKind::Direct,
+            consent_state: ConsentState::Allowed,
+            retention_ns: None,
         };
         let convo_2 = StoredConversation {
             convo_id: "convo_2".into(),
             peer_address: address.clone(),
             created_at: 10,
-            convo_state: ConversationState::Uninitialized as i32,
+            convo_state: ConversationState::Uninitialized,
+            convo_kind: ConversationKind::Group,
+            consent_state: ConsentState::Unknown,
+            retention_ns: None,
         };
         let user_1 = StoredUser {
             user_address: address.clone(),
@@ -1218,6 +2184,211 @@ mod tests {
         assert_eq!(convo_2.convo_id, uninitialized_conversations[0].convo_id);
     }
 
+    #[test]
+    fn expired_messages_are_swept() {
+        let store = EncryptedMessageStore::new(
+            StorageOption::Ephemeral,
+            EncryptedMessageStore::generate_enc_key(),
+        )
+        .unwrap();
+        // Sweeping acquires its own connection
+        {
+            let conn = &mut store.conn().unwrap();
+            StoredUser {
+                user_address: "0x01".into(),
+                created_at: 10,
+                last_refreshed: 0,
+            }
+            .store(conn)
+            .unwrap();
+            for (convo_id, retention_ns) in [("ephemeral", Some(100)), ("kept", None)] {
+                StoredConversation {
+                    convo_id: convo_id.into(),
+                    peer_address: "0x01".into(),
+                    created_at: 10,
+                    convo_state: ConversationState::Invited,
+                    convo_kind: ConversationKind::Direct,
+                    consent_state: ConsentState::Allowed,
+                    retention_ns,
+                }
+                .store(conn)
+                .unwrap();
+            }
+            let messages = [
+                ("ephemeral", MessageState::Received, 1000),
+                ("ephemeral", MessageState::Unprocessed, 1000),
+                ("ephemeral", MessageState::LocallyCommitted, 1050),
+                ("kept", MessageState::Received, 1000),
+            ];
+            for (convo_id, state, sent_at_ns) in messages {
+                NewStoredMessage::new(
+                    convo_id.into(),
+                    "0x01".into(),
+                    rand_vec(),
+                    state,
+                    sent_at_ns,
+                )
+                .store(conn)
+                .unwrap();
+            }
+            let received = store
+                .get_stored_messages(
+                    conn,
+                    Some(vec![MessageState::Received]),
+                    None,
+                    &ListMessagesOptions::default(),
+                )
+                .unwrap();
+            let inbound = InboundMessage {
+                id: rand_string(),
+                sent_at_ns: 1000,
+                payload: rand_vec(),
+                topic: rand_string(),
+                status: InboundMessageStatus::Processed,
+                status_reason: None,
+                message_id: String::new(),
+            };
+            store.save_inbound_message(conn, inbound.clone()).unwrap();
+            store
+                .link_inbound_message(conn, &inbound.id, &received[0].message_id)
+                .unwrap();
+        }
+
+        let clock = ManualClock::new(1099);
+        assert_eq!(store.sweep_expired_messages(&clock).unwrap(), 0);
+        clock.set(1100);
+        assert_eq!(store.sweep_expired_messages(&clock).unwrap(), 1);
+        clock.advance(Duration::from_nanos(50));
+        assert_eq!(store.sweep_expired_messages(&clock).unwrap(), 1);
+
+        // Unsent messages and messages in conversations without a retention are kept
+        let conn = &mut store.conn().unwrap();
+        let remaining = store
+            .get_stored_messages(conn, None, None, &ListMessagesOptions::default())
+            .unwrap();
+        assert_eq!(remaining.len(), 2);
+        assert!(store
+            .get_inbound_messages(conn, InboundMessageStatus::Processed)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn search_index_follows_messages() {
+        let store = EncryptedMessageStore::new(
+            StorageOption::Ephemeral,
+            EncryptedMessageStore::generate_enc_key(),
+        )
+        .unwrap();
+        let conn = &mut store.conn().unwrap();
+        let text = |text: &str| TextCodec::encode(text.to_string()).unwrap().encode_to_vec();
+        let messages = [
+            (text("Café tomorrow?"), MessageState::Received, 10),
+            (text("cafe cafe cafe"), MessageState::LocallyCommitted, 20),
+            (text("See you at the cafe"), MessageState::Unprocessed, 30),
+            ("cafe".as_bytes().to_vec(), MessageState::Received, 40),
+        ];
+        for (content, state, sent_at_ns) in messages {
+            NewStoredMessage::new("convo".into(), "0x01".into(), content, state, sent_at_ns)
+                .store(conn)
+                .unwrap();
+        }
+
+        // Diacritics are folded, and unprocessed or non-text messages are not returned
+        let hits = store
+            .search_messages(conn, "CAFE", &SearchMessagesOptions::default())
+            .unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].0.sent_at_ns, 20);
+        assert!(hits[0].1 <= hits[1].1);
+        let hits = store
+            .search_messages(
+                conn,
+                "caf",
+                &SearchMessagesOptions {
+                    start_time_ns: Some(15),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        let from = |sender_address: &str| SearchMessagesOptions {
+            sender_addresses: Some(vec![sender_address.to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            store
+                .search_messages(conn, "cafe", &from("0x01"))
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(store
+            .search_messages(conn, "cafe", &from("0x02"))
+            .unwrap()
+            .is_empty());
+        assert!(store
+            .search_messages(conn, "cafe AND \"", &SearchMessagesOptions::default())
+            .unwrap()
+            .is_empty());
+
+        // Deleted messages leave the index
+        diesel::delete(schema::messages::table)
+            .execute(conn)
+            .unwrap();
+        assert!(store
+            .search_messages(conn, "cafe", &SearchMessagesOptions::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn conversation_members() {
+        let store = EncryptedMessageStore::new(
+            StorageOption::Ephemeral,
+            EncryptedMessageStore::generate_enc_key(),
+        )
+        .unwrap();
+        let conn = &mut store.conn().unwrap();
+
+        StoredUser {
+            user_address: "0x01".into(),
+            created_at: 10,
+            last_refreshed: 0,
+        }
+        .store(conn)
+        .unwrap();
+        StoredConversation {
+            convo_id: "group".into(),
+            peer_address: "0x01".into(),
+            created_at: 10,
+            convo_state: ConversationState::Invited,
+            convo_kind: ConversationKind::Group,
+            consent_state: ConsentState::Allowed,
+            retention_ns: None,
+        }
+        .store(conn)
+        .unwrap();
+
+        store
+            .set_conversation_members(conn, "group", &["0x01".into(), "0x02".into()])
+            .unwrap();
+        let mut members = store.get_conversation_members(conn, "group").unwrap();
+        members.sort();
+        assert_eq!(members, vec!["0x01", "0x02"]);
+
+        store
+            .set_conversation_members(conn, "group", &["0x01".into(), "0x03".into()])
+            .unwrap();
+        let mut members = store.get_conversation_members(conn, "group").unwrap();
+        members.sort();
+        assert_eq!(members, vec!["0x01", "0x03"]);
+        assert!(store
+            .get_conversation_members(conn, "other")
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn errors_when_no_update() {
         let store = EncryptedMessageStore::new(
@@ -1246,7 +2417,7 @@ mod tests {
             convo_id.to_string(),
             "0x000A".into(),
             "Hello Bola".into(),
-            MessageState::LocallyCommitted as i32,
+            MessageState::LocallyCommitted,
             10,
         )
         .store(conn)
@@ -1256,7 +2427,7 @@ mod tests {
             convo_id.to_string(),
             "0x000A".into(),
             "Hello again".into(),
-            MessageState::Received as i32,
+            MessageState::Received,
             20,
         )
         .store(conn)
@@ -1266,14 +2437,14 @@ mod tests {
             "convo_2".into(),
             "0x000A".into(),
             "Hello from convo 2".into(),
-            MessageState::Received as i32,
+            MessageState::Received,
             30,
         )
         .store(conn)
         .unwrap();
 
         let convo_1_results = store
-            .get_stored_messages(conn, None, Some(convo_id), None, None, None)
+            .get_stored_messages(conn, None, Some(convo_id), &ListMessagesOptions::default())
             .unwrap();
         assert_eq!(2, convo_1_results.len());
         // Ensure results are properly sorted
@@ -1284,21 +2455,132 @@ mod tests {
                 conn,
                 Some(vec![MessageState::Received]),
                 None,
-                None,
-                None,
-                None,
+                &ListMessagesOptions::default(),
             )
             .unwrap();
         assert_eq!(2, results_with_received_state.len());
         assert_eq!("convo_2", results_with_received_state[1].convo_id);
 
-        let results_with_time_filter =
-            store.get_stored_messages(conn, None, None, Some(11), Some(20), None);
+        let results_with_time_filter = store.get_stored_messages(
+            conn,
+            None,
+            None,
+            &ListMessagesOptions::new(Some(11), Some(20), None),
+        );
         assert_eq!(1, results_with_time_filter.unwrap().len());
 
         let results_with_limit = store
-            .get_stored_messages(conn, None, None, None, None, Some(1))
+            .get_stored_messages(
+                conn,
+                None,
+                None,
+                &ListMessagesOptions::new(None, None, Some(1)),
+            )
             .unwrap();
         assert_eq!(1, results_with_limit.len());
     }
+
+    #[test]
+    fn paginate_stored_messages() {
+        let store = EncryptedMessageStore::new(
+            StorageOption::Ephemeral,
+            EncryptedMessageStore::generate_enc_key(),
+        )
+        .unwrap();
+        let conn = &mut store.conn().unwrap();
+        let text = |text: &str| TextCodec::encode(text.to_string()).unwrap().encode_to_vec();
+        let reaction = ReactionCodec::encode(Reaction {
+            reference: "message_id".to_string(),
+            action: ReactionAction::Added,
+            content: "👍".to_string(),
+            schema: ReactionSchema::Unicode,
+        })
+        .unwrap()
+        .encode_to_vec();
+        let messages = [
+            ("0x01", text("one"), 10),
+            ("0x02", text("two"), 20),
+            ("0x01", reaction, 20),
+            ("0x02", text("four"), 20),
+            ("0x01", text("five"), 30),
+        ];
+        for (addr_from, content, sent_at_ns) in messages {
+            NewStoredMessage::new(
+                "convo".into(),
+                addr_from.into(),
+                content,
+                MessageState::Received,
+                sent_at_ns,
+            )
+            .store(conn)
+            .unwrap();
+        }
+
+        // Pages split between messages sent at the same time without skipping or repeating any
+        let mut pages = |direction: SortDirection| -> Vec<Vec<i64>> {
+            let mut sent_at = vec![];
+            let mut cursor = None;
+            loop {
+                let page = store
+                    .get_stored_messages(
+                        conn,
+                        None,
+                        Some("convo"),
+                        &ListMessagesOptions {
+                            limit: Some(2),
+                            cursor,
+                            direction,
+                            ..Default::default()
+                        },
+                    )
+                    .unwrap();
+                match page.last() {
+                    Some(last) => cursor = Some(MessageCursor::from(last)),
+                    None => return sent_at,
+                }
+                sent_at.push(page.iter().map(|message| message.sent_at_ns).collect());
+            }
+        };
+        assert_eq!(
+            pages(SortDirection::Ascending),
+            vec![vec![10, 20], vec![20, 20], vec![30]]
+        );
+        assert_eq!(
+            pages(SortDirection::Descending),
+            vec![vec![30, 20], vec![20, 20], vec![10]]
+        );
+
+        let mut filtered = |opts: ListMessagesOptions| -> Vec<String> {
+            store
+                .get_stored_messages(conn, None, None, &opts)
+                .unwrap()
+                .iter()
+                .map(|message| message.get_text().unwrap())
+                .collect()
+        };
+        assert_eq!(
+            filtered(ListMessagesOptions {
+                sender_addresses: Some(vec!["0x02".to_string()]),
+                direction: SortDirection::Descending,
+                ..Default::default()
+            }),
+            vec!["four", "two"]
+        );
+        assert_eq!(
+            filtered(ListMessagesOptions {
+                sender_addresses: Some(vec!["0x01".to_string()]),
+                content_types: Some(vec![TextCodec::content_type()]),
+                ..Default::default()
+            }),
+            vec!["one", "five"]
+        );
+        assert_eq!(
+            filtered(ListMessagesOptions {
+                content_types: Some(vec![ReactionCodec::content_type()]),
+                ..Default::default()
+            })
+            .len(),
+            1
+        );
+    }
 }
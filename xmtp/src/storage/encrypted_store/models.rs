@@ -1,18 +1,36 @@
 use super::{schema::*, DbConnection};
 use crate::{
     account::Account,
+    codecs::{
+        compression::CompressionConfig, delivery_receipt::DeliveryReceiptCodec,
+        read_receipt::ReadReceiptCodec, CodecError, CodecRegistry, MessageContent,
+    },
     contact::{Contact, ContactError},
-    storage::StorageError,
+    storage::{StorageError, UnknownEnumValue},
     ContentCodec, Save, TextCodec,
 };
-use diesel::prelude::*;
+use diesel::{
+    backend::RawValue,
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    prelude::*,
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::{Integer, SmallInt},
+    sqlite::Sqlite,
+};
 use prost::{DecodeError, Message};
-use std::fmt;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::time::{SystemTime, UNIX_EPOCH};
+use std::{any::Any, fmt};
 use xmtp_cryptography::hash::sha256_bytes;
-use xmtp_proto::xmtp::{message_api::v1::Envelope, message_contents::EncodedContent};
+use xmtp_proto::xmtp::{
+    message_api::v1::Envelope,
+    message_contents::{ContentTypeId, EncodedContent},
+};
 
-#[derive(Insertable, Selectable, Identifiable, Queryable, PartialEq, Debug, Clone)]
+#[derive(
+    Insertable, Selectable, Identifiable, Queryable, PartialEq, Debug, Clone, Serialize, Deserialize,
+)]
 #[diesel(table_name = users)]
 #[diesel(belongs_to(StoredConversation))]
 #[diesel(primary_key(user_address))]
@@ -22,30 +40,141 @@ pub struct StoredUser {
     pub last_refreshed: i64,
 }
 
+/// Stores an enum in an integer column, and serializes it, as its discriminant. Reading a value
+/// which is not one of the listed variants fails with `UnknownEnumValue` instead of being
+/// accepted.
+macro_rules! sql_enum {
+    ($name:ident, $sql_type:ty, $int:ty, [$($variant:ident),+]) => {
+        impl TryFrom<i32> for $name {
+            type Error = UnknownEnumValue;
+
+            fn try_from(value: i32) -> Result<Self, Self::Error> {
+                $(
+                    if value == Self::$variant as i32 {
+                        return Ok(Self::$variant);
+                    }
+                )+
+                Err(UnknownEnumValue {
+                    name: stringify!($name),
+                    value,
+                })
+            }
+        }
+
+        impl ToSql<$sql_type, Sqlite> for $name {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+                out.set_value(*self as i32);
+                Ok(IsNull::No)
+            }
+        }
+
+        impl FromSql<$sql_type, Sqlite> for $name {
+            fn from_sql(bytes: RawValue<'_, Sqlite>) -> deserialize::Result<Self> {
+                let value = <$int as FromSql<$sql_type, Sqlite>>::from_sql(bytes)?;
+                Ok(Self::try_from(i32::from(value))?)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_i32(*self as i32)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = i32::deserialize(deserializer)?;
+                Self::try_from(value).map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+#[derive(AsExpression, FromSqlRow, Clone, Copy, Debug, PartialEq, Eq)]
+#[diesel(sql_type = Integer)]
 pub enum ConversationState {
     Uninitialized = 0,
     Invited = 10,
     InviteReceived = 20,
 }
 
-#[derive(Insertable, Identifiable, Selectable, Queryable, PartialEq, Debug, Clone)]
+sql_enum!(
+    ConversationState,
+    Integer,
+    i32,
+    [Uninitialized, Invited, InviteReceived]
+);
+
+#[derive(AsExpression, FromSqlRow, Clone, Copy, Debug, PartialEq, Eq)]
+#[diesel(sql_type = Integer)]
+pub enum ConversationKind {
+    Direct = 0,
+    Group = 1,
+}
+
+sql_enum!(ConversationKind, Integer, i32, [Direct, Group]);
+
+/// Whether the user wants to hear from a conversation. Conversations started by someone else
+/// are `Unknown` until the user allows or blocks the peer.
+#[derive(AsExpression, FromSqlRow, Clone, Copy, Debug, PartialEq, Eq)]
+#[diesel(sql_type = Integer)]
+pub enum ConsentState {
+    Unknown = 0,
+    Allowed = 10,
+    Denied = 20,
+}
+
+sql_enum!(ConsentState, Integer, i32, [Unknown, Allowed, Denied]);
+
+#[derive(
+    Insertable, Identifiable, Selectable, Queryable, PartialEq, Debug, Clone, Serialize, Deserialize,
+)]
 #[diesel(table_name = conversations)]
 #[diesel(primary_key(convo_id))]
 pub struct StoredConversation {
     pub convo_id: String,
-    pub peer_address: String, // links to users table
+    pub peer_address: String, // links to users table. For groups, the member who added us
     pub created_at: i64,
-    pub convo_state: i32, // ConversationState
+    pub convo_state: ConversationState,
+    pub convo_kind: ConversationKind,
+    pub consent_state: ConsentState,
+    pub retention_ns: Option<i64>,
 }
 
+#[derive(Insertable, Identifiable, Queryable, PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[diesel(table_name = conversation_members)]
+#[diesel(primary_key(convo_id, user_address))]
+pub struct StoredConversationMember {
+    pub convo_id: String,
+    pub user_address: String,
+    pub added_at_ns: i64,
+}
+
+#[derive(AsExpression, FromSqlRow, Clone, Copy, Debug, PartialEq, Eq)]
+#[diesel(sql_type = Integer)]
 pub enum MessageState {
     Unprocessed = 0,
     LocallyCommitted = 10,
     Received = 20,
+    // Gave up sending, see `error_reason`
+    Failed = 30,
+    // Received from a blocked peer. Kept so it is not processed again, but never surfaced.
+    Ignored = 40,
 }
 
+sql_enum!(
+    MessageState,
+    Integer,
+    i32,
+    [Unprocessed, LocallyCommitted, Received, Failed, Ignored]
+);
+
+// Outbound messages are attempted this many times unless the error is permanent
+pub const DEFAULT_MAX_SEND_ATTEMPTS: i32 = 5;
+
 /// Placeholder type for messages returned from the Store.
-#[derive(Queryable, Debug)]
+#[derive(Queryable, Insertable, Debug, Serialize, Deserialize)]
+#[diesel(table_name = messages)]
 pub struct StoredMessage {
     pub id: i32,
     pub created_at: i64,
@@ -53,18 +182,82 @@ pub struct StoredMessage {
     pub convo_id: String,
     pub addr_from: String,
     pub content: Vec<u8>,
-    pub state: i32,
+    pub state: MessageState,
+    pub send_attempts: i32,
+    pub max_send_attempts: i32,
+    pub next_attempt_ns: i64,
+    pub error_reason: Option<String>,
+    /// Identifies the message across every installation which stores a copy of it
+    pub message_id: String,
+    /// The `authority_id/type_id` of the content, see `content_type_name`
+    #[serde(default)]
+    pub content_type: String,
 }
 
 impl StoredMessage {
     pub fn get_text(&self) -> Result<String, DecodeError> {
         let content = EncodedContent::decode(self.content.as_slice())?;
         let fallback = String::from(content.fallback());
-        match TextCodec::decode(content) {
+        let decoded = CompressionConfig::default()
+            .decompress(content)
+            .and_then(TextCodec::decode);
+        match decoded {
             Ok(t) => Ok(t),
             Err(_) => Ok(fallback),
         }
     }
+
+    /// Decode the content with the codec `codecs` has registered for its type
+    pub fn decode_content(&self, codecs: &CodecRegistry) -> Result<MessageContent, CodecError> {
+        let content = EncodedContent::decode(self.content.as_slice())
+            .map_err(|err| CodecError::Decode(err.to_string()))?;
+        codecs.decode(content)
+    }
+
+    /// Decode the content as a `T`, failing if `codecs` decodes it to any other type
+    pub fn decode<T: Any + Clone>(&self, codecs: &CodecRegistry) -> Result<T, CodecError> {
+        let content = EncodedContent::decode(self.content.as_slice())
+            .map_err(|err| CodecError::Decode(err.to_string()))?;
+        codecs.decode_as(content)
+    }
+}
+
+/// Names a content type regardless of its version, as messages are filtered by type
+pub fn content_type_name(content_type: &ContentTypeId) -> String {
+    format!("{}/{}", content_type.authority_id, content_type.type_id)
+}
+
+/// Delivery and read receipts are stored as messages, but aren't listed unless asked for
+pub(crate) fn receipt_content_types() -> Vec<String> {
+    vec![
+        content_type_name(&DeliveryReceiptCodec::content_type()),
+        content_type_name(&ReadReceiptCodec::content_type()),
+    ]
+}
+
+/// The name of the type of encoded message content, or an empty string if it has none
+pub(crate) fn stored_content_type(content: &[u8]) -> String {
+    EncodedContent::decode(content)
+        .ok()
+        .and_then(|content| content.r#type)
+        .map_or_else(String::new, |content_type| content_type_name(&content_type))
+}
+
+/// The text of message content to add to the search index. Only text content is indexed, as the
+/// fallbacks of other content types describe the content rather than contain it.
+pub(crate) fn searchable_text(content: &[u8]) -> Option<String> {
+    let content = EncodedContent::decode(content).ok()?;
+    let text_type = TextCodec::content_type();
+    match &content.r#type {
+        Some(content_type)
+            if content_type.authority_id == text_type.authority_id
+                && content_type.type_id == text_type.type_id => {}
+        _ => return None,
+    }
+    CompressionConfig::default()
+        .decompress(content)
+        .and_then(TextCodec::decode)
+        .ok()
 }
 
 /// Placeholder type for messages being inserted into the store. This type is the same as
@@ -78,7 +271,27 @@ pub struct NewStoredMessage {
     pub convo_id: String,
     pub addr_from: String,
     pub content: Vec<u8>,
-    pub state: i32,
+    pub state: MessageState,
+    pub max_send_attempts: i32,
+    pub message_id: String,
+    pub content_type: String,
+}
+
+/// Derive the id of a message from what its sender signed, so that the sender and every
+/// recipient arrive at the same id without it being sent, and a message delivered more than
+/// once is only stored once
+pub fn compute_message_id(
+    convo_id: &str,
+    addr_from: &str,
+    sent_at_ns: i64,
+    content: &[u8],
+) -> String {
+    let preimage = [
+        format!("{convo_id}:{addr_from}:{sent_at_ns}:").as_bytes(),
+        content,
+    ]
+    .concat();
+    hex::encode(sha256_bytes(&preimage))
 }
 
 impl NewStoredMessage {
@@ -86,18 +299,26 @@ impl NewStoredMessage {
         convo_id: String,
         addr_from: String,
         content: Vec<u8>,
-        state: i32,
+        state: MessageState,
         sent_at_ns: i64,
     ) -> Self {
         Self {
             created_at: now(),
+            message_id: compute_message_id(&convo_id, &addr_from, sent_at_ns, &content),
+            content_type: stored_content_type(&content),
             convo_id,
             sent_at_ns,
             addr_from,
             content,
             state,
+            max_send_attempts: DEFAULT_MAX_SEND_ATTEMPTS,
         }
     }
+
+    pub fn with_max_send_attempts(mut self, max_send_attempts: i32) -> Self {
+        self.max_send_attempts = max_send_attempts;
+        self
+    }
 }
 
 impl PartialEq<StoredMessage> for NewStoredMessage {
@@ -110,21 +331,55 @@ impl PartialEq<StoredMessage> for NewStoredMessage {
     }
 }
 
+#[derive(AsExpression, FromSqlRow, Clone, Copy, Debug, PartialEq, Eq)]
+#[diesel(sql_type = Integer)]
 pub enum OutboundPayloadState {
     Pending = 0,
     ServerAcknowledged = 10,
 }
 
+sql_enum!(
+    OutboundPayloadState,
+    Integer,
+    i32,
+    [Pending, ServerAcknowledged]
+);
+
+/// How far a message has got towards one recipient installation. Statuses only move forward.
+#[derive(AsExpression, FromSqlRow, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[diesel(sql_type = Integer)]
+pub enum DeliveryStatus {
+    Pending = 0,
+    // Accepted by the network
+    Published = 10,
+    // Acknowledged by the recipient with a delivery receipt
+    Delivered = 20,
+    // Acknowledged by the recipient with a read receipt
+    Read = 30,
+}
+
+sql_enum!(
+    DeliveryStatus,
+    Integer,
+    i32,
+    [Pending, Published, Delivered, Read]
+);
+
 #[derive(Insertable, Identifiable, Queryable, PartialEq, Debug)]
 #[diesel(table_name = outbound_payloads)]
-#[diesel(primary_key(created_at_ns))]
+#[diesel(primary_key(payload_id))]
 pub struct StoredOutboundPayload {
     pub payload_id: String,
     pub created_at_ns: i64,
     pub content_topic: String,
     pub payload: Vec<u8>,
-    pub outbound_payload_state: i32,
+    pub outbound_payload_state: OutboundPayloadState,
     pub locked_until_ns: i64,
+    pub publish_attempts: i32,
+    pub message_id: String,
+    pub recipient_address: String,
+    pub recipient_installation_id: String,
+    pub delivery_status: DeliveryStatus,
 }
 
 impl StoredOutboundPayload {
@@ -132,8 +387,11 @@ impl StoredOutboundPayload {
         created_at_ns: i64,
         content_topic: String,
         payload: Vec<u8>,
-        outbound_payload_state: i32,
+        outbound_payload_state: OutboundPayloadState,
         locked_until_ns: i64,
+        message_id: String,
+        recipient_address: String,
+        recipient_installation_id: String,
     ) -> Self {
         let payload_id = hex::encode(sha256_bytes(
             &(format!("{created_at_ns}:{content_topic}").encode_to_vec()),
@@ -145,6 +403,11 @@ impl StoredOutboundPayload {
             payload,
             outbound_payload_state,
             locked_until_ns,
+            publish_attempts: 0,
+            message_id,
+            recipient_address,
+            recipient_installation_id,
+            delivery_status: DeliveryStatus::Pending,
         }
     }
 }
@@ -157,7 +420,17 @@ pub fn now() -> i64 {
         .as_nanos() as i64
 }
 
-#[derive(Insertable, Identifiable, Queryable, Clone, PartialEq, Debug, QueryableByName)]
+#[derive(
+    Insertable,
+    Identifiable,
+    Queryable,
+    Clone,
+    PartialEq,
+    Debug,
+    QueryableByName,
+    Serialize,
+    Deserialize,
+)]
 #[diesel(table_name = sessions)]
 #[diesel(primary_key(session_id))]
 pub struct StoredSession {
@@ -232,7 +505,16 @@ impl TryFrom<&Account> for NewStoredAccount {
 }
 
 #[derive(
-    Queryable, Selectable, Associations, Insertable, Debug, PartialEq, Identifiable, Clone,
+    Queryable,
+    Selectable,
+    Associations,
+    Insertable,
+    Debug,
+    PartialEq,
+    Identifiable,
+    Clone,
+    Serialize,
+    Deserialize,
 )]
 #[diesel(belongs_to(StoredUser, foreign_key = user_address))]
 #[diesel(primary_key(installation_id))]
@@ -261,6 +543,12 @@ impl StoredInstallation {
     pub fn get_contact(&self) -> Result<Contact, ContactError> {
         Contact::from_bytes(self.contact.clone(), self.user_address.clone())
     }
+
+    /// Revoked installations expire at the time of their revocation
+    pub fn is_expired(&self, now_ns: i64) -> bool {
+        self.expires_at_ns
+            .map_or(false, |expires_at_ns| expires_at_ns <= now_ns)
+    }
 }
 
 pub enum RefreshJobKind {
@@ -294,7 +582,8 @@ impl Save<DbConnection> for RefreshJob {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(AsExpression, FromSqlRow, Clone, Copy, Debug, PartialEq, Eq)]
+#[diesel(sql_type = SmallInt)]
 pub enum InboundInviteStatus {
     Pending = 0,
     Processed = 1,
@@ -302,7 +591,15 @@ pub enum InboundInviteStatus {
     Invalid = 3,
 }
 
-#[derive(Clone, Debug)]
+sql_enum!(
+    InboundInviteStatus,
+    SmallInt,
+    i16,
+    [Pending, Processed, DecryptionFailure, Invalid]
+);
+
+#[derive(AsExpression, FromSqlRow, Clone, Copy, Debug, PartialEq, Eq)]
+#[diesel(sql_type = SmallInt)]
 pub enum InboundMessageStatus {
     Pending = 0,
     Processed = 1,
@@ -310,6 +607,13 @@ pub enum InboundMessageStatus {
     Invalid = 3,
 }
 
+sql_enum!(
+    InboundMessageStatus,
+    SmallInt,
+    i16,
+    [Pending, Processed, DecryptionFailure, Invalid]
+);
+
 #[derive(Insertable, Identifiable, Queryable, Clone, PartialEq, Debug)]
 #[diesel(table_name = inbound_invites)]
 pub struct InboundInvite {
@@ -317,7 +621,7 @@ pub struct InboundInvite {
     pub sent_at_ns: i64,
     pub payload: Vec<u8>,
     pub topic: String,
-    pub status: i16,
+    pub status: InboundInviteStatus,
 }
 
 impl From<Envelope> for InboundInvite {
@@ -333,11 +637,37 @@ impl From<Envelope> for InboundInvite {
             sent_at_ns,
             payload,
             topic,
-            status: InboundInviteStatus::Pending as i16,
+            status: InboundInviteStatus::Pending,
         }
     }
 }
 
+/// Why an inbound message was marked `InboundMessageStatus::Invalid`
+#[derive(AsExpression, FromSqlRow, Clone, Copy, Debug, PartialEq, Eq)]
+#[diesel(sql_type = SmallInt)]
+pub enum InvalidMessageReason {
+    RecipientMismatch = 1,
+    SenderMismatch = 2,
+    UnknownSender = 3,
+    InvalidSignature = 4,
+    ConversationMismatch = 5,
+    UnauthorizedMembershipChange = 6,
+}
+
+sql_enum!(
+    InvalidMessageReason,
+    SmallInt,
+    i16,
+    [
+        RecipientMismatch,
+        SenderMismatch,
+        UnknownSender,
+        InvalidSignature,
+        ConversationMismatch,
+        UnauthorizedMembershipChange
+    ]
+);
+
 #[derive(Insertable, Identifiable, Queryable, Clone, PartialEq, Debug)]
 #[diesel(table_name = inbound_messages)]
 pub struct InboundMessage {
@@ -345,7 +675,10 @@ pub struct InboundMessage {
     pub sent_at_ns: i64,
     pub payload: Vec<u8>,
     pub topic: String,
-    pub status: i16,
+    pub status: InboundMessageStatus,
+    pub status_reason: Option<InvalidMessageReason>,
+    /// The message this payload decrypted to, once it has been processed
+    pub message_id: String,
 }
 
 impl From<Envelope> for InboundMessage {
@@ -361,7 +694,9 @@ impl From<Envelope> for InboundMessage {
             sent_at_ns,
             payload,
             topic,
-            status: InboundMessageStatus::Pending as i16,
+            status: InboundMessageStatus::Pending,
+            status_reason: None,
+            message_id: String::new(),
         }
     }
 }
@@ -8,12 +8,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    conversation_members (convo_id, user_address) {
+        convo_id -> Text,
+        user_address -> Text,
+        added_at_ns -> BigInt,
+    }
+}
+
 diesel::table! {
     conversations (convo_id) {
         convo_id -> Text,
         peer_address -> Text,
         created_at -> BigInt,
         convo_state -> Integer,
+        convo_kind -> Integer,
+        consent_state -> Integer,
+        retention_ns -> Nullable<BigInt>,
     }
 }
 
@@ -34,6 +45,8 @@ diesel::table! {
         payload -> Binary,
         topic -> Text,
         status -> SmallInt,
+        status_reason -> Nullable<SmallInt>,
+        message_id -> Text,
     }
 }
 
@@ -56,6 +69,12 @@ diesel::table! {
         addr_from -> Text,
         content -> Binary,
         state -> Integer,
+        send_attempts -> Integer,
+        max_send_attempts -> Integer,
+        next_attempt_ns -> BigInt,
+        error_reason -> Nullable<Text>,
+        message_id -> Text,
+        content_type -> Text,
     }
 }
 
@@ -67,6 +86,11 @@ diesel::table! {
         payload -> Binary,
         outbound_payload_state -> Integer,
         locked_until_ns -> BigInt,
+        publish_attempts -> Integer,
+        message_id -> Text,
+        recipient_address -> Text,
+        recipient_installation_id -> Text,
+        delivery_status -> Integer,
     }
 }
 
@@ -96,11 +120,13 @@ diesel::table! {
     }
 }
 
+diesel::joinable!(conversation_members -> conversations (convo_id));
 diesel::joinable!(conversations -> users (peer_address));
 diesel::joinable!(installations -> users (user_address));
 
 diesel::allow_tables_to_appear_in_same_query!(
     accounts,
+    conversation_members,
     conversations,
     inbound_invites,
     inbound_messages,
@@ -1,3 +1,4 @@
+use super::BackupError;
 use crate::contact::ContactError;
 use thiserror::Error;
 
@@ -6,11 +7,17 @@ pub enum StorageError {
     #[error("Diesel connection error")]
     DieselConnectError(#[from] diesel::ConnectionError),
     #[error("Diesel result error: {0}")]
-    DieselResultError(#[from] diesel::result::Error),
+    DieselResultError(#[source] diesel::result::Error),
     #[error("Pool error {0}")]
     PoolError(String),
     #[error("Either incorrect encryptionkey or file is not a db {0}")]
     DbInitError(String),
+    #[error("The encryption key does not match the database")]
+    WrongEncryptionKey,
+    #[error("Backup error: {0}")]
+    Backup(#[from] BackupError),
+    #[error(transparent)]
+    UnknownEnumValue(#[from] UnknownEnumValue),
     #[error("Store Error")]
     Store(String),
     #[error(transparent)]
@@ -22,3 +29,28 @@ pub enum StorageError {
     #[error("unknown storage error: {0}")]
     Unknown(String),
 }
+
+/// A value was read from an enum column which is not one of the variants of the enum
+#[derive(Debug, Error)]
+#[error("unknown {name} value {value} in the store")]
+pub struct UnknownEnumValue {
+    pub name: &'static str,
+    pub value: i32,
+}
+
+impl From<diesel::result::Error> for StorageError {
+    fn from(err: diesel::result::Error) -> Self {
+        match err {
+            // Surface unknown values read from the store as themselves rather than as a Diesel error
+            diesel::result::Error::DeserializationError(err) => {
+                match err.downcast::<UnknownEnumValue>() {
+                    Ok(unknown) => Self::UnknownEnumValue(*unknown),
+                    Err(err) => {
+                        Self::DieselResultError(diesel::result::Error::DeserializationError(err))
+                    }
+                }
+            }
+            err => Self::DieselResultError(err),
+        }
+    }
+}
@@ -1,13 +1,17 @@
+pub mod clock;
 mod encrypted_store;
 mod errors;
 
 pub use encrypted_store::{
+    backup::{BackupError, BackupOptions, BackupSecret, BACKUP_SCHEMA_VERSION},
     models::{
-        now, ConversationState, InboundInvite, InboundInviteStatus, InboundMessage,
-        InboundMessageStatus, MessageState, NewStoredMessage, OutboundPayloadState, RefreshJob,
-        RefreshJobKind, StoredConversation, StoredInstallation, StoredMessage,
-        StoredOutboundPayload, StoredSession, StoredUser,
+        compute_message_id, content_type_name, now, ConsentState, ConversationKind,
+        ConversationState, DeliveryStatus, InboundInvite, InboundInviteStatus, InboundMessage,
+        InboundMessageStatus, InvalidMessageReason, MessageState, NewStoredMessage,
+        OutboundPayloadState, RefreshJob, RefreshJobKind, StoredConversation,
+        StoredConversationMember, StoredInstallation, StoredMessage, StoredOutboundPayload,
+        StoredSession, StoredUser,
     },
     DbConnection, EncryptedMessageStore, EncryptionKey, StorageOption,
 };
-pub use errors::StorageError;
+pub use errors::{StorageError, UnknownEnumValue};
@@ -5,7 +5,9 @@ pub mod test_utils {
         types::networking::XmtpApiClient, Client, ClientBuilder,
     };
 
-    async fn gen_test_client_internal(api_client: MockXmtpApiClient) -> Client<MockXmtpApiClient> {
+    pub async fn gen_test_client_on_network(
+        api_client: MockXmtpApiClient,
+    ) -> Client<MockXmtpApiClient> {
         let mut client = ClientBuilder::new_test()
             .api_client(api_client)
             .build()
@@ -15,7 +17,7 @@ pub mod test_utils {
     }
 
     pub async fn gen_test_client() -> Client<MockXmtpApiClient> {
-        gen_test_client_internal(MockXmtpApiClient::new()).await
+        gen_test_client_on_network(MockXmtpApiClient::new()).await
     }
 
     // Generate test clients pointing to the same network
@@ -23,8 +25,8 @@ pub mod test_utils {
         let api_client_1 = MockXmtpApiClient::new();
         let api_client_2 = api_client_1.clone();
         (
-            gen_test_client_internal(api_client_1).await,
-            gen_test_client_internal(api_client_2).await,
+            gen_test_client_on_network(api_client_1).await,
+            gen_test_client_on_network(api_client_2).await,
         )
     }
 
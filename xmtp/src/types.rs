@@ -6,8 +6,8 @@ pub mod networking {
     use std::{error::Error as StdError, fmt};
 
     pub use xmtp_proto::xmtp::message_api::v1::{
-        Envelope, PagingInfo, PublishRequest, PublishResponse, QueryRequest, QueryResponse,
-        SubscribeRequest,
+        cursor, Cursor, Envelope, IndexCursor, PagingInfo, PublishRequest, PublishResponse,
+        QueryRequest, QueryResponse, SortDirection, SubscribeRequest,
     };
 
     #[derive(Debug)]
@@ -95,5 +95,67 @@ pub mod networking {
         async fn query(&self, request: QueryRequest) -> Result<QueryResponse, Error>;
 
         async fn subscribe(&self, request: SubscribeRequest) -> Result<Self::Subscription, Error>;
+
+        /// Claim a single one-time key from those published on `topic`, removing it so that no
+        /// other sender is handed the same key. Nodes without one-time key support return
+        /// `None`, in which case senders fall back to the contact's fallback key.
+        async fn claim_one_time_key(&self, _topic: String) -> Result<Option<Envelope>, Error> {
+            Ok(None)
+        }
+    }
+
+    /// Walks through every page of a query, following the cursor returned with each response
+    pub struct QueryPages<'a, A>
+    where
+        A: XmtpApiClient,
+    {
+        api_client: &'a A,
+        request: QueryRequest,
+        done: bool,
+    }
+
+    impl<'a, A> QueryPages<'a, A>
+    where
+        A: XmtpApiClient,
+    {
+        pub fn new(api_client: &'a A, request: QueryRequest) -> Self {
+            Self {
+                api_client,
+                request,
+                done: false,
+            }
+        }
+
+        /// Fetch the next page of envelopes, or `None` once the query has been exhausted
+        pub async fn next_page(&mut self) -> Result<Option<Vec<Envelope>>, Error> {
+            if self.done {
+                return Ok(None);
+            }
+
+            let response = self.api_client.query(self.request.clone()).await?;
+            let next_cursor = response.paging_info.and_then(|info| info.cursor);
+            match next_cursor {
+                Some(next_cursor) if !response.envelopes.is_empty() => {
+                    let paging_info = self
+                        .request
+                        .paging_info
+                        .get_or_insert_with(Default::default);
+                    paging_info.cursor = Some(next_cursor);
+                }
+                _ => self.done = true,
+            }
+
+            Ok(Some(response.envelopes))
+        }
+
+        /// Fetch all remaining pages and concatenate their envelopes
+        pub async fn collect_all(mut self) -> Result<Vec<Envelope>, Error> {
+            let mut envelopes = vec![];
+            while let Some(page) = self.next_page().await? {
+                envelopes.extend(page);
+            }
+
+            Ok(envelopes)
+        }
     }
 }
@@ -25,6 +25,10 @@ pub fn build_installation_message_topic(installation_id: &str) -> String {
     format!("/xmtp/3/message-{}/proto", installation_id)
 }
 
+pub fn build_installation_one_time_key_topic(installation_id: &str) -> String {
+    format!("/xmtp/3/otk-{}/proto", installation_id)
+}
+
 pub fn build_envelope(content_topic: String, message: Vec<u8>) -> Envelope {
     Envelope {
         content_topic,
@@ -80,10 +80,12 @@ mod test {
         };
         let fallback_key = VmacInstallationLinkedKey {
             key: Some(fallback_key_proto.proto),
+            signature: None,
         };
         VmacInstallationPublicKeyBundleV1 {
             identity_key: Some(identity_key),
             fallback_key: Some(fallback_key),
+            sealed_sender_key: None,
         }
     }
 
@@ -32,6 +32,8 @@ pub struct EdDsaSignature {
 pub enum AssociationTextVersion {
     Unspecified = 0,
     AssociationTextVersion1 = 1,
+    /// Covers the Ed25519 identity key as well as the Curve25519 one
+    AssociationTextVersion2 = 2,
 }
 impl AssociationTextVersion {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -42,6 +44,7 @@ impl AssociationTextVersion {
         match self {
             AssociationTextVersion::Unspecified => "ASSOCIATION_TEXT_VERSION_UNSPECIFIED",
             AssociationTextVersion::AssociationTextVersion1 => "ASSOCIATION_TEXT_VERSION_1",
+            AssociationTextVersion::AssociationTextVersion2 => "ASSOCIATION_TEXT_VERSION_2",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -49,6 +52,7 @@ impl AssociationTextVersion {
         match value {
             "ASSOCIATION_TEXT_VERSION_UNSPECIFIED" => Some(Self::Unspecified),
             "ASSOCIATION_TEXT_VERSION_1" => Some(Self::AssociationTextVersion1),
+            "ASSOCIATION_TEXT_VERSION_2" => Some(Self::AssociationTextVersion2),
             _ => None,
         }
     }
@@ -106,6 +110,9 @@ pub mod vmac_account_linked_key {
 pub struct VmacInstallationLinkedKey {
     #[prost(message, optional, tag="1")]
     pub key: ::core::option::Option<VmacUnsignedPublicKey>,
+    /// Signature over the encoded `key` by the installation's Ed25519 key
+    #[prost(message, optional, tag="2")]
+    pub signature: ::core::option::Option<EdDsaSignature>,
 }
 /// A bundle of one time keys uploaded by a client, to be used as
 /// input to (X)3DH exchanges with it. The server is expected to serve
@@ -141,6 +148,13 @@ pub struct VmacInstallationPublicKeyBundleV1 {
     pub identity_key: ::core::option::Option<VmacAccountLinkedKey>,
     #[prost(message, optional, tag="2")]
     pub fallback_key: ::core::option::Option<VmacInstallationLinkedKey>,
+    /// The key that sealed sender metadata is encrypted to
+    #[prost(message, optional, tag="3")]
+    pub sealed_sender_key: ::core::option::Option<VmacInstallationLinkedKey>,
+    /// The installation's Ed25519 identity key, covered by the association on
+    /// `identity_key`
+    #[prost(bytes="vec", tag="4")]
+    pub ed25519_key: ::prost::alloc::vec::Vec<u8>,
 }
 /// A wrapper for versions of the installation contact bundle to allow
 /// upgradeability
@@ -299,7 +313,7 @@ impl PadlockMessagePayloadVersion {
 }
 /// Encoded file descriptor set for the `xmtp.v3.message_contents` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0x93, 0x0c, 0x0a, 0x25, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f,
+    0x0a, 0x9e, 0x0d, 0x0a, 0x25, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f,
     0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2f, 0x61, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61,
     0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x18, 0x78, 0x6d, 0x74, 0x70,
     0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74,
@@ -324,164 +338,18 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x62, 0x79,
     0x74, 0x65, 0x73, 0x22, 0x26, 0x0a, 0x0e, 0x45, 0x64, 0x44, 0x73, 0x61, 0x53, 0x69, 0x67, 0x6e,
     0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01,
-    0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x2a, 0x62, 0x0a, 0x16, 0x41,
-    0x73, 0x73, 0x6f, 0x63, 0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x65, 0x78, 0x74, 0x56, 0x65,
-    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x28, 0x0a, 0x24, 0x41, 0x53, 0x53, 0x4f, 0x43, 0x49, 0x41,
-    0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x54, 0x45, 0x58, 0x54, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f,
-    0x4e, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12,
-    0x1e, 0x0a, 0x1a, 0x41, 0x53, 0x53, 0x4f, 0x43, 0x49, 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x54,
-    0x45, 0x58, 0x54, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x31, 0x10, 0x01, 0x42,
-    0xdf, 0x01, 0x0a, 0x1c, 0x63, 0x6f, 0x6d, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e,
-    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73,
-    0x42, 0x10, 0x41, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f,
-    0x74, 0x6f, 0x50, 0x01, 0x5a, 0x2f, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d,
-    0x2f, 0x78, 0x6d, 0x74, 0x70, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2f, 0x76, 0x33, 0x2f, 0x67,
-    0x6f, 0x2f, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e,
-    0x74, 0x65, 0x6e, 0x74, 0x73, 0xa2, 0x02, 0x03, 0x58, 0x56, 0x4d, 0xaa, 0x02, 0x17, 0x58, 0x6d,
-    0x74, 0x70, 0x2e, 0x56, 0x33, 0x2e, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e,
-    0x74, 0x65, 0x6e, 0x74, 0x73, 0xca, 0x02, 0x17, 0x58, 0x6d, 0x74, 0x70, 0x5c, 0x56, 0x33, 0x5c,
-    0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0xe2,
-    0x02, 0x23, 0x58, 0x6d, 0x74, 0x70, 0x5c, 0x56, 0x33, 0x5c, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67,
-    0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74,
-    0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x19, 0x58, 0x6d, 0x74, 0x70, 0x3a, 0x3a, 0x56, 0x33,
-    0x3a, 0x3a, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74,
-    0x73, 0x4a, 0xaa, 0x06, 0x0a, 0x06, 0x12, 0x04, 0x01, 0x00, 0x1f, 0x01, 0x0a, 0x1d, 0x0a, 0x01,
-    0x0c, 0x12, 0x03, 0x01, 0x00, 0x12, 0x1a, 0x13, 0x20, 0x41, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x79, 0x70, 0x65, 0x73, 0x0a, 0x0a, 0x08, 0x0a, 0x01, 0x02,
-    0x12, 0x03, 0x03, 0x00, 0x21, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x05, 0x00, 0x46, 0x0a,
-    0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12, 0x03, 0x05, 0x00, 0x46, 0x0a, 0x6f, 0x0a, 0x02, 0x05, 0x00,
-    0x12, 0x04, 0x0a, 0x00, 0x0d, 0x01, 0x1a, 0x63, 0x20, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x73, 0x20,
-    0x66, 0x6f, 0x72, 0x20, 0x75, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x61, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74,
-    0x65, 0x78, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x0a, 0x20, 0x69, 0x6e, 0x63,
-    0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e,
-    0x74, 0x69, 0x72, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05,
-    0x00, 0x01, 0x12, 0x03, 0x0a, 0x05, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12,
-    0x03, 0x0b, 0x04, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b,
-    0x04, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0b, 0x2b, 0x2c,
-    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x04, 0x23, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0c, 0x04, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0c, 0x21, 0x22, 0x0a, 0x53, 0x0a, 0x02, 0x04, 0x00, 0x12,
-    0x04, 0x10, 0x00, 0x14, 0x01, 0x1a, 0x47, 0x20, 0x45, 0x49, 0x50, 0x31, 0x39, 0x31, 0x41, 0x73,
-    0x73, 0x6f, 0x63, 0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x75, 0x73, 0x65,
-    0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x45, 0x49, 0x50, 0x20, 0x31, 0x39,
-    0x31, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x69, 0x61, 0x6e, 0x74, 0x20, 0x77, 0x61, 0x6c, 0x6c,
-    0x65, 0x74, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x0a, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x10, 0x08, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
-    0x02, 0x00, 0x12, 0x03, 0x11, 0x04, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06,
-    0x12, 0x03, 0x11, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x11, 0x1b, 0x33, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x11, 0x36,
-    0x37, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x12, 0x04, 0x2c, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x03, 0x12, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x12, 0x1e, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x01, 0x03, 0x12, 0x03, 0x12, 0x2a, 0x2b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02,
-    0x12, 0x03, 0x13, 0x04, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03,
-    0x13, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x13, 0x0b,
-    0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x13, 0x1c, 0x1d, 0x0a,
-    0x27, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x17, 0x00, 0x1a, 0x01, 0x1a, 0x1b, 0x20, 0x52, 0x65,
-    0x63, 0x6f, 0x76, 0x65, 0x72, 0x61, 0x62, 0x6c, 0x65, 0x45, 0x63, 0x64, 0x73, 0x61, 0x53, 0x69,
-    0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12,
-    0x03, 0x17, 0x08, 0x21, 0x0a, 0x34, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x19, 0x04,
-    0x14, 0x1a, 0x27, 0x20, 0x49, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x73, 0x20, 0x72, 0x65, 0x63,
-    0x6f, 0x76, 0x65, 0x72, 0x79, 0x20, 0x69, 0x64, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x6c, 0x61, 0x73, 0x74, 0x20, 0x62, 0x79, 0x74, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x19, 0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x19, 0x0a, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x19, 0x12, 0x13, 0x0a, 0x35, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x1d, 0x00, 0x1f, 0x01,
-    0x1a, 0x29, 0x20, 0x45, 0x64, 0x44, 0x53, 0x41, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
-    0x72, 0x65, 0x20, 0x62, 0x79, 0x74, 0x65, 0x73, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x69, 0x6e,
-    0x67, 0x20, 0x52, 0x46, 0x43, 0x20, 0x38, 0x30, 0x33, 0x32, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
-    0x02, 0x01, 0x12, 0x03, 0x1d, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12,
-    0x03, 0x1e, 0x04, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1e,
-    0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1e, 0x0a, 0x0f,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1e, 0x12, 0x13, 0x62, 0x06,
-    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33, 0x0a, 0xd0, 0x1e, 0x0a, 0x24, 0x76, 0x33, 0x2f, 0x6d, 0x65,
-    0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2f, 0x70,
-    0x75, 0x62, 0x6c, 0x69, 0x63, 0x5f, 0x6b, 0x65, 0x79, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12,
-    0x18, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
-    0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x1a, 0x25, 0x76, 0x33, 0x2f, 0x6d, 0x65,
-    0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2f, 0x61,
-    0x73, 0x73, 0x6f, 0x63, 0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-    0x22, 0xd3, 0x01, 0x0a, 0x15, 0x56, 0x6d, 0x61, 0x63, 0x55, 0x6e, 0x73, 0x69, 0x67, 0x6e, 0x65,
-    0x64, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x12, 0x1d, 0x0a, 0x0a, 0x63, 0x72,
-    0x65, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09,
-    0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x4e, 0x73, 0x12, 0x65, 0x0a, 0x0a, 0x63, 0x75, 0x72,
-    0x76, 0x65, 0x32, 0x35, 0x35, 0x31, 0x39, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x43, 0x2e,
-    0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f,
-    0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x55, 0x6e, 0x73,
-    0x69, 0x67, 0x6e, 0x65, 0x64, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x2e, 0x56,
-    0x6f, 0x64, 0x6f, 0x7a, 0x65, 0x6d, 0x61, 0x63, 0x43, 0x75, 0x72, 0x76, 0x65, 0x32, 0x35, 0x35,
-    0x31, 0x39, 0x48, 0x00, 0x52, 0x0a, 0x63, 0x75, 0x72, 0x76, 0x65, 0x32, 0x35, 0x35, 0x31, 0x39,
-    0x1a, 0x2b, 0x0a, 0x13, 0x56, 0x6f, 0x64, 0x6f, 0x7a, 0x65, 0x6d, 0x61, 0x63, 0x43, 0x75, 0x72,
-    0x76, 0x65, 0x32, 0x35, 0x35, 0x31, 0x39, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73,
-    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x42, 0x07, 0x0a,
-    0x05, 0x75, 0x6e, 0x69, 0x6f, 0x6e, 0x22, 0xb0, 0x01, 0x0a, 0x14, 0x56, 0x6d, 0x61, 0x63, 0x41,
-    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x12,
-    0x41, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2f, 0x2e, 0x78,
-    0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63,
-    0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x55, 0x6e, 0x73, 0x69,
-    0x67, 0x6e, 0x65, 0x64, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b,
-    0x65, 0x79, 0x12, 0x46, 0x0a, 0x07, 0x65, 0x69, 0x70, 0x5f, 0x31, 0x39, 0x31, 0x18, 0x02, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x2b, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65,
-    0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x45,
-    0x69, 0x70, 0x31, 0x39, 0x31, 0x41, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-    0x48, 0x00, 0x52, 0x06, 0x65, 0x69, 0x70, 0x31, 0x39, 0x31, 0x42, 0x0d, 0x0a, 0x0b, 0x61, 0x73,
-    0x73, 0x6f, 0x63, 0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x5e, 0x0a, 0x19, 0x56, 0x6d, 0x61,
-    0x63, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x6e,
-    0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x12, 0x41, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x2f, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65,
-    0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56,
-    0x6d, 0x61, 0x63, 0x55, 0x6e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x50, 0x75, 0x62, 0x6c, 0x69,
-    0x63, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0xc7, 0x01, 0x0a, 0x19, 0x56, 0x6d,
-    0x61, 0x63, 0x4f, 0x6e, 0x65, 0x54, 0x69, 0x6d, 0x65, 0x4b, 0x65, 0x79, 0x54, 0x6f, 0x70, 0x75,
-    0x70, 0x42, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x12, 0x51, 0x0a, 0x0c, 0x69, 0x64, 0x65, 0x6e, 0x74,
-    0x69, 0x74, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2e, 0x2e,
-    0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f,
-    0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x41, 0x63, 0x63,
-    0x6f, 0x75, 0x6e, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x52, 0x0b, 0x69,
-    0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x4b, 0x65, 0x79, 0x12, 0x57, 0x0a, 0x0d, 0x6f, 0x6e,
-    0x65, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x5f, 0x6b, 0x65, 0x79, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28,
-    0x0b, 0x32, 0x33, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73,
-    0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61,
-    0x63, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x6e,
-    0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x52, 0x0b, 0x6f, 0x6e, 0x65, 0x54, 0x69, 0x6d, 0x65, 0x4b,
-    0x65, 0x79, 0x73, 0x22, 0xc4, 0x01, 0x0a, 0x17, 0x56, 0x6d, 0x61, 0x63, 0x46, 0x61, 0x6c, 0x6c,
-    0x62, 0x61, 0x63, 0x6b, 0x4b, 0x65, 0x79, 0x52, 0x6f, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12,
-    0x51, 0x0a, 0x0c, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2e, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e,
-    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73,
-    0x2e, 0x56, 0x6d, 0x61, 0x63, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x4c, 0x69, 0x6e, 0x6b,
-    0x65, 0x64, 0x4b, 0x65, 0x79, 0x52, 0x0b, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x4b,
-    0x65, 0x79, 0x12, 0x56, 0x0a, 0x0c, 0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x5f, 0x6b,
-    0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x33, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e,
-    0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65,
-    0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x52, 0x0b, 0x66,
-    0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x4b, 0x65, 0x79, 0x22, 0xce, 0x01, 0x0a, 0x21, 0x56,
-    0x6d, 0x61, 0x63, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50,
-    0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x42, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x56, 0x31,
-    0x12, 0x51, 0x0a, 0x0c, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x5f, 0x6b, 0x65, 0x79,
-    0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2e, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x2a, 0x82, 0x01, 0x0a, 0x16,
+    0x41, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x54, 0x65, 0x78, 0x74, 0x56,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x28, 0x0a, 0x24, 0x41, 0x53, 0x53, 0x4f, 0x43, 0x49,
+    0x41, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x54, 0x45, 0x58, 0x54, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49,
+    0x4f, 0x4e, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00,
+    0x12, 0x1e, 0x0a, 0x1a, 0x41, 0x53, 0x53, 0x4f, 0x43, 0x49, 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x5f,
+    0x54, 0x45, 0x58, 0x54, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x31, 0x10, 0x01,
+    0x12, 0x1e, 0x0a, 0x1a, 0x41, 0x53, 0x53, 0x4f, 0x43, 0x49, 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x5f,
+    0x54, 0x45, 0x58, 0x54, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x32, 0x10, 0x02,
+    0x42, 0xdf, 0x01, 0x0a, 0x1c, 0x63, 0x6f, 0x6d, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33,
     0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74,
-    0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x4c, 0x69, 0x6e,
-    0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x52, 0x0b, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79,
-    0x4b, 0x65, 0x79, 0x12, 0x56, 0x0a, 0x0c, 0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x5f,
-    0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x33, 0x2e, 0x78, 0x6d, 0x74, 0x70,
-    0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74,
-    0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c,
-    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x52, 0x0b,
-    0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x4b, 0x65, 0x79, 0x22, 0x75, 0x0a, 0x19, 0x49,
-    0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x61,
-    0x63, 0x74, 0x42, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x12, 0x4d, 0x0a, 0x02, 0x76, 0x31, 0x18, 0x01,
-    0x20, 0x01, 0x28, 0x0b, 0x32, 0x3b, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d,
-    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e,
-    0x56, 0x6d, 0x61, 0x63, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-    0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x42, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x56,
-    0x31, 0x48, 0x00, 0x52, 0x02, 0x76, 0x31, 0x42, 0x09, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69,
-    0x6f, 0x6e, 0x42, 0xdd, 0x01, 0x0a, 0x1c, 0x63, 0x6f, 0x6d, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e,
-    0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65,
-    0x6e, 0x74, 0x73, 0x42, 0x0e, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x50, 0x72,
+    0x73, 0x42, 0x10, 0x41, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x72,
     0x6f, 0x74, 0x6f, 0x50, 0x01, 0x5a, 0x2f, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f,
     0x6d, 0x2f, 0x78, 0x6d, 0x74, 0x70, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2f, 0x76, 0x33, 0x2f,
     0x67, 0x6f, 0x2f, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f,
@@ -493,332 +361,374 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65,
     0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x19, 0x58, 0x6d, 0x74, 0x70, 0x3a, 0x3a, 0x56,
     0x33, 0x3a, 0x3a, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e,
-    0x74, 0x73, 0x4a, 0xbc, 0x12, 0x0a, 0x06, 0x12, 0x04, 0x02, 0x00, 0x4a, 0x01, 0x0a, 0x7b, 0x0a,
-    0x01, 0x0c, 0x12, 0x03, 0x02, 0x00, 0x12, 0x1a, 0x71, 0x20, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74,
-    0x75, 0x72, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e,
-    0x74, 0x69, 0x6e, 0x67, 0x20, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x6b, 0x65, 0x79, 0x73,
-    0x20, 0x6f, 0x66, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x79,
-    0x70, 0x65, 0x73, 0x2c, 0x0a, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x69, 0x6e, 0x67, 0x20,
-    0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20,
-    0x74, 0x6f, 0x20, 0x61, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x73, 0x2e, 0x0a, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12,
-    0x03, 0x04, 0x00, 0x21, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x06, 0x00, 0x2f, 0x0a,
-    0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x08, 0x00, 0x46, 0x0a, 0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12,
-    0x03, 0x08, 0x00, 0x46, 0x0a, 0x34, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x0c, 0x00, 0x19, 0x01,
-    0x1a, 0x28, 0x20, 0x41, 0x6e, 0x20, 0x75, 0x6e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x70,
-    0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x62,
-    0x79, 0x20, 0x6c, 0x69, 0x62, 0x78, 0x6d, 0x74, 0x70, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00,
-    0x01, 0x12, 0x03, 0x0c, 0x08, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
-    0x0d, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0d, 0x04,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0d, 0x0b, 0x15, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0d, 0x18, 0x19, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x00, 0x08, 0x00, 0x12, 0x04, 0x0f, 0x04, 0x11, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x08, 0x00, 0x01, 0x12, 0x03, 0x0f, 0x0a, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
-    0x01, 0x12, 0x03, 0x10, 0x08, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12,
-    0x03, 0x10, 0x08, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x10,
-    0x1c, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x10, 0x29, 0x2a,
-    0x0a, 0xb8, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x03, 0x00, 0x12, 0x04, 0x16, 0x04, 0x18, 0x05, 0x1a,
-    0xa9, 0x01, 0x20, 0x41, 0x20, 0x56, 0x6f, 0x64, 0x6f, 0x7a, 0x65, 0x6d, 0x61, 0x63, 0x20, 0x63,
-    0x75, 0x72, 0x76, 0x65, 0x32, 0x35, 0x35, 0x31, 0x39, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x73, 0x65,
-    0x72, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x20, 0x76, 0x69, 0x61, 0x20, 0x73, 0x65, 0x72,
-    0x64, 0x65, 0x0a, 0x20, 0x28, 0x68, 0x74, 0x74, 0x70, 0x73, 0x3a, 0x2f, 0x2f, 0x67, 0x69, 0x74,
-    0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x6d, 0x61, 0x74, 0x72, 0x69, 0x78, 0x2d, 0x6f,
-    0x72, 0x67, 0x2f, 0x76, 0x6f, 0x64, 0x6f, 0x7a, 0x65, 0x6d, 0x61, 0x63, 0x2f, 0x62, 0x6c, 0x6f,
-    0x62, 0x2f, 0x0a, 0x20, 0x39, 0x32, 0x39, 0x62, 0x62, 0x61, 0x66, 0x33, 0x32, 0x35, 0x36, 0x38,
-    0x36, 0x34, 0x33, 0x35, 0x62, 0x64, 0x64, 0x30, 0x65, 0x64, 0x30, 0x64, 0x30, 0x63, 0x63, 0x34,
-    0x35, 0x62, 0x30, 0x63, 0x62, 0x61, 0x64, 0x33, 0x34, 0x33, 0x30, 0x64, 0x2f, 0x73, 0x72, 0x63,
-    0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x63, 0x75, 0x72, 0x76, 0x65, 0x32, 0x35, 0x35, 0x31,
-    0x39, 0x2e, 0x72, 0x73, 0x23, 0x4c, 0x31, 0x30, 0x30, 0x29, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x03, 0x00, 0x01, 0x12, 0x03, 0x16, 0x0c, 0x1f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03,
-    0x00, 0x02, 0x00, 0x12, 0x03, 0x17, 0x08, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x00,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x17, 0x08, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x17, 0x0e, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x00, 0x03, 0x00,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x17, 0x16, 0x17, 0x0a, 0x7b, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04,
-    0x1d, 0x00, 0x22, 0x01, 0x1a, 0x6f, 0x20, 0x41, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x6c, 0x69, 0x6e,
-    0x6b, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x20, 0x58, 0x4d, 0x54, 0x50, 0x20, 0x61,
-    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x20, 0x73, 0x69, 0x67,
-    0x6e, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x29,
-    0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x75, 0x72, 0x70, 0x6f, 0x73, 0x65, 0x20, 0x6f, 0x66,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6e, 0x63, 0x6f,
-    0x64, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61,
-    0x74, 0x75, 0x72, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x1d, 0x08,
-    0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1e, 0x04, 0x22, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1e, 0x04, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1e, 0x1a, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x1e, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x08, 0x00,
-    0x12, 0x04, 0x1f, 0x04, 0x21, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x08, 0x00, 0x01, 0x12,
-    0x03, 0x1f, 0x0a, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x20, 0x08,
-    0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x20, 0x08, 0x19, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x20, 0x1a, 0x21, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x20, 0x24, 0x25, 0x0a, 0x90, 0x01, 0x0a, 0x02,
-    0x04, 0x02, 0x12, 0x04, 0x26, 0x00, 0x28, 0x01, 0x1a, 0x83, 0x01, 0x20, 0x41, 0x20, 0x6b, 0x65,
-    0x79, 0x20, 0x6c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x20, 0x69,
-    0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x28, 0x65, 0x2e, 0x67,
-    0x2e, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x6e, 0x20, 0x69,
-    0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x64, 0x65, 0x6e,
-    0x74, 0x69, 0x74, 0x79, 0x20, 0x6b, 0x65, 0x79, 0x29, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70,
-    0x75, 0x72, 0x70, 0x6f, 0x73, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x65,
-    0x79, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x0a, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x26, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02,
-    0x02, 0x00, 0x12, 0x03, 0x27, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06,
-    0x12, 0x03, 0x27, 0x04, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x27, 0x1a, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x27, 0x20,
-    0x21, 0x0a, 0xa6, 0x02, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x2f, 0x00, 0x32, 0x01, 0x1a, 0x99,
-    0x02, 0x20, 0x41, 0x20, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x6f, 0x6e,
-    0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x73, 0x20, 0x75, 0x70, 0x6c, 0x6f,
-    0x61, 0x64, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74,
-    0x2c, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x61, 0x73, 0x0a,
-    0x20, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x28, 0x58, 0x29, 0x33, 0x44, 0x48,
-    0x20, 0x65, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
-    0x69, 0x74, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x69,
-    0x73, 0x20, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x65,
-    0x72, 0x76, 0x65, 0x0a, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x20,
-    0x6f, 0x6e, 0x65, 0x20, 0x70, 0x72, 0x65, 0x6b, 0x65, 0x79, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e,
-    0x79, 0x6f, 0x6e, 0x65, 0x20, 0x77, 0x68, 0x6f, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-    0x73, 0x20, 0x6f, 0x6e, 0x65, 0x2e, 0x0a, 0x20, 0x49, 0x6e, 0x20, 0x6f, 0x75, 0x72, 0x20, 0x69,
-    0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x74, 0x79, 0x70, 0x65,
-    0x20, 0x77, 0x65, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x61, 0x63, 0x74,
-    0x75, 0x61, 0x6c, 0x6c, 0x79, 0x20, 0x75, 0x73, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x2d, 0x74, 0x69,
-    0x6d, 0x65, 0x20, 0x70, 0x72, 0x65, 0x6b, 0x65, 0x79, 0x73, 0x2c, 0x0a, 0x20, 0x64, 0x65, 0x66,
-    0x61, 0x75, 0x6c, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x20, 0x66, 0x61, 0x6c, 0x6c, 0x62,
-    0x61, 0x63, 0x6b, 0x20, 0x6b, 0x65, 0x79, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03,
-    0x01, 0x12, 0x03, 0x2f, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03,
-    0x30, 0x04, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x30, 0x04,
-    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x30, 0x19, 0x25, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x30, 0x28, 0x29, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x31, 0x04, 0x39, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x01, 0x04, 0x12, 0x03, 0x31, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01,
-    0x06, 0x12, 0x03, 0x31, 0x0d, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12,
-    0x03, 0x31, 0x27, 0x34, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x31,
-    0x37, 0x38, 0x0a, 0x9b, 0x02, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x39, 0x00, 0x3c, 0x01, 0x1a,
-    0x8e, 0x02, 0x20, 0x41, 0x20, 0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x20, 0x6b, 0x65,
-    0x79, 0x20, 0x75, 0x70, 0x6c, 0x6f, 0x61, 0x64, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20,
-    0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x72, 0x65,
-    0x70, 0x6c, 0x61, 0x63, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x65, 0x78, 0x69, 0x73, 0x74,
-    0x69, 0x6e, 0x67, 0x0a, 0x20, 0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x20, 0x6b, 0x65,
-    0x79, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x69, 0x73,
-    0x20, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x65, 0x72,
-    0x76, 0x65, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x70, 0x72, 0x65, 0x6b, 0x65, 0x79, 0x20, 0x77,
-    0x68, 0x65, 0x6e, 0x0a, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x6f, 0x6e, 0x65, 0x2d, 0x74, 0x69, 0x6d,
-    0x65, 0x20, 0x70, 0x72, 0x65, 0x6b, 0x65, 0x79, 0x73, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x62,
-    0x65, 0x65, 0x6e, 0x20, 0x65, 0x78, 0x68, 0x61, 0x75, 0x73, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x20,
-    0x49, 0x6e, 0x20, 0x6f, 0x75, 0x72, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x20, 0x70,
-    0x72, 0x6f, 0x74, 0x6f, 0x74, 0x79, 0x70, 0x65, 0x20, 0x77, 0x65, 0x20, 0x77, 0x69, 0x6c, 0x6c,
-    0x20, 0x61, 0x6c, 0x77, 0x61, 0x79, 0x73, 0x20, 0x75, 0x73, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x69, 0x6e, 0x20,
-    0x70, 0x6c, 0x61, 0x63, 0x65, 0x0a, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x6f, 0x6e,
-    0x65, 0x2d, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x70, 0x72, 0x65, 0x6b, 0x65, 0x79, 0x73, 0x2e, 0x0a,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x39, 0x08, 0x1f, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x3a, 0x04, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x00, 0x06, 0x12, 0x03, 0x3a, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x3a, 0x19, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x3a, 0x28, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x04, 0x2f,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x3b, 0x04, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3b, 0x1e, 0x2a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3b, 0x2d, 0x2e, 0x0a, 0x4a, 0x0a, 0x02, 0x04, 0x05,
-    0x12, 0x04, 0x3f, 0x00, 0x42, 0x01, 0x1a, 0x3e, 0x20, 0x41, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61,
-    0x63, 0x74, 0x20, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x64,
-    0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x74,
-    0x6f, 0x20, 0x61, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x63,
-    0x6c, 0x69, 0x65, 0x6e, 0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x3f,
-    0x08, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x40, 0x04, 0x2a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x06, 0x12, 0x03, 0x40, 0x04, 0x18, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x40, 0x19, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x40, 0x28, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02,
-    0x01, 0x12, 0x03, 0x41, 0x04, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x06, 0x12,
-    0x03, 0x41, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x41,
-    0x1e, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x41, 0x2d, 0x2e,
-    0x0a, 0x60, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x46, 0x00, 0x4a, 0x01, 0x1a, 0x54, 0x20, 0x41,
-    0x20, 0x77, 0x72, 0x61, 0x70, 0x70, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x76, 0x65, 0x72,
-    0x73, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x73,
-    0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x63,
-    0x74, 0x20, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6c, 0x6c, 0x6f,
-    0x77, 0x0a, 0x20, 0x75, 0x70, 0x67, 0x72, 0x61, 0x64, 0x65, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74,
-    0x79, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x46, 0x08, 0x21, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x06, 0x08, 0x00, 0x12, 0x04, 0x47, 0x04, 0x49, 0x05, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x06, 0x08, 0x00, 0x01, 0x12, 0x03, 0x47, 0x0a, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06,
-    0x02, 0x00, 0x12, 0x03, 0x48, 0x08, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06,
-    0x12, 0x03, 0x48, 0x08, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x48, 0x2a, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x48, 0x2f,
-    0x30, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33, 0x0a, 0x97, 0x0e, 0x0a, 0x20, 0x76, 0x33,
-    0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74,
-    0x73, 0x2f, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x18,
-    0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f,
-    0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x1a, 0x24, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73,
-    0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2f, 0x70, 0x75,
-    0x62, 0x6c, 0x69, 0x63, 0x5f, 0x6b, 0x65, 0x79, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x44,
-    0x0a, 0x0c, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x56, 0x31, 0x12, 0x34,
-    0x0a, 0x16, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x65, 0x5f, 0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74,
-    0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x14,
-    0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x65, 0x57, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x41, 0x64, 0x64,
-    0x72, 0x65, 0x73, 0x73, 0x22, 0x85, 0x01, 0x0a, 0x14, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74,
-    0x69, 0x6f, 0x6e, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x56, 0x31, 0x12, 0x4d, 0x0a,
-    0x07, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x33,
-    0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
-    0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c,
-    0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74, 0x42, 0x75, 0x6e,
-    0x64, 0x6c, 0x65, 0x52, 0x07, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x72, 0x12, 0x1e, 0x0a, 0x0a,
-    0x63, 0x69, 0x70, 0x68, 0x65, 0x72, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c,
-    0x52, 0x0a, 0x63, 0x69, 0x70, 0x68, 0x65, 0x72, 0x74, 0x65, 0x78, 0x74, 0x22, 0x61, 0x0a, 0x12,
-    0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f,
-    0x70, 0x65, 0x12, 0x40, 0x0a, 0x02, 0x76, 0x31, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2e,
+    0x74, 0x73, 0x4a, 0x94, 0x07, 0x0a, 0x06, 0x12, 0x04, 0x01, 0x00, 0x21, 0x01, 0x0a, 0x1d, 0x0a,
+    0x01, 0x0c, 0x12, 0x03, 0x01, 0x00, 0x12, 0x1a, 0x13, 0x20, 0x41, 0x73, 0x73, 0x6f, 0x63, 0x69,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x79, 0x70, 0x65, 0x73, 0x0a, 0x0a, 0x08, 0x0a, 0x01,
+    0x02, 0x12, 0x03, 0x03, 0x00, 0x21, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x05, 0x00, 0x46,
+    0x0a, 0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12, 0x03, 0x05, 0x00, 0x46, 0x0a, 0x6f, 0x0a, 0x02, 0x05,
+    0x00, 0x12, 0x04, 0x0a, 0x00, 0x0f, 0x01, 0x1a, 0x63, 0x20, 0x41, 0x6c, 0x6c, 0x6f, 0x77, 0x73,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x75, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74,
+    0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x61, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x74, 0x65, 0x78, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x0a, 0x20, 0x69, 0x6e,
+    0x63, 0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65,
+    0x6e, 0x74, 0x69, 0x72, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x05, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x05, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x0b, 0x04, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x0b, 0x04, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0b, 0x2b,
+    0x2c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x04, 0x23, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0c, 0x04, 0x1e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0c, 0x21, 0x22, 0x0a, 0x4c, 0x0a, 0x04, 0x05, 0x00,
+    0x02, 0x02, 0x12, 0x03, 0x0e, 0x04, 0x23, 0x1a, 0x3f, 0x20, 0x43, 0x6f, 0x76, 0x65, 0x72, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x45, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x20, 0x69, 0x64, 0x65,
+    0x6e, 0x74, 0x69, 0x74, 0x79, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x61, 0x73, 0x20, 0x77, 0x65, 0x6c,
+    0x6c, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x43, 0x75, 0x72, 0x76, 0x65, 0x32, 0x35,
+    0x35, 0x31, 0x39, 0x20, 0x6f, 0x6e, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x0e, 0x04, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12,
+    0x03, 0x0e, 0x21, 0x22, 0x0a, 0x53, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x12, 0x00, 0x16, 0x01,
+    0x1a, 0x47, 0x20, 0x45, 0x49, 0x50, 0x31, 0x39, 0x31, 0x41, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x61, 0x6c, 0x6c, 0x20, 0x45, 0x49, 0x50, 0x20, 0x31, 0x39, 0x31, 0x20, 0x63, 0x6f, 0x6d,
+    0x70, 0x6c, 0x69, 0x61, 0x6e, 0x74, 0x20, 0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x20, 0x73, 0x69,
+    0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01,
+    0x12, 0x03, 0x12, 0x08, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x13,
+    0x04, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x13, 0x04, 0x1a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x13, 0x1b, 0x33, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x13, 0x36, 0x37, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x14, 0x04, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x01, 0x06, 0x12, 0x03, 0x14, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x14, 0x1e, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x14, 0x2a, 0x2b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x15, 0x04, 0x1e,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x15, 0x04, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x15, 0x0b, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x15, 0x1c, 0x1d, 0x0a, 0x27, 0x0a, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0x19, 0x00, 0x1c, 0x01, 0x1a, 0x1b, 0x20, 0x52, 0x65, 0x63, 0x6f, 0x76, 0x65, 0x72,
+    0x61, 0x62, 0x6c, 0x65, 0x45, 0x63, 0x64, 0x73, 0x61, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
+    0x72, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x19, 0x08, 0x21, 0x0a,
+    0x34, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1b, 0x04, 0x14, 0x1a, 0x27, 0x20, 0x49,
+    0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x73, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x76, 0x65, 0x72, 0x79,
+    0x20, 0x69, 0x64, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20,
+    0x62, 0x79, 0x74, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x1b, 0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x0a,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1b, 0x12, 0x13, 0x0a,
+    0x35, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x1f, 0x00, 0x21, 0x01, 0x1a, 0x29, 0x20, 0x45, 0x64,
+    0x44, 0x53, 0x41, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x20, 0x62, 0x79,
+    0x74, 0x65, 0x73, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x69, 0x6e, 0x67, 0x20, 0x52, 0x46, 0x43,
+    0x20, 0x38, 0x30, 0x33, 0x32, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1f,
+    0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x20, 0x04, 0x14, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x20, 0x04, 0x09, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x20, 0x0a, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x20, 0x12, 0x13, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x33, 0x0a, 0x97, 0x23, 0x0a, 0x24, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2f, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63,
+    0x5f, 0x6b, 0x65, 0x79, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x18, 0x78, 0x6d, 0x74, 0x70,
+    0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74,
+    0x65, 0x6e, 0x74, 0x73, 0x1a, 0x25, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2f, 0x61, 0x73, 0x73, 0x6f, 0x63, 0x69,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xd3, 0x01, 0x0a, 0x15,
+    0x56, 0x6d, 0x61, 0x63, 0x55, 0x6e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x50, 0x75, 0x62, 0x6c,
+    0x69, 0x63, 0x4b, 0x65, 0x79, 0x12, 0x1d, 0x0a, 0x0a, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64,
+    0x5f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x63, 0x72, 0x65, 0x61, 0x74,
+    0x65, 0x64, 0x4e, 0x73, 0x12, 0x65, 0x0a, 0x0a, 0x63, 0x75, 0x72, 0x76, 0x65, 0x32, 0x35, 0x35,
+    0x31, 0x39, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x43, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e,
+    0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65,
+    0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x55, 0x6e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64,
+    0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x2e, 0x56, 0x6f, 0x64, 0x6f, 0x7a, 0x65,
+    0x6d, 0x61, 0x63, 0x43, 0x75, 0x72, 0x76, 0x65, 0x32, 0x35, 0x35, 0x31, 0x39, 0x48, 0x00, 0x52,
+    0x0a, 0x63, 0x75, 0x72, 0x76, 0x65, 0x32, 0x35, 0x35, 0x31, 0x39, 0x1a, 0x2b, 0x0a, 0x13, 0x56,
+    0x6f, 0x64, 0x6f, 0x7a, 0x65, 0x6d, 0x61, 0x63, 0x43, 0x75, 0x72, 0x76, 0x65, 0x32, 0x35, 0x35,
+    0x31, 0x39, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x42, 0x07, 0x0a, 0x05, 0x75, 0x6e, 0x69, 0x6f,
+    0x6e, 0x22, 0xb0, 0x01, 0x0a, 0x14, 0x56, 0x6d, 0x61, 0x63, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x12, 0x41, 0x0a, 0x03, 0x6b, 0x65,
+    0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2f, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76,
+    0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e,
+    0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x55, 0x6e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x50,
+    0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x46, 0x0a,
+    0x07, 0x65, 0x69, 0x70, 0x5f, 0x31, 0x39, 0x31, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2b,
     0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
-    0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x56, 0x31, 0x48, 0x00,
-    0x52, 0x02, 0x76, 0x31, 0x42, 0x09, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x42,
-    0xda, 0x01, 0x0a, 0x1c, 0x63, 0x6f, 0x6d, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e,
-    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73,
-    0x42, 0x0b, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0x5a,
-    0x2f, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x78, 0x6d, 0x74, 0x70,
-    0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2f, 0x76, 0x33, 0x2f, 0x67, 0x6f, 0x2f, 0x76, 0x33, 0x2f,
-    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73,
-    0xa2, 0x02, 0x03, 0x58, 0x56, 0x4d, 0xaa, 0x02, 0x17, 0x58, 0x6d, 0x74, 0x70, 0x2e, 0x56, 0x33,
-    0x2e, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73,
-    0xca, 0x02, 0x17, 0x58, 0x6d, 0x74, 0x70, 0x5c, 0x56, 0x33, 0x5c, 0x4d, 0x65, 0x73, 0x73, 0x61,
-    0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0xe2, 0x02, 0x23, 0x58, 0x6d, 0x74,
-    0x70, 0x5c, 0x56, 0x33, 0x5c, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74,
-    0x65, 0x6e, 0x74, 0x73, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
-    0xea, 0x02, 0x19, 0x58, 0x6d, 0x74, 0x70, 0x3a, 0x3a, 0x56, 0x33, 0x3a, 0x3a, 0x4d, 0x65, 0x73,
-    0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x4a, 0x9c, 0x09, 0x0a,
-    0x06, 0x12, 0x04, 0x01, 0x00, 0x24, 0x01, 0x0a, 0x27, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x01, 0x00,
-    0x12, 0x1a, 0x1d, 0x20, 0x56, 0x33, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x20, 0x6d, 0x65,
-    0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x75, 0x72, 0x65, 0x0a,
-    0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x03, 0x00, 0x21, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00,
-    0x12, 0x03, 0x05, 0x00, 0x2e, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x07, 0x00, 0x46, 0x0a,
-    0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12, 0x03, 0x07, 0x00, 0x46, 0x0a, 0xc9, 0x01, 0x0a, 0x02, 0x04,
-    0x00, 0x12, 0x04, 0x0d, 0x00, 0x14, 0x01, 0x1a, 0xbc, 0x01, 0x20, 0x49, 0x6e, 0x76, 0x69, 0x74,
-    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x56, 0x31, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69,
-    0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
-    0x65, 0x20, 0x6d, 0x65, 0x61, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x20, 0x65, 0x6e,
-    0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x61, 0x73, 0x0a, 0x20, 0x63, 0x69, 0x70, 0x68,
-    0x65, 0x72, 0x74, 0x65, 0x78, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x56, 0x31, 0x20, 0x61,
-    0x6e, 0x64, 0x20, 0x64, 0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x75, 0x73,
-    0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65,
-    0x64, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x72, 0x20, 0x60, 0x49, 0x6e, 0x73, 0x74, 0x61,
-    0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74, 0x42, 0x75,
-    0x6e, 0x64, 0x6c, 0x65, 0x60, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0d,
-    0x08, 0x14, 0x0a, 0xe2, 0x02, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x12, 0x04, 0x26,
-    0x1a, 0xa4, 0x02, 0x20, 0x49, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74,
-    0x65, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74, 0x20, 0x62, 0x75, 0x6e, 0x64, 0x6c,
-    0x65, 0x20, 0x68, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x77,
-    0x61, 0x6c, 0x6c, 0x65, 0x74, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x61, 0x73,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x0a, 0x20, 0x75, 0x73,
-    0x65, 0x72, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x65, 0x20,
-    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x77, 0x61, 0x6c,
-    0x6c, 0x65, 0x74, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
-    0x20, 0x49, 0x66, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x72,
-    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74, 0x20, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x20,
-    0x68, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x74, 0x20,
-    0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x2c, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x65, 0x20, 0x77, 0x61, 0x6c, 0x6c,
-    0x65, 0x74, 0x0a, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x4d, 0x55, 0x53, 0x54,
-    0x20, 0x62, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x20, 0x61,
-    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65,
-    0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69,
-    0x6e, 0x76, 0x69, 0x74, 0x65, 0x2e, 0x0a, 0x22, 0x2e, 0x20, 0x54, 0x4f, 0x44, 0x4f, 0x3a, 0x20,
-    0x44, 0x65, 0x63, 0x69, 0x64, 0x65, 0x20, 0x77, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x77,
-    0x65, 0x20, 0x6e, 0x65, 0x65, 0x64, 0x20, 0x61, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74,
-    0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x12, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x12, 0x0b, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x12, 0x24,
-    0x25, 0x0a, 0x65, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x18, 0x00, 0x1d, 0x01, 0x1a, 0x59, 0x20,
-    0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f,
-    0x70, 0x65, 0x56, 0x31, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x63, 0x72,
-    0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-    0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74, 0x20, 0x6f, 0x66, 0x0a, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12,
-    0x03, 0x18, 0x08, 0x1c, 0x0a, 0x57, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1a, 0x04,
-    0x2a, 0x1a, 0x4a, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e,
-    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x6b, 0x65, 0x79,
-    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x75, 0x73,
-    0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x64, 0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x63, 0x69, 0x70, 0x68, 0x65, 0x72, 0x74, 0x65, 0x78, 0x74, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1a, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1a, 0x1e, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x1a, 0x28, 0x29, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12,
-    0x03, 0x1c, 0x04, 0x19, 0x1a, 0x28, 0x20, 0x43, 0x6f, 0x72, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-    0x64, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x20, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74,
-    0x69, 0x6f, 0x6e, 0x56, 0x31, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1c, 0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1c, 0x0a, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x01, 0x03, 0x12, 0x03, 0x1c, 0x17, 0x18, 0x0a, 0x22, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04,
-    0x20, 0x00, 0x24, 0x01, 0x1a, 0x16, 0x20, 0x57, 0x72, 0x61, 0x70, 0x70, 0x65, 0x72, 0x20, 0x6d,
-    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x02, 0x01, 0x12, 0x03, 0x20, 0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x02, 0x08, 0x00,
-    0x12, 0x04, 0x21, 0x04, 0x23, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x08, 0x00, 0x01, 0x12,
-    0x03, 0x21, 0x0a, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x22, 0x08,
-    0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x22, 0x08, 0x1c, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x1d, 0x1f, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x22, 0x22, 0x23, 0x62, 0x06, 0x70, 0x72, 0x6f,
-    0x74, 0x6f, 0x33, 0x0a, 0xa8, 0x1c, 0x0a, 0x21, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61,
-    0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2f, 0x6d, 0x65, 0x73, 0x73,
-    0x61, 0x67, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x18, 0x78, 0x6d, 0x74, 0x70, 0x2e,
+    0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x45, 0x69, 0x70, 0x31, 0x39, 0x31,
+    0x41, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x06, 0x65,
+    0x69, 0x70, 0x31, 0x39, 0x31, 0x42, 0x0d, 0x0a, 0x0b, 0x61, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x22, 0xa6, 0x01, 0x0a, 0x19, 0x56, 0x6d, 0x61, 0x63, 0x49, 0x6e, 0x73,
+    0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b,
+    0x65, 0x79, 0x12, 0x41, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x2f, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x55,
+    0x6e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79,
+    0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x46, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
+    0x72, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x28, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e,
     0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65,
-    0x6e, 0x74, 0x73, 0x1a, 0x25, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f,
-    0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2f, 0x61, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xa2, 0x02, 0x0a, 0x1c, 0x50,
-    0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x53, 0x65, 0x61,
-    0x6c, 0x65, 0x64, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x12, 0x2e, 0x0a, 0x13, 0x73,
-    0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65,
-    0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x11, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72,
-    0x55, 0x73, 0x65, 0x72, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x34, 0x0a, 0x16, 0x73,
-    0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69,
-    0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x14, 0x73, 0x65, 0x6e,
-    0x64, 0x65, 0x72, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x49,
-    0x64, 0x12, 0x34, 0x0a, 0x16, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x5f, 0x75,
-    0x73, 0x65, 0x72, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28,
-    0x09, 0x52, 0x14, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x55, 0x73, 0x65, 0x72,
-    0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x3a, 0x0a, 0x19, 0x72, 0x65, 0x63, 0x69, 0x70,
-    0x69, 0x65, 0x6e, 0x74, 0x5f, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f,
-    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x17, 0x72, 0x65, 0x63, 0x69,
-    0x70, 0x69, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f,
-    0x6e, 0x49, 0x64, 0x12, 0x2a, 0x0a, 0x11, 0x69, 0x73, 0x5f, 0x70, 0x72, 0x65, 0x6b, 0x65, 0x79,
-    0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0f,
-    0x69, 0x73, 0x50, 0x72, 0x65, 0x6b, 0x65, 0x79, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x22,
-    0x58, 0x0a, 0x14, 0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67,
-    0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x12, 0x17, 0x0a, 0x07, 0x73, 0x65, 0x6e, 0x74, 0x5f,
-    0x6e, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x06, 0x73, 0x65, 0x6e, 0x74, 0x4e, 0x73,
-    0x12, 0x27, 0x0a, 0x0f, 0x73, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x5f, 0x6d, 0x65, 0x74, 0x61, 0x64,
-    0x61, 0x74, 0x61, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0e, 0x73, 0x65, 0x61, 0x6c, 0x65,
-    0x64, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x22, 0x8d, 0x02, 0x0a, 0x15, 0x50, 0x61,
-    0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x50, 0x61, 0x79, 0x6c,
-    0x6f, 0x61, 0x64, 0x12, 0x5f, 0x0a, 0x0f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x76,
-    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x36, 0x2e, 0x78,
+    0x6e, 0x74, 0x73, 0x2e, 0x45, 0x64, 0x44, 0x73, 0x61, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
+    0x72, 0x65, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0xc7, 0x01,
+    0x0a, 0x19, 0x56, 0x6d, 0x61, 0x63, 0x4f, 0x6e, 0x65, 0x54, 0x69, 0x6d, 0x65, 0x4b, 0x65, 0x79,
+    0x54, 0x6f, 0x70, 0x75, 0x70, 0x42, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x12, 0x51, 0x0a, 0x0c, 0x69,
+    0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x2e, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73,
+    0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61,
+    0x63, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b, 0x65,
+    0x79, 0x52, 0x0b, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x4b, 0x65, 0x79, 0x12, 0x57,
+    0x0a, 0x0d, 0x6f, 0x6e, 0x65, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x5f, 0x6b, 0x65, 0x79, 0x73, 0x18,
+    0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x33, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e,
+    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73,
+    0x2e, 0x56, 0x6d, 0x61, 0x63, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x52, 0x0b, 0x6f, 0x6e, 0x65, 0x54,
+    0x69, 0x6d, 0x65, 0x4b, 0x65, 0x79, 0x73, 0x22, 0xc4, 0x01, 0x0a, 0x17, 0x56, 0x6d, 0x61, 0x63,
+    0x46, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x4b, 0x65, 0x79, 0x52, 0x6f, 0x74, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x12, 0x51, 0x0a, 0x0c, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x5f,
+    0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2e, 0x2e, 0x78, 0x6d, 0x74, 0x70,
+    0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74,
+    0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x52, 0x0b, 0x69, 0x64, 0x65, 0x6e, 0x74,
+    0x69, 0x74, 0x79, 0x4b, 0x65, 0x79, 0x12, 0x56, 0x0a, 0x0c, 0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61,
+    0x63, 0x6b, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x33, 0x2e, 0x78,
     0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63,
-    0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d,
-    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x50, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x56, 0x65, 0x72,
-    0x73, 0x69, 0x6f, 0x6e, 0x52, 0x0e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x56, 0x65, 0x72,
-    0x73, 0x69, 0x6f, 0x6e, 0x12, 0x53, 0x0a, 0x10, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x5f, 0x73,
-    0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x28,
+    0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x49, 0x6e, 0x73, 0x74,
+    0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b, 0x65,
+    0x79, 0x52, 0x0b, 0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x4b, 0x65, 0x79, 0x22, 0xd0,
+    0x02, 0x0a, 0x21, 0x56, 0x6d, 0x61, 0x63, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x42, 0x75, 0x6e, 0x64,
+    0x6c, 0x65, 0x56, 0x31, 0x12, 0x51, 0x0a, 0x0c, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79,
+    0x5f, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2e, 0x2e, 0x78, 0x6d, 0x74,
+    0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x52, 0x0b, 0x69, 0x64, 0x65, 0x6e,
+    0x74, 0x69, 0x74, 0x79, 0x4b, 0x65, 0x79, 0x12, 0x56, 0x0a, 0x0c, 0x66, 0x61, 0x6c, 0x6c, 0x62,
+    0x61, 0x63, 0x6b, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x33, 0x2e,
+    0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f,
+    0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x49, 0x6e, 0x73,
+    0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b,
+    0x65, 0x79, 0x52, 0x0b, 0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x4b, 0x65, 0x79, 0x12,
+    0x5f, 0x0a, 0x11, 0x73, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x5f, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72,
+    0x5f, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x33, 0x2e, 0x78, 0x6d, 0x74,
+    0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c,
+    0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x4b, 0x65, 0x79, 0x52,
+    0x0f, 0x73, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x53, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x4b, 0x65, 0x79,
+    0x12, 0x1f, 0x0a, 0x0b, 0x65, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x5f, 0x6b, 0x65, 0x79, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0a, 0x65, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x4b, 0x65,
+    0x79, 0x22, 0x75, 0x0a, 0x19, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74, 0x42, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x12, 0x4d,
+    0x0a, 0x02, 0x76, 0x31, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x3b, 0x2e, 0x78, 0x6d, 0x74,
+    0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x56, 0x6d, 0x61, 0x63, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c,
+    0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x42,
+    0x75, 0x6e, 0x64, 0x6c, 0x65, 0x56, 0x31, 0x48, 0x00, 0x52, 0x02, 0x76, 0x31, 0x42, 0x09, 0x0a,
+    0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x42, 0xdd, 0x01, 0x0a, 0x1c, 0x63, 0x6f, 0x6d,
     0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
-    0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x45, 0x64, 0x44, 0x73, 0x61, 0x53,
-    0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x52, 0x0f, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72,
-    0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x19, 0x0a, 0x08, 0x63, 0x6f, 0x6e,
-    0x76, 0x6f, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e,
-    0x76, 0x6f, 0x49, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x5f,
-    0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0c, 0x63, 0x6f, 0x6e,
-    0x74, 0x65, 0x6e, 0x74, 0x42, 0x79, 0x74, 0x65, 0x73, 0x22, 0x5b, 0x0a, 0x16, 0x50, 0x61, 0x64,
-    0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x45, 0x6e, 0x76, 0x65, 0x6c,
-    0x6f, 0x70, 0x65, 0x12, 0x21, 0x0a, 0x0c, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x5f, 0x62, 0x79,
-    0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0b, 0x68, 0x65, 0x61, 0x64, 0x65,
-    0x72, 0x42, 0x79, 0x74, 0x65, 0x73, 0x12, 0x1e, 0x0a, 0x0a, 0x63, 0x69, 0x70, 0x68, 0x65, 0x72,
-    0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0a, 0x63, 0x69, 0x70, 0x68,
-    0x65, 0x72, 0x74, 0x65, 0x78, 0x74, 0x2a, 0x78, 0x0a, 0x1c, 0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63,
-    0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x50, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x56,
-    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x2f, 0x0a, 0x2b, 0x50, 0x41, 0x44, 0x4c, 0x4f, 0x43,
-    0x4b, 0x5f, 0x4d, 0x45, 0x53, 0x53, 0x41, 0x47, 0x45, 0x5f, 0x50, 0x41, 0x59, 0x4c, 0x4f, 0x41,
-    0x44, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43,
-    0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x27, 0x0a, 0x23, 0x50, 0x41, 0x44, 0x4c, 0x4f,
-    0x43, 0x4b, 0x5f, 0x4d, 0x45, 0x53, 0x53, 0x41, 0x47, 0x45, 0x5f, 0x50, 0x41, 0x59, 0x4c, 0x4f,
-    0x41, 0x44, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x4f, 0x4e, 0x45, 0x10, 0x01,
-    0x42, 0xdb, 0x01, 0x0a, 0x1c, 0x63, 0x6f, 0x6d, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33,
-    0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74,
-    0x73, 0x42, 0x0c, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50,
+    0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x42, 0x0e, 0x50, 0x75, 0x62, 0x6c, 0x69,
+    0x63, 0x4b, 0x65, 0x79, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0x5a, 0x2f, 0x67, 0x69, 0x74,
+    0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x78, 0x6d, 0x74, 0x70, 0x2f, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x2f, 0x76, 0x33, 0x2f, 0x67, 0x6f, 0x2f, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73,
+    0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0xa2, 0x02, 0x03, 0x58,
+    0x56, 0x4d, 0xaa, 0x02, 0x17, 0x58, 0x6d, 0x74, 0x70, 0x2e, 0x56, 0x33, 0x2e, 0x4d, 0x65, 0x73,
+    0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0xca, 0x02, 0x17, 0x58,
+    0x6d, 0x74, 0x70, 0x5c, 0x56, 0x33, 0x5c, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f,
+    0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0xe2, 0x02, 0x23, 0x58, 0x6d, 0x74, 0x70, 0x5c, 0x56, 0x33,
+    0x5c, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73,
+    0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x19, 0x58,
+    0x6d, 0x74, 0x70, 0x3a, 0x3a, 0x56, 0x33, 0x3a, 0x3a, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x4a, 0xb8, 0x15, 0x0a, 0x06, 0x12, 0x04, 0x02,
+    0x00, 0x51, 0x01, 0x0a, 0x7b, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x02, 0x00, 0x12, 0x1a, 0x71, 0x20,
+    0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x75, 0x72, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x72, 0x65,
+    0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x70, 0x75, 0x62, 0x6c, 0x69,
+    0x63, 0x20, 0x6b, 0x65, 0x79, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65, 0x72,
+    0x65, 0x6e, 0x74, 0x20, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2c, 0x0a, 0x20, 0x69, 0x6e, 0x63, 0x6c,
+    0x75, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73,
+    0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74,
+    0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x73, 0x2e, 0x0a,
+    0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x04, 0x00, 0x21, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00,
+    0x12, 0x03, 0x06, 0x00, 0x2f, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x08, 0x00, 0x46, 0x0a,
+    0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12, 0x03, 0x08, 0x00, 0x46, 0x0a, 0x34, 0x0a, 0x02, 0x04, 0x00,
+    0x12, 0x04, 0x0c, 0x00, 0x19, 0x01, 0x1a, 0x28, 0x20, 0x41, 0x6e, 0x20, 0x75, 0x6e, 0x73, 0x69,
+    0x67, 0x6e, 0x65, 0x64, 0x20, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x6b, 0x65, 0x79, 0x20,
+    0x75, 0x73, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x6c, 0x69, 0x62, 0x78, 0x6d, 0x74, 0x70, 0x0a,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x08, 0x1d, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0d, 0x04, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x0d, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x0d, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x0d, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x08, 0x00, 0x12, 0x04, 0x0f, 0x04, 0x11,
+    0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x08, 0x00, 0x01, 0x12, 0x03, 0x0f, 0x0a, 0x0f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x10, 0x08, 0x2b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x03, 0x10, 0x08, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x10, 0x1c, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x10, 0x29, 0x2a, 0x0a, 0xb8, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x03, 0x00, 0x12,
+    0x04, 0x16, 0x04, 0x18, 0x05, 0x1a, 0xa9, 0x01, 0x20, 0x41, 0x20, 0x56, 0x6f, 0x64, 0x6f, 0x7a,
+    0x65, 0x6d, 0x61, 0x63, 0x20, 0x63, 0x75, 0x72, 0x76, 0x65, 0x32, 0x35, 0x35, 0x31, 0x39, 0x20,
+    0x6b, 0x65, 0x79, 0x20, 0x73, 0x65, 0x72, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x20, 0x76,
+    0x69, 0x61, 0x20, 0x73, 0x65, 0x72, 0x64, 0x65, 0x0a, 0x20, 0x28, 0x68, 0x74, 0x74, 0x70, 0x73,
+    0x3a, 0x2f, 0x2f, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x6d, 0x61,
+    0x74, 0x72, 0x69, 0x78, 0x2d, 0x6f, 0x72, 0x67, 0x2f, 0x76, 0x6f, 0x64, 0x6f, 0x7a, 0x65, 0x6d,
+    0x61, 0x63, 0x2f, 0x62, 0x6c, 0x6f, 0x62, 0x2f, 0x0a, 0x20, 0x39, 0x32, 0x39, 0x62, 0x62, 0x61,
+    0x66, 0x33, 0x32, 0x35, 0x36, 0x38, 0x36, 0x34, 0x33, 0x35, 0x62, 0x64, 0x64, 0x30, 0x65, 0x64,
+    0x30, 0x64, 0x30, 0x63, 0x63, 0x34, 0x35, 0x62, 0x30, 0x63, 0x62, 0x61, 0x64, 0x33, 0x34, 0x33,
+    0x30, 0x64, 0x2f, 0x73, 0x72, 0x63, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x63, 0x75, 0x72,
+    0x76, 0x65, 0x32, 0x35, 0x35, 0x31, 0x39, 0x2e, 0x72, 0x73, 0x23, 0x4c, 0x31, 0x30, 0x30, 0x29,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x03, 0x00, 0x01, 0x12, 0x03, 0x16, 0x0c, 0x1f, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x12, 0x03, 0x17, 0x08, 0x18, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x17, 0x08, 0x0d, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x17, 0x0e, 0x13, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x00, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x17, 0x16, 0x17, 0x0a, 0x7b,
+    0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x1d, 0x00, 0x22, 0x01, 0x1a, 0x6f, 0x20, 0x41, 0x20, 0x6b,
+    0x65, 0x79, 0x20, 0x6c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x20,
+    0x58, 0x4d, 0x54, 0x50, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x28, 0x65, 0x2e,
+    0x67, 0x2e, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x77,
+    0x61, 0x6c, 0x6c, 0x65, 0x74, 0x29, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x75, 0x72, 0x70,
+    0x6f, 0x73, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x69,
+    0x73, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x01, 0x01, 0x12, 0x03, 0x1d, 0x08, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12,
+    0x03, 0x1e, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1e,
+    0x04, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1e, 0x1a, 0x1d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1e, 0x20, 0x21, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x01, 0x08, 0x00, 0x12, 0x04, 0x1f, 0x04, 0x21, 0x05, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x08, 0x00, 0x01, 0x12, 0x03, 0x1f, 0x0a, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x01, 0x12, 0x03, 0x20, 0x08, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06,
+    0x12, 0x03, 0x20, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x20, 0x1a, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x20, 0x24,
+    0x25, 0x0a, 0x90, 0x01, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x26, 0x00, 0x2a, 0x01, 0x1a, 0x83,
+    0x01, 0x20, 0x41, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x6c, 0x69, 0x6e, 0x6b, 0x65, 0x64, 0x20, 0x74,
+    0x6f, 0x20, 0x61, 0x6e, 0x20, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x20, 0x28, 0x65, 0x2e, 0x67, 0x2e, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x62,
+    0x79, 0x20, 0x61, 0x6e, 0x20, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x20, 0x6b, 0x65, 0x79, 0x29, 0x0a,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x75, 0x72, 0x70, 0x6f, 0x73, 0x65, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64,
+    0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74,
+    0x75, 0x72, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x26, 0x08, 0x21,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x27, 0x04, 0x22, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x27, 0x04, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x27, 0x1a, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x27, 0x20, 0x21, 0x0a, 0x51, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x29, 0x04, 0x21, 0x1a, 0x44, 0x20, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65,
+    0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65,
+    0x64, 0x20, 0x60, 0x6b, 0x65, 0x79, 0x60, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69,
+    0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x27, 0x73, 0x20, 0x45, 0x64,
+    0x32, 0x35, 0x35, 0x31, 0x39, 0x20, 0x6b, 0x65, 0x79, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x01, 0x06, 0x12, 0x03, 0x29, 0x04, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x29, 0x13, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x29, 0x1f, 0x20, 0x0a, 0xa6, 0x02, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x31, 0x00, 0x34,
+    0x01, 0x1a, 0x99, 0x02, 0x20, 0x41, 0x20, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x6f, 0x6e, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x73, 0x20, 0x75,
+    0x70, 0x6c, 0x6f, 0x61, 0x64, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x63, 0x6c, 0x69,
+    0x65, 0x6e, 0x74, 0x2c, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20,
+    0x61, 0x73, 0x0a, 0x20, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x28, 0x58, 0x29,
+    0x33, 0x44, 0x48, 0x20, 0x65, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x20, 0x69, 0x74, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65,
+    0x72, 0x20, 0x69, 0x73, 0x20, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f,
+    0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x0a, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x64, 0x65, 0x6c, 0x65,
+    0x74, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x70, 0x72, 0x65, 0x6b, 0x65, 0x79, 0x20, 0x74, 0x6f,
+    0x20, 0x61, 0x6e, 0x79, 0x6f, 0x6e, 0x65, 0x20, 0x77, 0x68, 0x6f, 0x20, 0x72, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x73, 0x20, 0x6f, 0x6e, 0x65, 0x2e, 0x0a, 0x20, 0x49, 0x6e, 0x20, 0x6f, 0x75,
+    0x72, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x74,
+    0x79, 0x70, 0x65, 0x20, 0x77, 0x65, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x6e, 0x6f, 0x74, 0x20,
+    0x61, 0x63, 0x74, 0x75, 0x61, 0x6c, 0x6c, 0x79, 0x20, 0x75, 0x73, 0x65, 0x20, 0x6f, 0x6e, 0x65,
+    0x2d, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x70, 0x72, 0x65, 0x6b, 0x65, 0x79, 0x73, 0x2c, 0x0a, 0x20,
+    0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x20, 0x66, 0x61,
+    0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x20, 0x6b, 0x65, 0x79, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x31, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x00, 0x12, 0x03, 0x32, 0x04, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12,
+    0x03, 0x32, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x32,
+    0x19, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x32, 0x28, 0x29,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x33, 0x04, 0x39, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x33, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x33, 0x0d, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x33, 0x27, 0x34, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x33, 0x37, 0x38, 0x0a, 0x9b, 0x02, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x3b, 0x00,
+    0x3e, 0x01, 0x1a, 0x8e, 0x02, 0x20, 0x41, 0x20, 0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b,
+    0x20, 0x6b, 0x65, 0x79, 0x20, 0x75, 0x70, 0x6c, 0x6f, 0x61, 0x64, 0x65, 0x64, 0x20, 0x62, 0x79,
+    0x20, 0x61, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68,
+    0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x65, 0x78,
+    0x69, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x0a, 0x20, 0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b,
+    0x20, 0x6b, 0x65, 0x79, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72,
+    0x20, 0x69, 0x73, 0x20, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
+    0x73, 0x65, 0x72, 0x76, 0x65, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x70, 0x72, 0x65, 0x6b, 0x65,
+    0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x0a, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x6f, 0x6e, 0x65, 0x2d,
+    0x74, 0x69, 0x6d, 0x65, 0x20, 0x70, 0x72, 0x65, 0x6b, 0x65, 0x79, 0x73, 0x20, 0x68, 0x61, 0x76,
+    0x65, 0x20, 0x62, 0x65, 0x65, 0x6e, 0x20, 0x65, 0x78, 0x68, 0x61, 0x75, 0x73, 0x74, 0x65, 0x64,
+    0x2e, 0x0a, 0x20, 0x49, 0x6e, 0x20, 0x6f, 0x75, 0x72, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61,
+    0x6c, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x74, 0x79, 0x70, 0x65, 0x20, 0x77, 0x65, 0x20, 0x77,
+    0x69, 0x6c, 0x6c, 0x20, 0x61, 0x6c, 0x77, 0x61, 0x79, 0x73, 0x20, 0x75, 0x73, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x66, 0x61, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x20, 0x6b, 0x65, 0x79, 0x20,
+    0x69, 0x6e, 0x20, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x0a, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x79,
+    0x20, 0x6f, 0x6e, 0x65, 0x2d, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x70, 0x72, 0x65, 0x6b, 0x65, 0x79,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x3b, 0x08, 0x1f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x3c, 0x04, 0x2a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x3c, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x3c, 0x19, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x3c, 0x28, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03,
+    0x3d, 0x04, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x3d, 0x04,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3d, 0x1e, 0x2a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3d, 0x2d, 0x2e, 0x0a, 0x4a, 0x0a,
+    0x02, 0x04, 0x05, 0x12, 0x04, 0x41, 0x00, 0x49, 0x01, 0x1a, 0x3e, 0x20, 0x41, 0x20, 0x63, 0x6f,
+    0x6e, 0x74, 0x61, 0x63, 0x74, 0x20, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x20, 0x73, 0x65, 0x72,
+    0x76, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65,
+    0x72, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x69, 0x6e,
+    0x67, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01,
+    0x12, 0x03, 0x41, 0x08, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x42,
+    0x04, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x06, 0x12, 0x03, 0x42, 0x04, 0x18,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x42, 0x19, 0x25, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x42, 0x28, 0x29, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x43, 0x04, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x01, 0x06, 0x12, 0x03, 0x43, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x43, 0x1e, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x43, 0x2d, 0x2e, 0x0a, 0x42, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x45, 0x04, 0x34,
+    0x1a, 0x35, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x73, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x20, 0x6d, 0x65,
+    0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70,
+    0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x06,
+    0x12, 0x03, 0x45, 0x04, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x45, 0x1e, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x45, 0x32,
+    0x33, 0x0a, 0x65, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x48, 0x04, 0x1a, 0x1a, 0x58,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x27, 0x73, 0x20, 0x45, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x20, 0x69, 0x64, 0x65, 0x6e,
+    0x74, 0x69, 0x74, 0x79, 0x20, 0x6b, 0x65, 0x79, 0x2c, 0x20, 0x63, 0x6f, 0x76, 0x65, 0x72, 0x65,
+    0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x73, 0x73, 0x6f, 0x63, 0x69, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x6e, 0x0a, 0x20, 0x60, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69,
+    0x74, 0x79, 0x5f, 0x6b, 0x65, 0x79, 0x60, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03,
+    0x05, 0x12, 0x03, 0x48, 0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x48, 0x0a, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x48,
+    0x18, 0x19, 0x0a, 0x60, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x4d, 0x00, 0x51, 0x01, 0x1a, 0x54,
+    0x20, 0x41, 0x20, 0x77, 0x72, 0x61, 0x70, 0x70, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x76,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69,
+    0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74,
+    0x61, 0x63, 0x74, 0x20, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6c,
+    0x6c, 0x6f, 0x77, 0x0a, 0x20, 0x75, 0x70, 0x67, 0x72, 0x61, 0x64, 0x65, 0x61, 0x62, 0x69, 0x6c,
+    0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x4d, 0x08, 0x21,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x06, 0x08, 0x00, 0x12, 0x04, 0x4e, 0x04, 0x50, 0x05, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x08, 0x00, 0x01, 0x12, 0x03, 0x4e, 0x0a, 0x11, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x4f, 0x08, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x4f, 0x08, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x4f, 0x2a, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x4f, 0x2f, 0x30, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33, 0x0a, 0x97, 0x0e, 0x0a, 0x20,
+    0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65,
+    0x6e, 0x74, 0x73, 0x2f, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x12, 0x18, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x1a, 0x24, 0x76, 0x33, 0x2f, 0x6d,
+    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2f,
+    0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x5f, 0x6b, 0x65, 0x79, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x22, 0x44, 0x0a, 0x0c, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x56, 0x31,
+    0x12, 0x34, 0x0a, 0x16, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x65, 0x5f, 0x77, 0x61, 0x6c, 0x6c,
+    0x65, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x14, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x65, 0x57, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x41,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x22, 0x85, 0x01, 0x0a, 0x14, 0x49, 0x6e, 0x76, 0x69, 0x74,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x56, 0x31, 0x12,
+    0x4d, 0x0a, 0x07, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x33, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61,
+    0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x49, 0x6e, 0x73, 0x74,
+    0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74, 0x42,
+    0x75, 0x6e, 0x64, 0x6c, 0x65, 0x52, 0x07, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x72, 0x12, 0x1e,
+    0x0a, 0x0a, 0x63, 0x69, 0x70, 0x68, 0x65, 0x72, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x0a, 0x63, 0x69, 0x70, 0x68, 0x65, 0x72, 0x74, 0x65, 0x78, 0x74, 0x22, 0x61,
+    0x0a, 0x12, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x45, 0x6e, 0x76, 0x65,
+    0x6c, 0x6f, 0x70, 0x65, 0x12, 0x40, 0x0a, 0x02, 0x76, 0x31, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x2e, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61,
+    0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x49, 0x6e, 0x76, 0x69,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x56, 0x31,
+    0x48, 0x00, 0x52, 0x02, 0x76, 0x31, 0x42, 0x09, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f,
+    0x6e, 0x42, 0xda, 0x01, 0x0a, 0x1c, 0x63, 0x6f, 0x6d, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76,
+    0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e,
+    0x74, 0x73, 0x42, 0x0b, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50,
     0x01, 0x5a, 0x2f, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x78, 0x6d,
     0x74, 0x70, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2f, 0x76, 0x33, 0x2f, 0x67, 0x6f, 0x2f, 0x76,
     0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e,
@@ -829,159 +739,308 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x6d, 0x74, 0x70, 0x5c, 0x56, 0x33, 0x5c, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f,
     0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61,
     0x74, 0x61, 0xea, 0x02, 0x19, 0x58, 0x6d, 0x74, 0x70, 0x3a, 0x3a, 0x56, 0x33, 0x3a, 0x3a, 0x4d,
-    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x4a, 0xf5,
-    0x12, 0x0a, 0x06, 0x12, 0x04, 0x01, 0x00, 0x3c, 0x01, 0x0a, 0x28, 0x0a, 0x01, 0x0c, 0x12, 0x03,
-    0x01, 0x00, 0x12, 0x1a, 0x1e, 0x20, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x75, 0x72, 0x65, 0x20,
-    0x66, 0x6f, 0x72, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x20, 0x69, 0x6e, 0x20,
-    0x76, 0x33, 0x0a, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x03, 0x00, 0x21, 0x0a, 0x09, 0x0a,
-    0x02, 0x03, 0x00, 0x12, 0x03, 0x05, 0x00, 0x2f, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x07,
-    0x00, 0x46, 0x0a, 0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12, 0x03, 0x07, 0x00, 0x46, 0x0a, 0xc6, 0x01,
-    0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x0d, 0x00, 0x13, 0x01, 0x1a, 0xb9, 0x01, 0x20, 0x4d, 0x65,
-    0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x73, 0x20, 0x65,
-    0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x76, 0x69, 0x61, 0x20, 0x53, 0x65, 0x61,
-    0x6c, 0x65, 0x64, 0x53, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x6f, 0x6e,
-    0x6c, 0x79, 0x20, 0x76, 0x69, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x0a, 0x20, 0x43, 0x75, 0x72,
-    0x72, 0x65, 0x6e, 0x74, 0x6c, 0x79, 0x20, 0x77, 0x65, 0x20, 0x64, 0x6f, 0x20, 0x6e, 0x6f, 0x74,
-    0x20, 0x61, 0x63, 0x74, 0x75, 0x61, 0x6c, 0x6c, 0x79, 0x20, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70,
-    0x74, 0x20, 0x74, 0x68, 0x69, 0x73, 0x2c, 0x20, 0x61, 0x63, 0x74, 0x75, 0x61, 0x6c, 0x20, 0x69,
-    0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66,
-    0x0a, 0x20, 0x53, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x53, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x20, 0x77,
-    0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x61, 0x64, 0x64, 0x65, 0x64, 0x20, 0x73, 0x68, 0x6f,
-    0x72, 0x74, 0x6c, 0x79, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0d,
-    0x08, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0e, 0x04, 0x23, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0e, 0x04, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x0b, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0e, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
-    0x01, 0x12, 0x03, 0x0f, 0x04, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12,
-    0x03, 0x0f, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0f,
-    0x0b, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0f, 0x24, 0x25,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x10, 0x04, 0x26, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x10, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x10, 0x0b, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x03, 0x12, 0x03, 0x10, 0x24, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12,
-    0x03, 0x11, 0x04, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x11,
-    0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x11, 0x0b, 0x24,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x11, 0x27, 0x28, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x12, 0x04, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x04, 0x05, 0x12, 0x03, 0x12, 0x04, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x04, 0x01, 0x12, 0x03, 0x12, 0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03,
-    0x12, 0x03, 0x12, 0x1d, 0x1e, 0x0a, 0xc9, 0x01, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x18, 0x00,
-    0x1b, 0x01, 0x1a, 0xbc, 0x01, 0x20, 0x50, 0x6c, 0x61, 0x69, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x20,
-    0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x64, 0x20,
-    0x77, 0x69, 0x74, 0x68, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x2c, 0x20, 0x76,
-    0x69, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6c, 0x6c, 0x0a, 0x20, 0x52,
-    0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x63, 0x61, 0x6e, 0x20, 0x76, 0x65,
-    0x72, 0x69, 0x66, 0x79, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72,
-    0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x65, 0x6e, 0x20, 0x74, 0x61,
-    0x6d, 0x70, 0x65, 0x72, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x2e, 0x0a, 0x20, 0x53, 0x65,
-    0x72, 0x76, 0x65, 0x72, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x75, 0x6e, 0x61, 0x62, 0x6c, 0x65,
-    0x20, 0x74, 0x6f, 0x20, 0x76, 0x65, 0x72, 0x69, 0x66, 0x79, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x68, 0x61, 0x73, 0x20, 0x62, 0x65, 0x65,
-    0x6e, 0x20, 0x74, 0x61, 0x6d, 0x70, 0x65, 0x72, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x2e,
-    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x18, 0x08, 0x1c, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x19, 0x04, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x19, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x19, 0x0b, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x19, 0x15, 0x16, 0x0a, 0x2b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x1a, 0x04,
-    0x1e, 0x22, 0x1e, 0x20, 0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61,
-    0x67, 0x65, 0x53, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1a, 0x04, 0x09, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1a, 0x0a, 0x19, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1a, 0x1c, 0x1d, 0x0a, 0x48, 0x0a, 0x02, 0x05,
-    0x00, 0x12, 0x04, 0x1e, 0x00, 0x21, 0x01, 0x1a, 0x3c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x76, 0x65,
-    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x64, 0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x70, 0x61, 0x64,
-    0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x70, 0x61, 0x79,
-    0x6c, 0x6f, 0x61, 0x64, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x1e, 0x05,
-    0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x1f, 0x04, 0x34, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1f, 0x04, 0x2f, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x1f, 0x32, 0x33, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00,
-    0x02, 0x01, 0x12, 0x03, 0x20, 0x04, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01,
-    0x12, 0x03, 0x20, 0x04, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03,
-    0x20, 0x2a, 0x2b, 0x0a, 0x8b, 0x06, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x31, 0x00, 0x36, 0x01,
-    0x1a, 0xfe, 0x05, 0x20, 0x45, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x62, 0x6f,
-    0x64, 0x79, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68,
-    0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20,
-    0x76, 0x69, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x63, 0x69, 0x70,
-    0x69, 0x65, 0x6e, 0x74, 0x73, 0x0a, 0x20, 0x57, 0x68, 0x65, 0x6e, 0x20, 0x72, 0x65, 0x63, 0x65,
-    0x69, 0x76, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x3a,
-    0x0a, 0x20, 0x31, 0x2e, 0x20, 0x44, 0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x73, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
-    0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x76,
-    0x69, 0x61, 0x20, 0x53, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x53, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x0a,
-    0x20, 0x32, 0x2e, 0x20, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
-    0x79, 0x6f, 0x75, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65,
-    0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x61, 0x64, 0x64,
-    0x72, 0x65, 0x73, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x72, 0x65, 0x63,
+    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x4a, 0x9c,
+    0x09, 0x0a, 0x06, 0x12, 0x04, 0x01, 0x00, 0x24, 0x01, 0x0a, 0x27, 0x0a, 0x01, 0x0c, 0x12, 0x03,
+    0x01, 0x00, 0x12, 0x1a, 0x1d, 0x20, 0x56, 0x33, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x20,
+    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x75, 0x72,
+    0x65, 0x0a, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x03, 0x00, 0x21, 0x0a, 0x09, 0x0a, 0x02,
+    0x03, 0x00, 0x12, 0x03, 0x05, 0x00, 0x2e, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x07, 0x00,
+    0x46, 0x0a, 0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12, 0x03, 0x07, 0x00, 0x46, 0x0a, 0xc9, 0x01, 0x0a,
+    0x02, 0x04, 0x00, 0x12, 0x04, 0x0d, 0x00, 0x14, 0x01, 0x1a, 0xbc, 0x01, 0x20, 0x49, 0x6e, 0x76,
+    0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x56, 0x31, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6d, 0x65, 0x73, 0x73,
+    0x61, 0x67, 0x65, 0x20, 0x6d, 0x65, 0x61, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x20,
+    0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x61, 0x73, 0x0a, 0x20, 0x63, 0x69,
+    0x70, 0x68, 0x65, 0x72, 0x74, 0x65, 0x78, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x49, 0x6e, 0x76, 0x69,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x56, 0x31,
+    0x20, 0x61, 0x6e, 0x64, 0x20, 0x64, 0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x62,
+    0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x20,
+    0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69,
+    0x64, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x72, 0x20, 0x60, 0x49, 0x6e, 0x73,
+    0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74,
+    0x42, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x60, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12,
+    0x03, 0x0d, 0x08, 0x14, 0x0a, 0xe2, 0x02, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x12,
+    0x04, 0x26, 0x1a, 0xa4, 0x02, 0x20, 0x49, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76,
+    0x69, 0x74, 0x65, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74, 0x20, 0x62, 0x75, 0x6e,
+    0x64, 0x6c, 0x65, 0x20, 0x68, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65,
+    0x20, 0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20,
+    0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x0a, 0x20,
+    0x75, 0x73, 0x65, 0x72, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65,
+    0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x77,
+    0x61, 0x6c, 0x6c, 0x65, 0x74, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x2e, 0x20, 0x49, 0x66, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74,
+    0x65, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74, 0x20, 0x62, 0x75, 0x6e, 0x64, 0x6c,
+    0x65, 0x20, 0x68, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65, 0x72, 0x65, 0x6e,
+    0x74, 0x20, 0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x65, 0x20, 0x77, 0x61,
+    0x6c, 0x6c, 0x65, 0x74, 0x0a, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x4d, 0x55,
+    0x53, 0x54, 0x20, 0x62, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74,
+    0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x2e, 0x0a, 0x22, 0x2e, 0x20, 0x54, 0x4f, 0x44, 0x4f,
+    0x3a, 0x20, 0x44, 0x65, 0x63, 0x69, 0x64, 0x65, 0x20, 0x77, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72,
+    0x20, 0x77, 0x65, 0x20, 0x6e, 0x65, 0x65, 0x64, 0x20, 0x61, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x65,
+    0x78, 0x74, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x12, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x12, 0x0b, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x12, 0x24, 0x25, 0x0a, 0x65, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x18, 0x00, 0x1d, 0x01, 0x1a,
+    0x59, 0x20, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x45, 0x6e, 0x76, 0x65,
+    0x6c, 0x6f, 0x70, 0x65, 0x56, 0x31, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e,
+    0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x63, 0x74, 0x20, 0x6f, 0x66, 0x0a, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01,
+    0x01, 0x12, 0x03, 0x18, 0x08, 0x1c, 0x0a, 0x57, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03,
+    0x1a, 0x04, 0x2a, 0x1a, 0x4a, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61,
+    0x69, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x6b,
+    0x65, 0x79, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20,
+    0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x64, 0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x69, 0x70, 0x68, 0x65, 0x72, 0x74, 0x65, 0x78, 0x74, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1a, 0x04, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1a, 0x1e, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1a, 0x28, 0x29, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x01, 0x12, 0x03, 0x1c, 0x04, 0x19, 0x1a, 0x28, 0x20, 0x43, 0x6f, 0x72, 0x72, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x64, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x20, 0x49, 0x6e, 0x76, 0x69, 0x74,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x56, 0x31, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1c, 0x04, 0x09, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1c, 0x0a, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1c, 0x17, 0x18, 0x0a, 0x22, 0x0a, 0x02, 0x04, 0x02,
+    0x12, 0x04, 0x20, 0x00, 0x24, 0x01, 0x1a, 0x16, 0x20, 0x57, 0x72, 0x61, 0x70, 0x70, 0x65, 0x72,
+    0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x0a, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x20, 0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x02,
+    0x08, 0x00, 0x12, 0x04, 0x21, 0x04, 0x23, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x08, 0x00,
+    0x01, 0x12, 0x03, 0x21, 0x0a, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03,
+    0x22, 0x08, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x22, 0x08,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x1d, 0x1f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x22, 0x22, 0x23, 0x62, 0x06, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x33, 0x0a, 0xa8, 0x1c, 0x0a, 0x21, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73,
+    0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2f, 0x6d, 0x65,
+    0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x18, 0x78, 0x6d, 0x74,
+    0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x6e, 0x74, 0x73, 0x1a, 0x25, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2f, 0x61, 0x73, 0x73, 0x6f, 0x63,
+    0x69, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xa2, 0x02, 0x0a,
+    0x1c, 0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x53,
+    0x65, 0x61, 0x6c, 0x65, 0x64, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x12, 0x2e, 0x0a,
+    0x13, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x61, 0x64, 0x64,
+    0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x11, 0x73, 0x65, 0x6e, 0x64,
+    0x65, 0x72, 0x55, 0x73, 0x65, 0x72, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x34, 0x0a,
+    0x16, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x14, 0x73,
+    0x65, 0x6e, 0x64, 0x65, 0x72, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x49, 0x64, 0x12, 0x34, 0x0a, 0x16, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74,
+    0x5f, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x14, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x55, 0x73,
+    0x65, 0x72, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x3a, 0x0a, 0x19, 0x72, 0x65, 0x63,
     0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x5f, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74,
-    0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x2e, 0x20, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x20, 0x74,
-    0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x69,
-    0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x20, 0x6d,
-    0x61, 0x74, 0x63, 0x68, 0x65, 0x73, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
-    0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65,
-    0x73, 0x73, 0x2e, 0x0a, 0x20, 0x32, 0x2e, 0x20, 0x46, 0x69, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x72, 0x65, 0x6c, 0x65, 0x76, 0x61, 0x6e, 0x74, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f,
-    0x6e, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6e, 0x64,
-    0x65, 0x72, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20,
-    0x61, 0x6e, 0x64, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x69,
-    0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x20, 0x69,
-    0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x20, 0x6d,
-    0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x0a, 0x20, 0x33, 0x2e, 0x20, 0x55, 0x73, 0x65, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x64,
-    0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x79, 0x6c, 0x6f,
-    0x61, 0x64, 0x0a, 0x20, 0x34, 0x2e, 0x20, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x20, 0x74, 0x68,
-    0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x5f, 0x73, 0x69,
-    0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x64,
-    0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64,
-    0x20, 0x77, 0x61, 0x73, 0x20, 0x70, 0x72, 0x6f, 0x64, 0x75, 0x63, 0x65, 0x64, 0x20, 0x62, 0x79,
-    0x0a, 0x20, 0x20, 0x20, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x20, 0x77, 0x69,
-    0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x20, 0x6b,
-    0x65, 0x79, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x0a,
-    0x20, 0x20, 0x20, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x69, 0x6e, 0x73, 0x74, 0x61,
-    0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x0a, 0x20, 0x35, 0x2e, 0x20, 0x56,
-    0x65, 0x72, 0x69, 0x66, 0x79, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x62, 0x6f, 0x74, 0x68, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x20,
-    0x61, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x5f, 0x75, 0x73,
-    0x65, 0x72, 0x20, 0x61, 0x72, 0x65, 0x20, 0x70, 0x61, 0x72, 0x74, 0x69, 0x70, 0x61, 0x6e, 0x74,
-    0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x63, 0x6f, 0x6e,
-    0x76, 0x65, 0x72, 0x73, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x72, 0x65, 0x66, 0x65, 0x72, 0x65,
-    0x6e, 0x63, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x63, 0x6f, 0x6e, 0x76, 0x6f, 0x5f, 0x69, 0x64,
-    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x31, 0x08, 0x1d, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x32, 0x04, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x06, 0x12, 0x03, 0x32, 0x04, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x32, 0x21, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x32, 0x33, 0x34, 0x0a, 0x29, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x33, 0x04,
-    0x28, 0x22, 0x1c, 0x20, 0x53, 0x69, 0x67, 0x6e, 0x73, 0x20, 0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63,
-    0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x06, 0x12, 0x03, 0x33, 0x04, 0x12, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x33, 0x13, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x33, 0x26, 0x27, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02,
-    0x02, 0x12, 0x03, 0x34, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12,
-    0x03, 0x34, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x34,
-    0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x34, 0x16, 0x17,
-    0x0a, 0x1d, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x35, 0x04, 0x1c, 0x22, 0x10, 0x20,
-    0x45, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x05, 0x12, 0x03, 0x35, 0x04, 0x09, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x35, 0x0a, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x35, 0x1a, 0x1b, 0x0a, 0x46, 0x0a, 0x02, 0x04, 0x03, 0x12,
-    0x04, 0x39, 0x00, 0x3c, 0x01, 0x1a, 0x3a, 0x20, 0x43, 0x6f, 0x6d, 0x62, 0x69, 0x6e, 0x65, 0x73,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6c, 0x61, 0x69, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x20, 0x68,
-    0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65,
-    0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64,
-    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x39, 0x08, 0x1e, 0x0a, 0x23, 0x0a,
-    0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x3a, 0x04, 0x1b, 0x22, 0x16, 0x20, 0x50, 0x61, 0x64,
-    0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65,
-    0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3a, 0x04, 0x09,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3a, 0x0a, 0x16, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3a, 0x19, 0x1a, 0x0a, 0x2e, 0x0a, 0x04,
-    0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x04, 0x19, 0x22, 0x21, 0x20, 0x45, 0x6e, 0x63, 0x72,
-    0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73,
-    0x73, 0x61, 0x67, 0x65, 0x50, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3b, 0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x3b, 0x0a, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x3b, 0x17, 0x18, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x17, 0x72, 0x65,
+    0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x49, 0x64, 0x12, 0x2a, 0x0a, 0x11, 0x69, 0x73, 0x5f, 0x70, 0x72, 0x65, 0x6b,
+    0x65, 0x79, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08,
+    0x52, 0x0f, 0x69, 0x73, 0x50, 0x72, 0x65, 0x6b, 0x65, 0x79, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x22, 0x58, 0x0a, 0x14, 0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73,
+    0x61, 0x67, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x12, 0x17, 0x0a, 0x07, 0x73, 0x65, 0x6e,
+    0x74, 0x5f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x06, 0x73, 0x65, 0x6e, 0x74,
+    0x4e, 0x73, 0x12, 0x27, 0x0a, 0x0f, 0x73, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x5f, 0x6d, 0x65, 0x74,
+    0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0e, 0x73, 0x65, 0x61,
+    0x6c, 0x65, 0x64, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x22, 0x8d, 0x02, 0x0a, 0x15,
+    0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x50, 0x61,
+    0x79, 0x6c, 0x6f, 0x61, 0x64, 0x12, 0x5f, 0x0a, 0x0f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x36,
+    0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63,
+    0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x50, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x56,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x0e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x56,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x53, 0x0a, 0x10, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72,
+    0x5f, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x28, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e, 0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61,
+    0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x45, 0x64, 0x44, 0x73,
+    0x61, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x52, 0x0f, 0x68, 0x65, 0x61, 0x64,
+    0x65, 0x72, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x19, 0x0a, 0x08, 0x63,
+    0x6f, 0x6e, 0x76, 0x6f, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63,
+    0x6f, 0x6e, 0x76, 0x6f, 0x49, 0x64, 0x12, 0x23, 0x0a, 0x0d, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e,
+    0x74, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0c, 0x63,
+    0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x42, 0x79, 0x74, 0x65, 0x73, 0x22, 0x5b, 0x0a, 0x16, 0x50,
+    0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x45, 0x6e, 0x76,
+    0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x21, 0x0a, 0x0c, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x5f,
+    0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0b, 0x68, 0x65, 0x61,
+    0x64, 0x65, 0x72, 0x42, 0x79, 0x74, 0x65, 0x73, 0x12, 0x1e, 0x0a, 0x0a, 0x63, 0x69, 0x70, 0x68,
+    0x65, 0x72, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0a, 0x63, 0x69,
+    0x70, 0x68, 0x65, 0x72, 0x74, 0x65, 0x78, 0x74, 0x2a, 0x78, 0x0a, 0x1c, 0x50, 0x61, 0x64, 0x6c,
+    0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x50, 0x61, 0x79, 0x6c, 0x6f, 0x61,
+    0x64, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x2f, 0x0a, 0x2b, 0x50, 0x41, 0x44, 0x4c,
+    0x4f, 0x43, 0x4b, 0x5f, 0x4d, 0x45, 0x53, 0x53, 0x41, 0x47, 0x45, 0x5f, 0x50, 0x41, 0x59, 0x4c,
+    0x4f, 0x41, 0x44, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x55, 0x4e, 0x53, 0x50,
+    0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x27, 0x0a, 0x23, 0x50, 0x41, 0x44,
+    0x4c, 0x4f, 0x43, 0x4b, 0x5f, 0x4d, 0x45, 0x53, 0x53, 0x41, 0x47, 0x45, 0x5f, 0x50, 0x41, 0x59,
+    0x4c, 0x4f, 0x41, 0x44, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x5f, 0x4f, 0x4e, 0x45,
+    0x10, 0x01, 0x42, 0xdb, 0x01, 0x0a, 0x1c, 0x63, 0x6f, 0x6d, 0x2e, 0x78, 0x6d, 0x74, 0x70, 0x2e,
+    0x76, 0x33, 0x2e, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65,
+    0x6e, 0x74, 0x73, 0x42, 0x0c, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x50, 0x72, 0x6f, 0x74,
+    0x6f, 0x50, 0x01, 0x5a, 0x2f, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f,
+    0x78, 0x6d, 0x74, 0x70, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2f, 0x76, 0x33, 0x2f, 0x67, 0x6f,
+    0x2f, 0x76, 0x33, 0x2f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x63, 0x6f, 0x6e, 0x74,
+    0x65, 0x6e, 0x74, 0x73, 0xa2, 0x02, 0x03, 0x58, 0x56, 0x4d, 0xaa, 0x02, 0x17, 0x58, 0x6d, 0x74,
+    0x70, 0x2e, 0x56, 0x33, 0x2e, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74,
+    0x65, 0x6e, 0x74, 0x73, 0xca, 0x02, 0x17, 0x58, 0x6d, 0x74, 0x70, 0x5c, 0x56, 0x33, 0x5c, 0x4d,
+    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0xe2, 0x02,
+    0x23, 0x58, 0x6d, 0x74, 0x70, 0x5c, 0x56, 0x33, 0x5c, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61,
+    0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x19, 0x58, 0x6d, 0x74, 0x70, 0x3a, 0x3a, 0x56, 0x33, 0x3a,
+    0x3a, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x73,
+    0x4a, 0xf5, 0x12, 0x0a, 0x06, 0x12, 0x04, 0x01, 0x00, 0x3c, 0x01, 0x0a, 0x28, 0x0a, 0x01, 0x0c,
+    0x12, 0x03, 0x01, 0x00, 0x12, 0x1a, 0x1e, 0x20, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x75, 0x72,
+    0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x20, 0x69,
+    0x6e, 0x20, 0x76, 0x33, 0x0a, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x03, 0x00, 0x21, 0x0a,
+    0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x05, 0x00, 0x2f, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12,
+    0x03, 0x07, 0x00, 0x46, 0x0a, 0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12, 0x03, 0x07, 0x00, 0x46, 0x0a,
+    0xc6, 0x01, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x0d, 0x00, 0x13, 0x01, 0x1a, 0xb9, 0x01, 0x20,
+    0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x73,
+    0x20, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x76, 0x69, 0x61, 0x20, 0x53,
+    0x65, 0x61, 0x6c, 0x65, 0x64, 0x53, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x20, 0x61, 0x6e, 0x64, 0x20,
+    0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x76, 0x69, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x0a, 0x20, 0x43,
+    0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x6c, 0x79, 0x20, 0x77, 0x65, 0x20, 0x64, 0x6f, 0x20, 0x6e,
+    0x6f, 0x74, 0x20, 0x61, 0x63, 0x74, 0x75, 0x61, 0x6c, 0x6c, 0x79, 0x20, 0x65, 0x6e, 0x63, 0x72,
+    0x79, 0x70, 0x74, 0x20, 0x74, 0x68, 0x69, 0x73, 0x2c, 0x20, 0x61, 0x63, 0x74, 0x75, 0x61, 0x6c,
+    0x20, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x6f, 0x66, 0x0a, 0x20, 0x53, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x53, 0x65, 0x6e, 0x64, 0x65, 0x72,
+    0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x61, 0x64, 0x64, 0x65, 0x64, 0x20, 0x73,
+    0x68, 0x6f, 0x72, 0x74, 0x6c, 0x79, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12,
+    0x03, 0x0d, 0x08, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0e, 0x04,
+    0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0e, 0x04, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x0b, 0x1e, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0e, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x04, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x0f, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x0f, 0x0b, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0f,
+    0x24, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x10, 0x04, 0x26, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x10, 0x04, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x10, 0x0b, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x10, 0x24, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x03, 0x12, 0x03, 0x11, 0x04, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12,
+    0x03, 0x11, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x11,
+    0x0b, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x11, 0x27, 0x28,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x12, 0x04, 0x1f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x04, 0x05, 0x12, 0x03, 0x12, 0x04, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x12, 0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x04, 0x03, 0x12, 0x03, 0x12, 0x1d, 0x1e, 0x0a, 0xc9, 0x01, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04,
+    0x18, 0x00, 0x1b, 0x01, 0x1a, 0xbc, 0x01, 0x20, 0x50, 0x6c, 0x61, 0x69, 0x6e, 0x74, 0x65, 0x78,
+    0x74, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65,
+    0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x2c,
+    0x20, 0x76, 0x69, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6c, 0x6c, 0x0a,
+    0x20, 0x52, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x63, 0x61, 0x6e, 0x20,
+    0x76, 0x65, 0x72, 0x69, 0x66, 0x79, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x68, 0x65, 0x61, 0x64,
+    0x65, 0x72, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x65, 0x6e, 0x20,
+    0x74, 0x61, 0x6d, 0x70, 0x65, 0x72, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x2e, 0x0a, 0x20,
+    0x53, 0x65, 0x72, 0x76, 0x65, 0x72, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x75, 0x6e, 0x61, 0x62,
+    0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x76, 0x65, 0x72, 0x69, 0x66, 0x79, 0x20, 0x69, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x68, 0x61, 0x73, 0x20, 0x62,
+    0x65, 0x65, 0x6e, 0x20, 0x74, 0x61, 0x6d, 0x70, 0x65, 0x72, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x18, 0x08, 0x1c, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x19, 0x04, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x19, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x19, 0x0b, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x19, 0x15, 0x16, 0x0a, 0x2b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03,
+    0x1a, 0x04, 0x1e, 0x22, 0x1e, 0x20, 0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73,
+    0x73, 0x61, 0x67, 0x65, 0x53, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61,
+    0x74, 0x61, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1a, 0x04,
+    0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1a, 0x0a, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1a, 0x1c, 0x1d, 0x0a, 0x48, 0x0a,
+    0x02, 0x05, 0x00, 0x12, 0x04, 0x1e, 0x00, 0x21, 0x01, 0x1a, 0x3c, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x64, 0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x70,
+    0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x70,
+    0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03,
+    0x1e, 0x05, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x1f, 0x04, 0x34,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1f, 0x04, 0x2f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x1f, 0x32, 0x33, 0x0a, 0x0b, 0x0a, 0x04,
+    0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x20, 0x04, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x20, 0x04, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x20, 0x2a, 0x2b, 0x0a, 0x8b, 0x06, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x31, 0x00,
+    0x36, 0x01, 0x1a, 0xfe, 0x05, 0x20, 0x45, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20,
+    0x62, 0x6f, 0x64, 0x79, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x64, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x2c, 0x20, 0x6f, 0x6e, 0x6c,
+    0x79, 0x20, 0x76, 0x69, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x63,
+    0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x73, 0x0a, 0x20, 0x57, 0x68, 0x65, 0x6e, 0x20, 0x72, 0x65,
+    0x63, 0x65, 0x69, 0x76, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x3a, 0x0a, 0x20, 0x31, 0x2e, 0x20, 0x44, 0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61,
+    0x74, 0x61, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72,
+    0x20, 0x76, 0x69, 0x61, 0x20, 0x53, 0x65, 0x61, 0x6c, 0x65, 0x64, 0x53, 0x65, 0x6e, 0x64, 0x65,
+    0x72, 0x0a, 0x20, 0x32, 0x2e, 0x20, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x79, 0x6f, 0x75, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x61,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x72,
+    0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x5f, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x2e, 0x20, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79,
+    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72,
+    0x5f, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64,
+    0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x65, 0x73, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x61, 0x64, 0x64,
+    0x72, 0x65, 0x73, 0x73, 0x2e, 0x0a, 0x20, 0x32, 0x2e, 0x20, 0x46, 0x69, 0x6e, 0x64, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x72, 0x65, 0x6c, 0x65, 0x76, 0x61, 0x6e, 0x74, 0x20, 0x73, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65,
+    0x6e, 0x64, 0x65, 0x72, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x20, 0x61, 0x6e, 0x64, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72,
+    0x5f, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64,
+    0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x61, 0x6c, 0x65, 0x64,
+    0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x0a, 0x20, 0x33, 0x2e, 0x20, 0x55, 0x73,
+    0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x6f,
+    0x20, 0x64, 0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x79,
+    0x6c, 0x6f, 0x61, 0x64, 0x0a, 0x20, 0x34, 0x2e, 0x20, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x20,
+    0x74, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x5f,
+    0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x64, 0x65, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x70, 0x61, 0x79, 0x6c, 0x6f,
+    0x61, 0x64, 0x20, 0x77, 0x61, 0x73, 0x20, 0x70, 0x72, 0x6f, 0x64, 0x75, 0x63, 0x65, 0x64, 0x20,
+    0x62, 0x79, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39,
+    0x20, 0x6b, 0x65, 0x79, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68,
+    0x65, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x69, 0x6e, 0x73,
+    0x74, 0x61, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x0a, 0x20, 0x35, 0x2e,
+    0x20, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x62, 0x6f, 0x74,
+    0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x5f, 0x75, 0x73, 0x65,
+    0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x5f,
+    0x75, 0x73, 0x65, 0x72, 0x20, 0x61, 0x72, 0x65, 0x20, 0x70, 0x61, 0x72, 0x74, 0x69, 0x70, 0x61,
+    0x6e, 0x74, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x63,
+    0x6f, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x72, 0x65, 0x66, 0x65,
+    0x72, 0x65, 0x6e, 0x63, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x63, 0x6f, 0x6e, 0x76, 0x6f, 0x5f,
+    0x69, 0x64, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x31, 0x08, 0x1d, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x32, 0x04, 0x35, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x32, 0x04, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x32, 0x21, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x32, 0x33, 0x34, 0x0a, 0x29, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x33, 0x04, 0x28, 0x22, 0x1c, 0x20, 0x53, 0x69, 0x67, 0x6e, 0x73, 0x20, 0x50, 0x61, 0x64, 0x6c,
+    0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x06, 0x12, 0x03, 0x33, 0x04, 0x12, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x33, 0x13, 0x23, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x33, 0x26, 0x27, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x02, 0x02, 0x02, 0x12, 0x03, 0x34, 0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02,
+    0x05, 0x12, 0x03, 0x34, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x34, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x34,
+    0x16, 0x17, 0x0a, 0x1d, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x35, 0x04, 0x1c, 0x22,
+    0x10, 0x20, 0x45, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x05, 0x12, 0x03, 0x35, 0x04, 0x09, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x35, 0x0a, 0x17, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x35, 0x1a, 0x1b, 0x0a, 0x46, 0x0a, 0x02, 0x04,
+    0x03, 0x12, 0x04, 0x39, 0x00, 0x3c, 0x01, 0x1a, 0x3a, 0x20, 0x43, 0x6f, 0x6d, 0x62, 0x69, 0x6e,
+    0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6c, 0x61, 0x69, 0x6e, 0x74, 0x65, 0x78, 0x74,
+    0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x70, 0x61, 0x79, 0x6c, 0x6f,
+    0x61, 0x64, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x39, 0x08, 0x1e, 0x0a,
+    0x23, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x3a, 0x04, 0x1b, 0x22, 0x16, 0x20, 0x50,
+    0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x48, 0x65, 0x61,
+    0x64, 0x65, 0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3a,
+    0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3a, 0x0a, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3a, 0x19, 0x1a, 0x0a, 0x2e,
+    0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x04, 0x19, 0x22, 0x21, 0x20, 0x45, 0x6e,
+    0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x50, 0x61, 0x64, 0x6c, 0x6f, 0x63, 0x6b, 0x4d,
+    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x50, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3b, 0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3b, 0x0a, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x3b, 0x17, 0x18, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
 include!("xmtp.v3.message_contents.serde.rs");
 // @@protoc_insertion_point(module)
\ No newline at end of file
@@ -8,6 +8,7 @@ impl serde::Serialize for AssociationTextVersion {
         let variant = match self {
             Self::Unspecified => "ASSOCIATION_TEXT_VERSION_UNSPECIFIED",
             Self::AssociationTextVersion1 => "ASSOCIATION_TEXT_VERSION_1",
+            Self::AssociationTextVersion2 => "ASSOCIATION_TEXT_VERSION_2",
         };
         serializer.serialize_str(variant)
     }
@@ -21,6 +22,7 @@ impl<'de> serde::Deserialize<'de> for AssociationTextVersion {
         const FIELDS: &[&str] = &[
             "ASSOCIATION_TEXT_VERSION_UNSPECIFIED",
             "ASSOCIATION_TEXT_VERSION_1",
+            "ASSOCIATION_TEXT_VERSION_2",
         ];
 
         struct GeneratedVisitor;
@@ -65,6 +67,7 @@ impl<'de> serde::Deserialize<'de> for AssociationTextVersion {
                 match value {
                     "ASSOCIATION_TEXT_VERSION_UNSPECIFIED" => Ok(AssociationTextVersion::Unspecified),
                     "ASSOCIATION_TEXT_VERSION_1" => Ok(AssociationTextVersion::AssociationTextVersion1),
+                    "ASSOCIATION_TEXT_VERSION_2" => Ok(AssociationTextVersion::AssociationTextVersion2),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -1630,10 +1633,16 @@ impl serde::Serialize for VmacInstallationLinkedKey {
         if self.key.is_some() {
             len += 1;
         }
+        if self.signature.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("xmtp.v3.message_contents.VmacInstallationLinkedKey", len)?;
         if let Some(v) = self.key.as_ref() {
             struct_ser.serialize_field("key", v)?;
         }
+        if let Some(v) = self.signature.as_ref() {
+            struct_ser.serialize_field("signature", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1645,11 +1654,13 @@ impl<'de> serde::Deserialize<'de> for VmacInstallationLinkedKey {
     {
         const FIELDS: &[&str] = &[
             "key",
+            "signature",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Key,
+            Signature,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1672,6 +1683,7 @@ impl<'de> serde::Deserialize<'de> for VmacInstallationLinkedKey {
                     {
                         match value {
                             "key" => Ok(GeneratedField::Key),
+                            "signature" => Ok(GeneratedField::Signature),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1692,6 +1704,7 @@ impl<'de> serde::Deserialize<'de> for VmacInstallationLinkedKey {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut key__ = None;
+                let mut signature__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Key => {
@@ -1700,10 +1713,17 @@ impl<'de> serde::Deserialize<'de> for VmacInstallationLinkedKey {
                             }
                             key__ = map.next_value()?;
                         }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(VmacInstallationLinkedKey {
                     key: key__,
+                    signature: signature__,
                 })
             }
         }
@@ -1724,6 +1744,12 @@ impl serde::Serialize for VmacInstallationPublicKeyBundleV1 {
         if self.fallback_key.is_some() {
             len += 1;
         }
+        if self.sealed_sender_key.is_some() {
+            len += 1;
+        }
+        if !self.ed25519_key.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("xmtp.v3.message_contents.VmacInstallationPublicKeyBundleV1", len)?;
         if let Some(v) = self.identity_key.as_ref() {
             struct_ser.serialize_field("identityKey", v)?;
@@ -1731,6 +1757,12 @@ impl serde::Serialize for VmacInstallationPublicKeyBundleV1 {
         if let Some(v) = self.fallback_key.as_ref() {
             struct_ser.serialize_field("fallbackKey", v)?;
         }
+        if let Some(v) = self.sealed_sender_key.as_ref() {
+            struct_ser.serialize_field("sealedSenderKey", v)?;
+        }
+        if !self.ed25519_key.is_empty() {
+            struct_ser.serialize_field("ed25519Key", pbjson::private::base64::encode(&self.ed25519_key).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -1745,12 +1777,18 @@ impl<'de> serde::Deserialize<'de> for VmacInstallationPublicKeyBundleV1 {
             "identityKey",
             "fallback_key",
             "fallbackKey",
+            "sealed_sender_key",
+            "sealedSenderKey",
+            "ed25519_key",
+            "ed25519Key",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             IdentityKey,
             FallbackKey,
+            SealedSenderKey,
+            Ed25519Key,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1774,6 +1812,8 @@ impl<'de> serde::Deserialize<'de> for VmacInstallationPublicKeyBundleV1 {
                         match value {
                             "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             "fallbackKey" | "fallback_key" => Ok(GeneratedField::FallbackKey),
+                            "sealedSenderKey" | "sealed_sender_key" => Ok(GeneratedField::SealedSenderKey),
+                            "ed25519Key" | "ed25519_key" => Ok(GeneratedField::Ed25519Key),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1795,6 +1835,8 @@ impl<'de> serde::Deserialize<'de> for VmacInstallationPublicKeyBundleV1 {
             {
                 let mut identity_key__ = None;
                 let mut fallback_key__ = None;
+                let mut sealed_sender_key__ = None;
+                let mut ed25519_key__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::IdentityKey => {
@@ -1809,11 +1851,27 @@ impl<'de> serde::Deserialize<'de> for VmacInstallationPublicKeyBundleV1 {
                             }
                             fallback_key__ = map.next_value()?;
                         }
+                        GeneratedField::SealedSenderKey => {
+                            if sealed_sender_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sealedSenderKey"));
+                            }
+                            sealed_sender_key__ = map.next_value()?;
+                        }
+                        GeneratedField::Ed25519Key => {
+                            if ed25519_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ed25519Key"));
+                            }
+                            ed25519_key__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(VmacInstallationPublicKeyBundleV1 {
                     identity_key: identity_key__,
                     fallback_key: fallback_key__,
+                    sealed_sender_key: sealed_sender_key__,
+                    ed25519_key: ed25519_key__.unwrap_or_default(),
                 })
             }
         }